@@ -115,7 +115,7 @@ impl FreeAttrDecl<f32> for Power {
 
     type Attr = PowerAttr;
 
-    fn materialize(self, _builder: &mut AttrBuilder) -> anyhow::Result<Self::Attr> {
+    async fn materialize(self, _builder: &mut AttrBuilder<'_>) -> anyhow::Result<Self::Attr> {
         let last = Arc::new(Mutex::new(0.0));
         let next = last.clone();
 
@@ -134,7 +134,7 @@ impl BoundAttrDecl<f32> for Power {
 
     type Attr = PowerAttr;
 
-    fn materialize(self, bounds: Bounds<f32>, _builder: &mut AttrBuilder) -> anyhow::Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<f32>, _builder: &mut AttrBuilder<'_>) -> anyhow::Result<Self::Attr> {
         let last = Arc::new(Mutex::new(0.0));
         let next = last.clone();
 
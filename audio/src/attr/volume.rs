@@ -34,7 +34,7 @@ impl FreeAttrDecl<f32> for Volume {
 
     type Attr = VolumeAttr;
 
-    fn materialize(self, _builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, _builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         let last = Arc::new(Mutex::new(0.0));
         let next = last.clone();
 
@@ -57,7 +57,7 @@ impl BoundAttrDecl<f32> for Volume {
 
     type Attr = VolumeAttr;
 
-    fn materialize(self, bounds: Bounds<f32>, _builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<f32>, _builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         let last = Arc::new(Mutex::new(0.0));
         let next = last.clone();
 
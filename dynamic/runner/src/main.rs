@@ -44,6 +44,10 @@ async fn main() -> Result<()> {
     let root = builder.node("root", scene.root)?;
     let output = builder.output(scene.output)?;
 
+    for binding in scene.bindings {
+        builder.binding(binding)?;
+    }
+
     let scene = builder.build();
     let scene = scene.run(root, output).await?;
 
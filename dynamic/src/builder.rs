@@ -1,9 +1,10 @@
 use anyhow::{anyhow, Context, Result};
-use palette::rgb::Rgb;
+use palette::Srgba;
 use serde::de::DeserializeOwned;
 use std::marker::PhantomData;
 
 use photonic::attr::{AsFixedAttr, Bounded};
+use photonic::binding::Target;
 use photonic::boxed::{Boxed, BoxedBoundAttrDecl, BoxedFreeAttrDecl, BoxedNodeDecl, BoxedOutputDecl};
 use photonic::input::InputValue;
 use photonic::scene::InputHandle;
@@ -28,6 +29,10 @@ impl<Reg: Registry> InputBuilder<'_, Reg> {
 }
 
 impl<Reg: Registry> AttrBuilder<'_, Reg> {
+    pub fn node(&mut self, name: &str, config: config::Node) -> Result<NodeHandle<BoxedNodeDecl<Srgba>>> {
+        return self.0.node(name, config);
+    }
+
     pub fn input<I>(&mut self, config: config::Input) -> Result<InputHandle<I>>
     where I: InputValue {
         return self.0.input(config);
@@ -45,7 +50,7 @@ impl<Reg: Registry> AttrBuilder<'_, Reg> {
 }
 
 impl<Reg: Registry> NodeBuilder<'_, Reg> {
-    pub fn node(&mut self, name: &str, config: config::Node) -> Result<NodeHandle<BoxedNodeDecl<Rgb>>> {
+    pub fn node(&mut self, name: &str, config: config::Node) -> Result<NodeHandle<BoxedNodeDecl<Srgba>>> {
         return self.0.node(name, config);
     }
 
@@ -167,7 +172,7 @@ impl<Reg: Registry> Builder<Reg> {
         }
     }
 
-    pub fn node(&mut self, name: &str, config: config::Node) -> Result<NodeHandle<BoxedNodeDecl<Rgb>>> {
+    pub fn node(&mut self, name: &str, config: config::Node) -> Result<NodeHandle<BoxedNodeDecl<Srgba>>> {
         let factory = Reg::node::<Reg>(&config.kind).ok_or_else(|| anyhow!("Unknown node type: {}", config.kind))?;
 
         let decl = factory
@@ -177,6 +182,21 @@ impl<Reg: Registry> Builder<Reg> {
         return self.scene.node(&config.name, decl);
     }
 
+    pub fn binding(&mut self, config: config::Binding) -> Result<()> {
+        let targets = config.targets.into_iter().map(|target| {
+            Target::new(target.input)
+                .with_scale(target.scale)
+                .with_offset(target.offset)
+                .with_invert(target.invert)
+                .with_curve(target.curve)
+        });
+
+        return self
+            .scene
+            .binding(&config.name, targets)
+            .with_context(|| format!("Failed to build binding: {}", config.name));
+    }
+
     pub fn output(&mut self, config: config::Output) -> Result<BoxedOutputDecl> {
         let factory =
             Reg::output::<Reg>(&config.kind).ok_or_else(|| anyhow!("Unknown output type: {}", config.kind))?;
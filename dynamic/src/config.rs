@@ -1,4 +1,6 @@
-use serde::Deserialize;
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer};
 
 use photonic::AttrValue;
 
@@ -10,6 +12,9 @@ pub struct Scene {
 
     pub root: Node,
     pub output: Output,
+
+    #[serde(default)]
+    pub bindings: Vec<Binding>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -28,6 +33,39 @@ pub struct Input {
     pub input: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct Binding {
+    pub name: String,
+    pub targets: Vec<BindingTarget>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BindingTarget {
+    pub input: String,
+
+    #[serde(default = "BindingTarget::default_scale")]
+    pub scale: f32,
+
+    #[serde(default)]
+    pub offset: f32,
+
+    #[serde(default)]
+    pub invert: bool,
+
+    #[serde(default = "BindingTarget::default_curve")]
+    pub curve: f32,
+}
+
+impl BindingTarget {
+    fn default_scale() -> f32 {
+        return 1.0;
+    }
+
+    fn default_curve() -> f32 {
+        return 1.0;
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Output {
     #[serde(alias = "type")]
@@ -38,7 +76,7 @@ pub struct Output {
 }
 
 #[derive(Debug, Clone, Deserialize)]
-#[serde(untagged)]
+#[serde(untagged, bound(deserialize = "V: Deserialize<'de>"))]
 // TODO: Life the requirement of V: Deserialize and make fixed values optional by type
 pub enum Attr<V>
 where V: AttrValue
@@ -55,8 +93,34 @@ where V: AttrValue
         #[serde(flatten)]
         input: Input,
 
+        #[serde(deserialize_with = "Value::deserialize")]
         initial: V,
     },
 
-    Fixed(V),
+    Fixed(#[serde(deserialize_with = "Value::deserialize")] V),
+}
+
+/// Deserialization of attribute values in configs.
+///
+/// Values are deserialized using their [`Deserialize`] implementation by default. Types with a representation that
+/// is unhandy to write in a config file are deserialized from a more convenient format.
+pub trait Value<'de>: Sized {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de>;
+}
+
+impl<'de, V> Value<'de> for V
+where V: Deserialize<'de>
+{
+    default fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        return V::deserialize(deserializer);
+    }
+}
+
+impl<'de> Value<'de> for Duration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        return humantime_serde::deserialize(deserializer);
+    }
 }
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use anyhow::Result;
-use palette::rgb::Rgb;
+use palette::Srgba;
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
@@ -45,12 +45,12 @@ pub fn factory<P>() -> Box<PhantomData<P>> {
     return Box::new(PhantomData::<P>);
 }
 
-pub type NodeFactory<Reg> = Box<dyn Factory<dyn DynNodeDecl<Rgb>, Reg>>;
+pub type NodeFactory<Reg> = Box<dyn Factory<dyn DynNodeDecl<Srgba>, Reg>>;
 pub type BoundAttrFactory<Reg, V> = Box<dyn Factory<dyn DynBoundAttrDecl<V>, Reg>>;
 pub type FreeAttrFactory<Reg, V> = Box<dyn Factory<dyn DynFreeAttrDecl<V>, Reg>>;
 pub type OutputFactory<Reg> = Box<dyn Factory<dyn DynOutputDecl, Reg>>;
 
-impl Product for dyn DynNodeDecl<Rgb> {
+impl Product for dyn DynNodeDecl<Srgba> {
     type Builder<'b, Reg: Registry + 'b> = NodeBuilder<'b, Reg>;
 }
 
@@ -1,3 +1,5 @@
+#![feature(specialization)]
+
 pub use serde;
 
 pub use crate::builder::{AttrBuilder, Builder, NodeBuilder, OutputBuilder};
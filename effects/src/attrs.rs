@@ -1,19 +1,44 @@
+pub use adsr::Adsr;
 pub use button::Button;
 pub use color::*;
+pub use counter::{Counter, Overflow};
+pub use drunkard::Drunkard;
+pub use expression::Expression;
 pub use fader::Fader;
+pub use keyframes::{Keyframe, Keyframes};
+pub use lfo::{Lfo, Waveform};
 pub use looper::Looper;
 pub use noise::Noise;
+pub use palettes::{FromPalette, Palette, Palettes};
 pub use peak::Peak;
 pub use random::Random;
+pub use random_walk::RandomWalk;
+pub use sample::{Reduction, Sample, SampleValue};
 pub use sequence::Sequence;
+pub use smooth::{Smooth, Smoothing};
 pub use switch::Switch;
+pub use threshold::Threshold;
+pub use toggle::Toggle;
 
+pub(crate) mod adsr;
+pub(crate) mod arithmetic;
 pub(crate) mod button;
 pub(crate) mod color;
+pub(crate) mod counter;
+pub(crate) mod drunkard;
+pub(crate) mod expression;
 pub(crate) mod fader;
+pub(crate) mod keyframes;
+pub(crate) mod lfo;
 pub(crate) mod looper;
 pub(crate) mod noise;
+pub(crate) mod palettes;
 pub(crate) mod peak;
 pub(crate) mod random;
+pub(crate) mod random_walk;
+pub(crate) mod sample;
 pub(crate) mod sequence;
+pub(crate) mod smooth;
 pub(crate) mod switch;
+pub(crate) mod threshold;
+pub(crate) mod toggle;
@@ -0,0 +1,284 @@
+use std::time::Duration;
+
+use anyhow::Result;
+
+use photonic::attr::{Bounded, Bounds, FixedAttrDecl};
+use photonic::input::{Input, Poll, Trigger};
+use photonic::math::Lerp;
+use photonic::scene::InputHandle;
+use photonic::{scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl, FreeAttrDecl};
+
+use crate::easing::Easing;
+
+#[derive(Clone, Copy, Debug)]
+enum Stage {
+    Idle,
+    Attack { from: f32, progress: f32 },
+    Decay { progress: f32 },
+    Sustain,
+    Release { from: f32, progress: f32 },
+}
+
+/// Advances the progress of a stage with the given length.
+///
+/// Returns `None` if the stage is finished.
+fn advance(progress: f32, duration: Duration, length: Duration) -> Option<f32> {
+    if length.is_zero() {
+        return None;
+    }
+
+    let progress = progress + duration.as_secs_f32() / length.as_secs_f32();
+    return (progress < 1.0).then_some(progress);
+}
+
+pub struct AdsrAttr<V, Attack, Decay, Sustain, Release>
+where
+    V: AttrValue + Lerp,
+    Attack: Attr<Duration>,
+    Decay: Attr<Duration>,
+    Sustain: Attr<f32>,
+    Release: Attr<Duration>,
+{
+    attack: Easing<f32, Attack>,
+    decay: Easing<f32, Decay>,
+    sustain: Sustain,
+    release: Easing<f32, Release>,
+
+    trigger: Input<Trigger>,
+    release_trigger: Option<Input<Trigger>>,
+    gate: Option<Input<bool>>,
+
+    bounds: Bounds<V>,
+
+    stage: Stage,
+
+    /// Current level of the envelope
+    level: f32,
+}
+
+impl<V, Attack, Decay, Sustain, Release> Attr<V> for AdsrAttr<V, Attack, Decay, Sustain, Release>
+where
+    V: AttrValue + Lerp,
+    Attack: Attr<Duration>,
+    Decay: Attr<Duration>,
+    Sustain: Attr<f32>,
+    Release: Attr<Duration>,
+{
+    fn update(&mut self, ctx: &scene::RenderContext) -> V {
+        let attack = self.attack.speed.update(ctx);
+        let decay = self.decay.speed.update(ctx);
+        let sustain = self.sustain.update(ctx);
+        let release = self.release.speed.update(ctx);
+
+        let mut triggered = matches!(self.trigger.poll(anyhow::Ok), Poll::Update(_));
+        let mut released = matches!(self.release_trigger.as_mut().map(|t| t.poll(anyhow::Ok)), Some(Poll::Update(_)));
+
+        if let Some(Poll::Update(gate)) = self.gate.as_mut().map(|gate| gate.poll(anyhow::Ok)) {
+            triggered |= gate;
+            released |= !gate;
+        }
+
+        if triggered {
+            self.stage = Stage::Attack {
+                from: self.level,
+                progress: 0.0,
+            };
+        } else if released && !matches!(self.stage, Stage::Idle | Stage::Release { .. }) {
+            self.stage = Stage::Release {
+                from: self.level,
+                progress: 0.0,
+            };
+        }
+
+        // Without an explicit release, the envelope releases right after the decay
+        let holding = self.release_trigger.is_some() || self.gate.is_some();
+
+        self.stage = match self.stage {
+            Stage::Attack {
+                from,
+                progress,
+            } => match advance(progress, ctx.duration, attack) {
+                Some(progress) => Stage::Attack {
+                    from,
+                    progress,
+                },
+                None => Stage::Decay {
+                    progress: 0.0,
+                },
+            },
+            Stage::Decay {
+                progress,
+            } => match advance(progress, ctx.duration, decay) {
+                Some(progress) => Stage::Decay {
+                    progress,
+                },
+                None if holding => Stage::Sustain,
+                None => Stage::Release {
+                    from: sustain,
+                    progress: 0.0,
+                },
+            },
+            Stage::Release {
+                from,
+                progress,
+            } => match advance(progress, ctx.duration, release) {
+                Some(progress) => Stage::Release {
+                    from,
+                    progress,
+                },
+                None => Stage::Idle,
+            },
+            stage => stage,
+        };
+
+        self.level = match self.stage {
+            Stage::Idle => 0.0,
+            Stage::Attack {
+                from,
+                progress,
+            } => f32::lerp(from, 1.0, self.attack.curve.apply(progress)),
+            Stage::Decay {
+                progress,
+            } => f32::lerp(1.0, sustain, self.decay.curve.apply(progress)),
+            Stage::Sustain => sustain,
+            Stage::Release {
+                from,
+                progress,
+            } => f32::lerp(from, 0.0, self.release.curve.apply(progress)),
+        };
+
+        return V::lerp(self.bounds.min, self.bounds.max, self.level);
+    }
+}
+
+/// An attack-decay-sustain-release envelope.
+///
+/// Every time the trigger input fires, the envelope rises from its current level to the upper bound during the attack
+/// time and falls to the sustain level during the decay time. The sustain level is held until the envelope is released
+/// by the release trigger or by the gate falling to `false` - a rising gate starts the attack like the trigger. On
+/// release, the envelope falls to the lower bound during the release time. If neither a release trigger nor a gate
+/// is given, the envelope is released directly after the decay.
+///
+/// The curve of each stage is defined by its easing.
+pub struct Adsr<
+    Sustain,
+    Attack = FixedAttrDecl<Duration>,
+    Decay = FixedAttrDecl<Duration>,
+    Release = FixedAttrDecl<Duration>,
+> {
+    pub attack: Easing<f32, Attack>,
+    pub decay: Easing<f32, Decay>,
+    pub sustain: Sustain,
+    pub release: Easing<f32, Release>,
+
+    pub trigger: InputHandle<Trigger>,
+    pub release_trigger: Option<InputHandle<Trigger>>,
+    pub gate: Option<InputHandle<bool>>,
+}
+
+impl<V, Sustain, Attack, Decay, Release> BoundAttrDecl<V> for Adsr<Sustain, Attack, Decay, Release>
+where
+    V: AttrValue + Bounded + Lerp,
+    Sustain: BoundAttrDecl<f32>,
+    Attack: FreeAttrDecl<Duration>,
+    Decay: FreeAttrDecl<Duration>,
+    Release: FreeAttrDecl<Duration>,
+{
+    const KIND: &'static str = "adsr";
+
+    type Attr = AdsrAttr<V, Attack::Attr, Decay::Attr, Sustain::Attr, Release::Attr>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(AdsrAttr {
+            attack: Easing {
+                curve: self.attack.curve,
+                speed: builder.unbound_attr("attack", self.attack.speed).await?,
+            },
+            decay: Easing {
+                curve: self.decay.curve,
+                speed: builder.unbound_attr("decay", self.decay.speed).await?,
+            },
+            sustain: builder.bound_attr("sustain", self.sustain, Bounds::normal()).await?,
+            release: Easing {
+                curve: self.release.curve,
+                speed: builder.unbound_attr("release", self.release.speed).await?,
+            },
+            trigger: builder.input("trigger", self.trigger)?,
+            release_trigger: self.release_trigger.map(|input| builder.input("release_trigger", input)).transpose()?,
+            gate: self.gate.map(|input| builder.input("gate", input)).transpose()?,
+            bounds,
+            stage: Stage::Idle,
+            level: 0.0,
+        });
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use anyhow::bail;
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedBoundAttrDecl, BoxedFreeAttrDecl, DynBoundAttrDecl};
+    use photonic::input;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+    use crate::easing;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub attack: easing::dynamic::Config,
+        pub decay: easing::dynamic::Config,
+        pub sustain: config::Attr<f32>,
+        pub release: easing::dynamic::Config,
+
+        pub trigger: config::Input,
+
+        #[serde(default)]
+        pub release_trigger: Option<config::Input>,
+
+        #[serde(default)]
+        pub gate: Option<config::Input>,
+    }
+
+    type BoxedAdsr = Adsr<
+        BoxedBoundAttrDecl<f32>,
+        BoxedFreeAttrDecl<Duration>,
+        BoxedFreeAttrDecl<Duration>,
+        BoxedFreeAttrDecl<Duration>,
+    >;
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'adsr' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + Lerp
+    {
+        type Product = BoxedAdsr;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Adsr {
+                attack: config.attack.func.with_speed_attr(builder.free_attr("attack", config.attack.speed)?)?,
+                decay: config.decay.func.with_speed_attr(builder.free_attr("decay", config.decay.speed)?)?,
+                sustain: builder.bound_attr("sustain", config.sustain)?,
+                release: config.release.func.with_speed_attr(builder.free_attr("release", config.release.speed)?)?,
+                trigger: builder.input(config.trigger)?,
+                release_trigger: config.release_trigger.map(|input| builder.input(input)).transpose()?,
+                gate: config.gate.map(|input| builder.input(input)).transpose()?,
+            });
+        }
+    }
+}
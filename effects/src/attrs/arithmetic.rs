@@ -0,0 +1,337 @@
+//! Dynamic configuration of the arithmetic combinators from [`photonic::attr::ext`].
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use std::marker::PhantomData;
+    use std::ops;
+
+    use anyhow::{bail, Result};
+    use num_traits::Float;
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::attr::ext::{op, Binary, BinaryOp, Clamp, Invert, Mix, Operation, Quantize};
+    use photonic::attr::{BoundAttrDeclExt, Bounded, Bounds, FreeAttrDeclExt};
+    use photonic::boxed::{BoxedBoundAttrDecl, BoxedFreeAttrDecl, DynBoundAttrDecl, DynFreeAttrDecl};
+    use photonic::math::Lerp;
+    use photonic::{input, AttrValue};
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    #[derive(Deserialize, Debug)]
+    pub struct BinaryConfig<V, Op>
+    where V: AttrValue
+    {
+        pub inner: config::Attr<V>,
+        pub other: config::Attr<V>,
+
+        #[serde(skip)]
+        phantom: PhantomData<Op>,
+    }
+
+    pub type AddConfig<V> = BinaryConfig<V, op::Add>;
+    pub type MulConfig<V> = BinaryConfig<V, op::Mul>;
+    pub type MinConfig<V> = BinaryConfig<V, op::Min>;
+    pub type MaxConfig<V> = BinaryConfig<V, op::Max>;
+
+    impl<V, Op> Producible<dyn DynFreeAttrDecl<V>> for BinaryConfig<V, Op>
+    where
+        V: AttrValue + input::Coerced + DeserializeOwned,
+        Op: Operation + 'static,
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute '{}' no available for value type {}", Op::KIND, std::any::type_name::<V>());
+        }
+    }
+
+    impl<V, Op> Producible<dyn DynFreeAttrDecl<V>> for BinaryConfig<V, Op>
+    where
+        V: AttrValue + input::Coerced + DeserializeOwned,
+        Op: BinaryOp<V> + 'static,
+    {
+        type Product = Binary<BoxedFreeAttrDecl<V>, BoxedFreeAttrDecl<V>, Op>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Binary::new(
+                builder.free_attr("inner", config.inner)?,
+                builder.free_attr("other", config.other)?,
+            ));
+        }
+    }
+
+    impl<V, Op> Producible<dyn DynBoundAttrDecl<V>> for BinaryConfig<V, Op>
+    where
+        V: AttrValue + input::Coerced + DeserializeOwned + Bounded,
+        Op: Operation + 'static,
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute '{}' no available for value type {}", Op::KIND, std::any::type_name::<V>());
+        }
+    }
+
+    impl<V, Op> Producible<dyn DynBoundAttrDecl<V>> for BinaryConfig<V, Op>
+    where
+        V: AttrValue + input::Coerced + DeserializeOwned + Bounded + PartialOrd,
+        Op: BinaryOp<V> + 'static,
+    {
+        type Product = Binary<BoxedBoundAttrDecl<V>, BoxedBoundAttrDecl<V>, Op>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Binary::new(
+                builder.bound_attr("inner", config.inner)?,
+                builder.bound_attr("other", config.other)?,
+            ));
+        }
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct MixConfig<V>
+    where V: AttrValue
+    {
+        pub inner: config::Attr<V>,
+        pub other: config::Attr<V>,
+        pub amount: config::Attr<f32>,
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for MixConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'mix' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for MixConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Lerp
+    {
+        type Product = Mix<BoxedFreeAttrDecl<V>, BoxedFreeAttrDecl<V>, BoxedBoundAttrDecl<f32>>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            let inner = builder.free_attr("inner", config.inner)?;
+            let other = builder.free_attr("other", config.other)?;
+            let amount = builder.bound_attr("amount", config.amount)?;
+            return Ok(FreeAttrDeclExt::mix(inner, other, amount));
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for MixConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'mix' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for MixConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + Lerp
+    {
+        type Product = Mix<BoxedBoundAttrDecl<V>, BoxedBoundAttrDecl<V>, BoxedBoundAttrDecl<f32>>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            let inner = builder.bound_attr("inner", config.inner)?;
+            let other = builder.bound_attr("other", config.other)?;
+            let amount = builder.bound_attr("amount", config.amount)?;
+            return Ok(BoundAttrDeclExt::mix(inner, other, amount));
+        }
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct ClampConfig<V>
+    where V: AttrValue
+    {
+        pub inner: config::Attr<V>,
+        pub min: V,
+        pub max: V,
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for ClampConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'clamp' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for ClampConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + PartialOrd
+    {
+        type Product = Clamp<BoxedFreeAttrDecl<V>, V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            let inner = builder.free_attr("inner", config.inner)?;
+            return Ok(FreeAttrDeclExt::clamp(inner, (config.min, config.max)));
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for ClampConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'clamp' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for ClampConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + PartialOrd
+    {
+        type Product = Clamp<BoxedFreeAttrDecl<V>, V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            let inner = builder.free_attr("inner", config.inner)?;
+            return Ok(FreeAttrDeclExt::clamp(inner, (config.min, config.max)));
+        }
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct InvertConfig<V>
+    where V: AttrValue
+    {
+        pub inner: config::Attr<V>,
+
+        /// Bounds to invert unbound attributes within - bound attributes use their own bounds
+        pub min: Option<V>,
+        pub max: Option<V>,
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for InvertConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'invert' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for InvertConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + ops::Add<Output = V> + ops::Sub<Output = V>
+    {
+        type Product = Invert<BoxedFreeAttrDecl<V>, V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            let (Some(min), Some(max)) = (config.min, config.max) else {
+                bail!("Attribute 'invert' requires 'min' and 'max' if unbound");
+            };
+
+            let inner = builder.free_attr("inner", config.inner)?;
+            return Ok(FreeAttrDeclExt::invert(inner, Bounds {
+                min,
+                max,
+            }));
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for InvertConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'invert' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for InvertConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + ops::Add<Output = V> + ops::Sub<Output = V>
+    {
+        type Product = Invert<BoxedBoundAttrDecl<V>, V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            let inner = builder.bound_attr("inner", config.inner)?;
+            return Ok(BoundAttrDeclExt::invert(inner));
+        }
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct QuantizeConfig<V>
+    where V: AttrValue
+    {
+        pub inner: config::Attr<V>,
+        pub step: V,
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for QuantizeConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'quantize' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for QuantizeConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Float
+    {
+        type Product = Quantize<BoxedFreeAttrDecl<V>, V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            let inner = builder.free_attr("inner", config.inner)?;
+            return Ok(FreeAttrDeclExt::quantize(inner, config.step));
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for QuantizeConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'quantize' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for QuantizeConfig<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + Float
+    {
+        type Product = Quantize<BoxedBoundAttrDecl<V>, V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            let inner = builder.bound_attr("inner", config.inner)?;
+            return Ok(BoundAttrDeclExt::quantize(inner, config.step));
+        }
+    }
+}
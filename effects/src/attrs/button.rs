@@ -34,25 +34,31 @@ impl State {
     }
 }
 
-pub struct ButtonAttr<V>
-where V: AttrValue
+pub struct ButtonAttr<V, HoldTime>
+where
+    V: AttrValue,
+    HoldTime: Attr<Duration>,
 {
     value_released: V,
     value_pressed: V,
 
-    hold_time: Duration,
+    hold_time: HoldTime,
 
     state: State,
 
     trigger: Input<Trigger>,
 }
 
-impl<V> Attr<V> for ButtonAttr<V>
-where V: AttrValue
+impl<V, HoldTime> Attr<V> for ButtonAttr<V, HoldTime>
+where
+    V: AttrValue,
+    HoldTime: Attr<Duration>,
 {
     fn update(&mut self, ctx: &scene::RenderContext) -> V {
+        let hold_time = self.hold_time.update(ctx);
+
         if let Poll::Update(_) = self.trigger.poll(anyhow::Ok) {
-            self.state = State::Pressed(self.hold_time)
+            self.state = State::Pressed(hold_time)
         };
 
         self.state = self.state.update(ctx.duration);
@@ -64,47 +70,51 @@ where V: AttrValue
     }
 }
 
-pub struct Button<V>
+pub struct Button<V, HoldTime>
 where V: AttrValue
 {
     pub value_release: V,
     pub value_pressed: V,
 
-    pub hold_time: Duration,
+    pub hold_time: HoldTime,
 
     pub trigger: InputHandle<Trigger>,
 }
 
-impl<V> BoundAttrDecl<V> for Button<V>
-where V: AttrValue + Bounded
+impl<V, HoldTime> BoundAttrDecl<V> for Button<V, HoldTime>
+where
+    V: AttrValue + Bounded,
+    HoldTime: FreeAttrDecl<Duration>,
 {
     const KIND: &'static str = "button";
 
-    type Attr = ButtonAttr<V>;
+    type Attr = ButtonAttr<V, HoldTime::Attr>;
 
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         return Ok(ButtonAttr {
             value_released: bounds.ensure(self.value_release)?,
             value_pressed: bounds.ensure(self.value_pressed)?,
-            hold_time: self.hold_time,
+            hold_time: builder.unbound_attr("hold_time", self.hold_time).await?,
             state: State::Released,
             trigger: builder.input("trigger", self.trigger)?,
         });
     }
 }
 
-impl<V> FreeAttrDecl<V> for Button<V>
-where V: AttrValue
+impl<V, HoldTime> FreeAttrDecl<V> for Button<V, HoldTime>
+where
+    V: AttrValue,
+    HoldTime: FreeAttrDecl<Duration>,
 {
     const KIND: &'static str = "button";
 
-    type Attr = ButtonAttr<V>;
+    type Attr = ButtonAttr<V, HoldTime::Attr>;
 
-    fn materialize(self, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         return Ok(ButtonAttr {
             value_released: self.value_release,
             value_pressed: self.value_pressed,
-            hold_time: self.hold_time,
+            hold_time: builder.unbound_attr("hold_time", self.hold_time).await?,
             state: State::Released,
             trigger: builder.input("trigger", self.trigger)?,
         });
@@ -116,7 +126,7 @@ pub mod dynamic {
     use serde::de::DeserializeOwned;
     use serde::Deserialize;
 
-    use photonic::boxed::{DynBoundAttrDecl, DynFreeAttrDecl};
+    use photonic::boxed::{BoxedFreeAttrDecl, DynBoundAttrDecl, DynFreeAttrDecl};
     use photonic_dynamic::factory::Producible;
     use photonic_dynamic::registry::Registry;
     use photonic_dynamic::{builder, config};
@@ -129,21 +139,20 @@ pub mod dynamic {
         pub value_release: V,
         #[serde(bound(deserialize = "V: Deserialize<'de>"))]
         pub value_pressed: V,
-        #[serde(with = "humantime_serde")]
-        pub hold_time: Duration,
+        pub hold_time: config::Attr<Duration>,
         pub trigger: config::Input,
     }
 
     impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
     where V: AttrValue + DeserializeOwned
     {
-        type Product = Button<V>;
+        type Product = Button<V, BoxedFreeAttrDecl<Duration>>;
 
         fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Button {
                 value_release: config.value_release,
                 value_pressed: config.value_pressed,
-                hold_time: config.hold_time,
+                hold_time: builder.free_attr("hold_time", config.hold_time)?,
                 trigger: builder.input(config.trigger)?,
             });
         }
@@ -152,13 +161,13 @@ pub mod dynamic {
     impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
     where V: AttrValue + DeserializeOwned + Bounded
     {
-        type Product = Button<V>;
+        type Product = Button<V, BoxedFreeAttrDecl<Duration>>;
 
         fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Button {
                 value_release: config.value_release,
                 value_pressed: config.value_pressed,
-                hold_time: config.hold_time,
+                hold_time: builder.free_attr("hold_time", config.hold_time)?,
                 trigger: builder.input(config.trigger)?,
             });
         }
@@ -85,14 +85,14 @@ macro_rules! color_attrs {
                         )*
                     >;
 
-                    fn materialize(self, builder: &mut ::photonic::AttrBuilder) -> ::anyhow::Result<Self::Attr> {
+                    async fn materialize(self, builder: &mut ::photonic::AttrBuilder<'_>) -> ::anyhow::Result<Self::Attr> {
                         return ::anyhow::Ok(Self::Attr {
                             $(
                                 [<$channel>]: builder.bound_attr(
                                     stringify!($channel),
                                     self.[<$channel>],
                                     ($lb, $ub),
-                                )?,
+                                ).await?,
                             )*
                         });
                     }
@@ -0,0 +1,323 @@
+use anyhow::{ensure, Result};
+use num_traits::{NumCast, PrimInt};
+
+use photonic::attr::{Bounded, Bounds};
+use photonic::input::{Input, Poll, Trigger};
+use photonic::scene::InputHandle;
+use photonic::{scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl};
+
+/// Defines what happens if the counter leaves its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "dynamic", derive(serde::Deserialize))]
+#[cfg_attr(feature = "dynamic", serde(rename_all = "snake_case"))]
+pub enum Overflow {
+    /// Stop at the bound
+    #[default]
+    Saturate,
+
+    /// Continue at the opposite bound
+    Wrap,
+}
+
+pub struct CounterAttr<V>
+where V: AttrValue + PrimInt
+{
+    bounds: Bounds<V>,
+
+    step: V,
+    overflow: Overflow,
+
+    initial: V,
+    current: V,
+
+    increment: Option<Input<Trigger>>,
+    decrement: Option<Input<Trigger>>,
+    reset: Option<Input<Trigger>>,
+}
+
+impl<V> CounterAttr<V>
+where V: AttrValue + PrimInt
+{
+    /// Moves the counter by the given number of steps.
+    ///
+    /// Calculation is done in a wider type to avoid overflows at the limits of the value type.
+    fn count(&self, steps: i128) -> V {
+        let min = self.bounds.min.to_i128().expect("Can cast");
+        let max = self.bounds.max.to_i128().expect("Can cast");
+
+        let current = self.current.to_i128().expect("Can cast");
+        let step = self.step.to_i128().expect("Can cast");
+
+        let next = match self.overflow {
+            Overflow::Saturate => (current + steps * step).clamp(min, max),
+            Overflow::Wrap => min + (current - min + steps * step).rem_euclid(max - min + 1),
+        };
+
+        return <V as NumCast>::from(next).expect("Within bounds");
+    }
+}
+
+impl<V> Attr<V> for CounterAttr<V>
+where V: AttrValue + PrimInt
+{
+    fn update(&mut self, _ctx: &scene::RenderContext) -> V {
+        let poll = |input: &mut Option<Input<Trigger>>| {
+            return matches!(input.as_mut().map(|input| input.poll(anyhow::Ok)), Some(Poll::Update(_)));
+        };
+
+        let increment = poll(&mut self.increment);
+        let decrement = poll(&mut self.decrement);
+        let reset = poll(&mut self.reset);
+
+        if reset {
+            self.current = self.initial;
+        }
+
+        self.current = self.count(increment as i128 - decrement as i128);
+
+        return self.current;
+    }
+}
+
+/// Counts the triggers of the increment and decrement inputs.
+///
+/// Every trigger moves the counter by the step. If the counter would leave the bounds, it either stops at the bound or
+/// wraps around to the opposite bound. The reset input sets the counter back to its initial value, which defaults to
+/// the lower bound.
+pub struct Counter<V>
+where V: AttrValue
+{
+    pub step: V,
+    pub overflow: Overflow,
+
+    pub initial: Option<V>,
+
+    pub increment: Option<InputHandle<Trigger>>,
+    pub decrement: Option<InputHandle<Trigger>>,
+    pub reset: Option<InputHandle<Trigger>>,
+}
+
+impl<V> BoundAttrDecl<V> for Counter<V>
+where V: AttrValue + Bounded + PrimInt
+{
+    const KIND: &'static str = "counter";
+
+    type Attr = CounterAttr<V>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        ensure!(self.step > V::zero(), "Step must be positive");
+
+        let initial = bounds.ensure(self.initial.unwrap_or(bounds.min))?;
+
+        return Ok(CounterAttr {
+            bounds,
+            step: self.step,
+            overflow: self.overflow,
+            initial,
+            current: initial,
+            increment: self.increment.map(|input| builder.input("increment", input)).transpose()?,
+            decrement: self.decrement.map(|input| builder.input("decrement", input)).transpose()?,
+            reset: self.reset.map(|input| builder.input("reset", input)).transpose()?,
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use palette::rgb::Rgb;
+
+    use photonic::attr::AsFixedAttr;
+    use photonic::input::AnyInputValue;
+    use photonic::Scene;
+
+    use crate::nodes::{Select, Solid};
+    use crate::testing::Renderer;
+
+    use super::*;
+
+    fn counter<V>(min: V, max: V, step: V, overflow: Overflow, current: V) -> CounterAttr<V>
+    where V: AttrValue + PrimInt {
+        return CounterAttr {
+            bounds: Bounds {
+                min,
+                max,
+            },
+            step,
+            overflow,
+            initial: min,
+            current,
+            increment: None,
+            decrement: None,
+            reset: None,
+        };
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    enum Action {
+        Increment,
+        Decrement,
+        Reset,
+    }
+
+    /// Renders the counter in `[0, 3]` after each of the given actions.
+    async fn run(step: usize, overflow: Overflow, initial: Option<usize>, actions: &[Action]) -> Vec<usize> {
+        let mut scene = Scene::new();
+
+        let increment = scene.input::<Trigger>("increment").unwrap();
+        let decrement = scene.input::<Trigger>("decrement").unwrap();
+        let reset = scene.input::<Trigger>("reset").unwrap();
+
+        let sinks = [increment.sink(), decrement.sink(), reset.sink()];
+
+        // Each source renders its index as red
+        let mut select = Select::<Rgb, _>::with_value(Counter {
+            step,
+            overflow,
+            initial,
+            increment: Some(increment),
+            decrement: Some(decrement),
+            reset: Some(reset),
+        });
+        for i in 0..4 {
+            let color: Rgb = Rgb::new(i as f32 / 4.0, 0.0, 0.0);
+            let solid = scene
+                .node(&format!("solid-{i}"), Solid {
+                    color: color.fixed(),
+                })
+                .unwrap();
+            select = select.with_source(solid);
+        }
+
+        let select = scene.node("select", select).unwrap();
+
+        let mut renderer = Renderer::new(scene, select, 1).await.unwrap();
+        renderer.render(Duration::ZERO).await.unwrap();
+
+        let mut values = Vec::new();
+        for action in actions {
+            let sink = &sinks[*action as usize];
+
+            let (sent, frame) = tokio::join!(sink.send_any(AnyInputValue::Trigger), renderer.render(Duration::ZERO));
+            sent.unwrap();
+            frame.unwrap();
+
+            let frame = renderer.render(Duration::ZERO).await.unwrap();
+            values.push((frame[0].red * 4.0).round() as usize);
+        }
+
+        return values;
+    }
+
+    #[tokio::test]
+    async fn wrap() {
+        use Action::*;
+
+        let values =
+            run(1, Overflow::Wrap, None, &[Increment, Increment, Increment, Increment, Decrement, Decrement]).await;
+        assert_eq!(values, vec![1, 2, 3, 0, 3, 2]);
+    }
+
+    #[tokio::test]
+    async fn saturate() {
+        use Action::*;
+
+        let values = run(2, Overflow::Saturate, None, &[Increment, Increment, Decrement, Decrement, Decrement]).await;
+        assert_eq!(values, vec![2, 3, 1, 0, 0]);
+    }
+
+    #[tokio::test]
+    async fn reset() {
+        use Action::*;
+
+        let values = run(1, Overflow::Wrap, Some(2), &[Increment, Reset, Decrement, Decrement, Reset]).await;
+        assert_eq!(values, vec![3, 2, 1, 0, 2]);
+    }
+
+    #[test]
+    fn count_at_type_limits() {
+        assert_eq!(counter(u8::MIN, u8::MAX, 1, Overflow::Wrap, u8::MAX).count(1), u8::MIN);
+        assert_eq!(counter(u8::MIN, u8::MAX, 1, Overflow::Wrap, u8::MIN).count(-1), u8::MAX);
+        assert_eq!(counter(u8::MIN, u8::MAX, 200, Overflow::Wrap, 100).count(1), 44);
+        assert_eq!(counter(u8::MIN, u8::MAX, 200, Overflow::Saturate, 100).count(1), u8::MAX);
+
+        assert_eq!(counter(i64::MIN, i64::MAX, 1, Overflow::Wrap, i64::MAX).count(1), i64::MIN);
+        assert_eq!(counter(i64::MIN, i64::MAX, 1, Overflow::Wrap, i64::MIN).count(-1), i64::MAX);
+        assert_eq!(counter(i64::MIN, i64::MAX, i64::MAX, Overflow::Saturate, 0).count(2), i64::MAX);
+        assert_eq!(counter(i64::MIN, i64::MAX, i64::MAX, Overflow::Saturate, 0).count(-2), i64::MIN);
+
+        assert_eq!(counter(u64::MIN, u64::MAX, u64::MAX, Overflow::Wrap, 1).count(1), 0);
+        assert_eq!(counter(u64::MIN, u64::MAX, u64::MAX, Overflow::Saturate, 1).count(1), u64::MAX);
+    }
+
+    #[test]
+    fn count_step_larger_than_range() {
+        assert_eq!(counter(10, 14, 7, Overflow::Wrap, 10).count(1), 12);
+        assert_eq!(counter(10, 14, 7, Overflow::Wrap, 10).count(-1), 13);
+        assert_eq!(counter(10, 14, 7, Overflow::Saturate, 10).count(1), 14);
+        assert_eq!(counter(10, 14, 7, Overflow::Saturate, 14).count(-1), 10);
+
+        // A step of a multiple of the range size wraps onto the same value
+        assert_eq!(counter(10, 14, 5, Overflow::Wrap, 12).count(1), 12);
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use anyhow::bail;
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::DynBoundAttrDecl;
+    use photonic::input;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config<V> {
+        pub step: Option<V>,
+
+        #[serde(default)]
+        pub overflow: Overflow,
+
+        pub initial: Option<V>,
+
+        pub increment: Option<config::Input>,
+        pub decrement: Option<config::Input>,
+        pub reset: Option<config::Input>,
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'counter' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + PrimInt
+    {
+        type Product = Counter<V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Counter {
+                step: config.step.unwrap_or_else(V::one),
+                overflow: config.overflow,
+                initial: config.initial,
+                increment: config.increment.map(|input| builder.input(input)).transpose()?,
+                decrement: config.decrement.map(|input| builder.input(input)).transpose()?,
+                reset: config.reset.map(|input| builder.input(input)).transpose()?,
+            });
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+
+use photonic::attr::{Bounded, Bounds, FixedAttrDecl};
+use photonic::math::Lerp;
+use photonic::{scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl, FreeAttrDecl, Random};
+
+use crate::easing::Easing;
+
+pub struct DrunkardAttr<V, Interval, Speed>
+where
+    V: AttrValue + Lerp,
+    Interval: Attr<Duration>,
+    Speed: Attr<Duration>,
+{
+    interval: Interval,
+    fade: Easing<f32, Speed>,
+
+    bounds: Bounds<V>,
+
+    /// Positions relative to the bounds
+    current: f32,
+    from: f32,
+    target: f32,
+
+    progress: f32,
+
+    random: Random,
+}
+
+impl<V, Interval, Speed> DrunkardAttr<V, Interval, Speed>
+where
+    V: AttrValue + Lerp,
+    Interval: Attr<Duration>,
+    Speed: Attr<Duration>,
+{
+    fn new(interval: Interval, fade: Easing<f32, Speed>, bounds: Bounds<V>) -> Self {
+        let mut random = Random::new();
+
+        // Start at a random position
+        let current = random.range(0.0, 1.0);
+
+        return Self {
+            interval,
+            fade,
+            bounds,
+            current,
+            from: current,
+            target: current,
+            progress: 1.0,
+            random,
+        };
+    }
+}
+
+impl<V, Interval, Speed> Attr<V> for DrunkardAttr<V, Interval, Speed>
+where
+    V: AttrValue + Lerp,
+    Interval: Attr<Duration>,
+    Speed: Attr<Duration>,
+{
+    fn update(&mut self, ctx: &scene::RenderContext) -> V {
+        let interval = self.interval.update(ctx);
+        let speed = self.fade.speed.update(ctx);
+
+        // New targets are picked with the interval as mean time between them
+        if interval.is_zero() || self.random.rate(1.0 / interval.as_secs_f64(), ctx.duration) {
+            self.from = self.current;
+            self.target = self.random.range(0.0, 1.0);
+            self.progress = 0.0;
+        }
+
+        self.progress = if speed.is_zero() {
+            1.0
+        } else {
+            (self.progress + ctx.duration.as_secs_f32() / speed.as_secs_f32()).min(1.0)
+        };
+
+        let t = self.fade.curve.apply(self.progress);
+        self.current = f32::lerp(self.from, self.target, t);
+
+        return V::lerp(self.bounds.min, self.bounds.max, self.current);
+    }
+}
+
+/// Fades to random targets.
+///
+/// New targets are picked within the bounds at random intervals, averaging the given interval. The value eases from
+/// its current position to each new target.
+///
+/// Unbound attributes pick targets within the given bounds, bound attributes within the bounds they are materialized
+/// with.
+pub struct Drunkard<Interval, V, Speed = FixedAttrDecl<Duration>> {
+    pub interval: Interval,
+    pub fade: Easing<f32, Speed>,
+    pub bounds: Option<Bounds<V>>,
+}
+
+impl<V, Interval, Speed> BoundAttrDecl<V> for Drunkard<Interval, V, Speed>
+where
+    V: AttrValue + Bounded + Lerp,
+    Interval: FreeAttrDecl<Duration>,
+    Speed: FreeAttrDecl<Duration>,
+{
+    const KIND: &'static str = "drunkard";
+
+    type Attr = DrunkardAttr<V, Interval::Attr, Speed::Attr>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let interval = builder.unbound_attr("interval", self.interval).await?;
+        let fade = Easing {
+            curve: self.fade.curve,
+            speed: builder.unbound_attr("speed", self.fade.speed).await?,
+        };
+
+        return Ok(DrunkardAttr::new(interval, fade, bounds));
+    }
+}
+
+impl<V, Interval, Speed> FreeAttrDecl<V> for Drunkard<Interval, V, Speed>
+where
+    V: AttrValue + Lerp,
+    Interval: FreeAttrDecl<Duration>,
+    Speed: FreeAttrDecl<Duration>,
+{
+    const KIND: &'static str = "drunkard";
+
+    type Attr = DrunkardAttr<V, Interval::Attr, Speed::Attr>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let Some(bounds) = self.bounds else {
+            bail!("Drunkard of an unbound attribute requires bounds");
+        };
+
+        let interval = builder.unbound_attr("interval", self.interval).await?;
+        let fade = Easing {
+            curve: self.fade.curve,
+            speed: builder.unbound_attr("speed", self.fade.speed).await?,
+        };
+
+        return Ok(DrunkardAttr::new(interval, fade, bounds));
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedFreeAttrDecl, DynBoundAttrDecl, DynFreeAttrDecl};
+    use photonic::input;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+    use crate::easing;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config<V>
+    where V: AttrValue
+    {
+        pub interval: config::Attr<Duration>,
+        pub fade: easing::dynamic::Config,
+
+        /// Bounds to pick targets within for unbound attributes - bound attributes use their own bounds
+        pub min: Option<V>,
+        pub max: Option<V>,
+    }
+
+    type BoxedDrunkard<V> = Drunkard<BoxedFreeAttrDecl<Duration>, V, BoxedFreeAttrDecl<Duration>>;
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'drunkard' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Lerp
+    {
+        type Product = BoxedDrunkard<V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            let (Some(min), Some(max)) = (config.min, config.max) else {
+                bail!("Attribute 'drunkard' requires 'min' and 'max' if unbound");
+            };
+
+            return Ok(Drunkard {
+                interval: builder.free_attr("interval", config.interval)?,
+                fade: config.fade.func.with_speed_attr(builder.free_attr("speed", config.fade.speed)?)?,
+                bounds: Some(Bounds {
+                    min,
+                    max,
+                }),
+            });
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'drunkard' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + Lerp
+    {
+        type Product = BoxedDrunkard<V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Drunkard {
+                interval: builder.free_attr("interval", config.interval)?,
+                fade: config.fade.func.with_speed_attr(builder.free_attr("speed", config.fade.speed)?)?,
+                bounds: None,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use palette::rgb::Rgb;
+
+    use photonic::attr::AsFixedAttr;
+    use photonic::Scene;
+
+    use crate::easing::{Easing, Easings};
+    use crate::nodes::Solid;
+    use crate::testing::Renderer;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn color() {
+        let mut scene = Scene::new();
+
+        let solid = scene
+            .node("solid", Solid {
+                color: Drunkard {
+                    interval: Duration::from_millis(200).fixed(),
+                    fade: Easing::from(Easings::Linear).with_speed(Duration::from_millis(300)),
+                    bounds: Some(Bounds {
+                        min: Rgb::new(1.0, 0.0, 0.0),
+                        max: Rgb::new(0.0, 0.0, 1.0),
+                    }),
+                },
+            })
+            .unwrap();
+
+        let mut renderer = Renderer::new(scene, solid, 1).await.unwrap();
+
+        for _ in 0..100 {
+            let color = renderer.render(Duration::from_millis(100)).await.unwrap()[0];
+            assert_eq!(color.green, 0.0);
+            assert!((color.red + color.blue - 1.0).abs() < 1e-5);
+        }
+    }
+}
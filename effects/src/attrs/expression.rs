@@ -0,0 +1,640 @@
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use anyhow::{anyhow, bail, Result};
+use num_traits::{NumCast, ToPrimitive, Zero};
+
+use photonic::attr::{Bounded, Bounds};
+use photonic::{scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl, FreeAttrDecl};
+
+/// Name of the built-in variable holding the seconds elapsed since the attribute was created
+const TIME: &str = "time";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Func {
+    Min,
+    Max,
+    Clamp,
+    Abs,
+    Floor,
+    Ceil,
+    Sqrt,
+    Sin,
+    Cos,
+    Lerp,
+}
+
+impl Func {
+    fn parse(name: &str) -> Option<Self> {
+        return Some(match name {
+            "min" => Self::Min,
+            "max" => Self::Max,
+            "clamp" => Self::Clamp,
+            "abs" => Self::Abs,
+            "floor" => Self::Floor,
+            "ceil" => Self::Ceil,
+            "sqrt" => Self::Sqrt,
+            "sin" => Self::Sin,
+            "cos" => Self::Cos,
+            "lerp" => Self::Lerp,
+            _ => return None,
+        });
+    }
+
+    fn arity(self, args: usize) -> bool {
+        return match self {
+            Self::Min | Self::Max => args >= 1,
+            Self::Abs | Self::Floor | Self::Ceil | Self::Sqrt | Self::Sin | Self::Cos => args == 1,
+            Self::Clamp | Self::Lerp => args == 3,
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+    And,
+    Or,
+}
+
+fn truthy(value: f32) -> bool {
+    return value != 0.0;
+}
+
+fn boolean(value: bool) -> f32 {
+    return if value { 1.0 } else { 0.0 };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Number(f32),
+    Var(usize),
+    Neg(Box<Expr>),
+    Not(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+    Cond(Box<Expr>, Box<Expr>, Box<Expr>),
+    Call(Func, Vec<Expr>),
+}
+
+impl Expr {
+    fn eval(&self, vars: &[f32]) -> f32 {
+        return match self {
+            Expr::Number(value) => *value,
+            Expr::Var(index) => vars[*index],
+            Expr::Neg(expr) => -expr.eval(vars),
+            Expr::Not(expr) => boolean(!truthy(expr.eval(vars))),
+            Expr::Binary(Op::And, a, b) => boolean(truthy(a.eval(vars)) && truthy(b.eval(vars))),
+            Expr::Binary(Op::Or, a, b) => boolean(truthy(a.eval(vars)) || truthy(b.eval(vars))),
+            Expr::Binary(op, a, b) => {
+                let (a, b) = (a.eval(vars), b.eval(vars));
+                match op {
+                    Op::Add => a + b,
+                    Op::Sub => a - b,
+                    Op::Mul => a * b,
+                    Op::Div => a / b,
+                    Op::Rem => a % b,
+                    Op::Pow => a.powf(b),
+                    Op::Lt => boolean(a < b),
+                    Op::Le => boolean(a <= b),
+                    Op::Gt => boolean(a > b),
+                    Op::Ge => boolean(a >= b),
+                    Op::Eq => boolean(a == b),
+                    Op::Ne => boolean(a != b),
+                    Op::And | Op::Or => unreachable!("Evaluated lazily"),
+                }
+            }
+            Expr::Cond(cond, a, b) => {
+                if truthy(cond.eval(vars)) {
+                    a.eval(vars)
+                } else {
+                    b.eval(vars)
+                }
+            }
+            Expr::Call(Func::Min, args) => args.iter().map(|arg| arg.eval(vars)).fold(f32::INFINITY, f32::min),
+            Expr::Call(Func::Max, args) => args.iter().map(|arg| arg.eval(vars)).fold(f32::NEG_INFINITY, f32::max),
+            Expr::Call(Func::Clamp, args) => args[0].eval(vars).max(args[1].eval(vars)).min(args[2].eval(vars)),
+            Expr::Call(Func::Abs, args) => args[0].eval(vars).abs(),
+            Expr::Call(Func::Floor, args) => args[0].eval(vars).floor(),
+            Expr::Call(Func::Ceil, args) => args[0].eval(vars).ceil(),
+            Expr::Call(Func::Sqrt, args) => args[0].eval(vars).sqrt(),
+            Expr::Call(Func::Sin, args) => args[0].eval(vars).sin(),
+            Expr::Call(Func::Cos, args) => args[0].eval(vars).cos(),
+            Expr::Call(Func::Lerp, args) => {
+                let (a, b, t) = (args[0].eval(vars), args[1].eval(vars), args[2].eval(vars));
+                a + (b - a) * t
+            }
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token<'s> {
+    Number(f32),
+    Ident(&'s str),
+    Symbol(&'static str),
+}
+
+struct Lexer<'s> {
+    source: &'s str,
+    chars: Peekable<CharIndices<'s>>,
+}
+
+impl<'s> Lexer<'s> {
+    /// Symbols in matching order - longer symbols first
+    const SYMBOLS: [&'static str; 20] =
+        ["<=", ">=", "==", "!=", "&&", "||", "+", "-", "*", "/", "%", "^", "(", ")", ",", "?", ":", "<", ">", "!"];
+
+    fn tokenize(source: &'s str) -> Result<Vec<(usize, Token<'s>)>> {
+        let mut lexer = Self {
+            source,
+            chars: source.char_indices().peekable(),
+        };
+
+        let mut tokens = Vec::new();
+        while let Some(&(start, c)) = lexer.chars.peek() {
+            if c.is_whitespace() {
+                lexer.chars.next();
+            } else if c.is_ascii_digit() || c == '.' {
+                let end = lexer.skip_while(|c| c.is_ascii_digit() || c == '.');
+                let number = &source[start..end];
+                let number = number.parse().map_err(|_| anyhow!("Invalid number at {start}: {number}"))?;
+                tokens.push((start, Token::Number(number)));
+            } else if c.is_alphabetic() || c == '_' {
+                let end = lexer.skip_while(|c| c.is_alphanumeric() || c == '_');
+                tokens.push((start, Token::Ident(&source[start..end])));
+            } else {
+                let symbol = Self::SYMBOLS
+                    .into_iter()
+                    .find(|symbol| source[start..].starts_with(symbol))
+                    .ok_or_else(|| anyhow!("Unexpected character at {start}: '{c}'"))?;
+                for _ in 0..symbol.len() {
+                    lexer.chars.next();
+                }
+                tokens.push((start, Token::Symbol(symbol)));
+            }
+        }
+
+        return Ok(tokens);
+    }
+
+    fn skip_while(&mut self, f: impl Fn(char) -> bool) -> usize {
+        while let Some(&(_, c)) = self.chars.peek() {
+            if !f(c) {
+                break;
+            }
+            self.chars.next();
+        }
+
+        return self.chars.peek().map_or(self.source.len(), |&(i, _)| i);
+    }
+}
+
+/// Recursive descent parser resolving variable names to indices.
+struct Parser<'s, 'v> {
+    tokens: Vec<(usize, Token<'s>)>,
+    position: usize,
+
+    vars: &'v HashMap<&'s str, usize>,
+}
+
+impl<'s> Parser<'s, '_> {
+    fn parse(source: &'s str, vars: &HashMap<&'s str, usize>) -> Result<Expr> {
+        let mut parser = Parser {
+            tokens: Lexer::tokenize(source)?,
+            position: 0,
+            vars,
+        };
+
+        let expr = parser.expr()?;
+
+        if let Some((position, token)) = parser.tokens.get(parser.position) {
+            bail!("Unexpected token at {position}: {token:?}");
+        }
+
+        return Ok(expr);
+    }
+
+    fn peek(&self) -> Option<&Token<'s>> {
+        return self.tokens.get(self.position).map(|(_, token)| token);
+    }
+
+    fn accept(&mut self, symbols: &[&'static str]) -> Option<&'static str> {
+        if let Some(Token::Symbol(symbol)) = self.peek() {
+            if symbols.contains(symbol) {
+                let symbol = *symbol;
+                self.position += 1;
+                return Some(symbol);
+            }
+        }
+
+        return None;
+    }
+
+    fn expect(&mut self, symbol: &'static str) -> Result<()> {
+        if self.accept(&[symbol]).is_none() {
+            return Err(self.unexpected(&format!("'{symbol}'")));
+        }
+
+        return Ok(());
+    }
+
+    fn unexpected(&self, expected: &str) -> anyhow::Error {
+        return match self.tokens.get(self.position) {
+            Some((position, token)) => anyhow!("Expected {expected} at {position}, got {token:?}"),
+            None => anyhow!("Expected {expected}, got end of expression"),
+        };
+    }
+
+    fn binary(&mut self, ops: &[(&'static str, Op)], next: fn(&mut Self) -> Result<Expr>) -> Result<Expr> {
+        let symbols = ops.iter().map(|(symbol, _)| *symbol).collect::<Vec<_>>();
+
+        let mut expr = next(self)?;
+        while let Some(symbol) = self.accept(&symbols) {
+            let op = ops.iter().find(|(s, _)| *s == symbol).expect("Symbol accepted").1;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(next(self)?));
+        }
+
+        return Ok(expr);
+    }
+
+    fn expr(&mut self) -> Result<Expr> {
+        let cond = self.or()?;
+
+        if self.accept(&["?"]).is_none() {
+            return Ok(cond);
+        }
+
+        let a = self.expr()?;
+        self.expect(":")?;
+        let b = self.expr()?;
+
+        return Ok(Expr::Cond(Box::new(cond), Box::new(a), Box::new(b)));
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        return self.binary(&[("||", Op::Or)], Self::and);
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        return self.binary(&[("&&", Op::And)], Self::cmp);
+    }
+
+    fn cmp(&mut self) -> Result<Expr> {
+        return self.binary(
+            &[("<=", Op::Le), (">=", Op::Ge), ("==", Op::Eq), ("!=", Op::Ne), ("<", Op::Lt), (">", Op::Gt)],
+            Self::add,
+        );
+    }
+
+    fn add(&mut self) -> Result<Expr> {
+        return self.binary(&[("+", Op::Add), ("-", Op::Sub)], Self::mul);
+    }
+
+    fn mul(&mut self) -> Result<Expr> {
+        return self.binary(&[("*", Op::Mul), ("/", Op::Div), ("%", Op::Rem)], Self::unary);
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        return match self.accept(&["-", "!"]) {
+            Some("-") => Ok(Expr::Neg(Box::new(self.unary()?))),
+            Some(_) => Ok(Expr::Not(Box::new(self.unary()?))),
+            None => self.pow(),
+        };
+    }
+
+    fn pow(&mut self) -> Result<Expr> {
+        let base = self.atom()?;
+
+        // Exponentiation is right-associative and binds tighter than unary minus on its left
+        if self.accept(&["^"]).is_some() {
+            return Ok(Expr::Binary(Op::Pow, Box::new(base), Box::new(self.unary()?)));
+        }
+
+        return Ok(base);
+    }
+
+    fn atom(&mut self) -> Result<Expr> {
+        let Some((position, token)) = self.tokens.get(self.position).cloned() else {
+            return Err(self.unexpected("value"));
+        };
+
+        return match token {
+            Token::Number(value) => {
+                self.position += 1;
+                Ok(Expr::Number(value))
+            }
+
+            Token::Symbol("(") => {
+                self.position += 1;
+                let expr = self.expr()?;
+                self.expect(")")?;
+                Ok(expr)
+            }
+
+            Token::Ident(name) => {
+                self.position += 1;
+
+                if self.accept(&["("]).is_none() {
+                    return match (self.vars.get(name), name) {
+                        (Some(index), _) => Ok(Expr::Var(*index)),
+                        (None, "pi") => Ok(Expr::Number(std::f32::consts::PI)),
+                        (None, _) => bail!("Unknown variable at {position}: {name}"),
+                    };
+                }
+
+                let mut args = Vec::new();
+                if self.accept(&[")"]).is_none() {
+                    loop {
+                        args.push(self.expr()?);
+                        if self.accept(&[","]).is_none() {
+                            break;
+                        }
+                    }
+                    self.expect(")")?;
+                }
+
+                if name == "if" {
+                    let [cond, a, b]: [Expr; 3] =
+                        args.try_into().map_err(|_| anyhow!("Invalid number of arguments for 'if' at {position}"))?;
+                    return Ok(Expr::Cond(Box::new(cond), Box::new(a), Box::new(b)));
+                }
+
+                let func = Func::parse(name).ok_or_else(|| anyhow!("Unknown function at {position}: {name}"))?;
+                if !func.arity(args.len()) {
+                    bail!("Invalid number of arguments for '{name}' at {position}: {}", args.len());
+                }
+
+                Ok(Expr::Call(func, args))
+            }
+
+            Token::Symbol(_) => Err(self.unexpected("value")),
+        };
+    }
+}
+
+/// Parses an expression over the given named variables.
+///
+/// The built-in variable `time` is always available at index `0`. All other variables are numbered in the given order
+/// starting at index `1`.
+fn parse<'s>(source: &'s str, vars: impl IntoIterator<Item = &'s str>) -> Result<Expr> {
+    let vars = std::iter::once(TIME).chain(vars).enumerate().map(|(i, name)| (name, i)).collect::<HashMap<_, _>>();
+
+    return Parser::parse(source, &vars);
+}
+
+pub struct ExpressionAttr<V, Var>
+where Var: Attr<f32>
+{
+    expr: Expr,
+
+    vars: Vec<Var>,
+    values: Vec<f32>,
+
+    bounds: Option<Bounds<f32>>,
+
+    phantom: std::marker::PhantomData<V>,
+}
+
+impl<V, Var> Attr<V> for ExpressionAttr<V, Var>
+where
+    V: AttrValue + NumCast + Zero,
+    Var: Attr<f32>,
+{
+    fn update(&mut self, ctx: &scene::RenderContext) -> V {
+        self.values[0] += ctx.duration.as_secs_f32();
+
+        for (var, value) in self.vars.iter_mut().zip(self.values[1..].iter_mut()) {
+            *value = var.update(ctx);
+        }
+
+        let value = self.expr.eval(&self.values);
+
+        let value = match self.bounds {
+            Some(bounds) => value.max(bounds.min).min(bounds.max),
+            None => value,
+        };
+
+        return V::from(value).unwrap_or_else(V::zero);
+    }
+}
+
+/// An attribute calculated from a math expression.
+///
+/// The expression is evaluated every frame and can refer to the named variables, which are child attributes, and the
+/// built-in `time` (seconds since start) and `pi`. It supports arithmetic (`+ - * / % ^`), comparisons and logic
+/// (`< <= > >= == != && || !`, true is any value other than `0`), conditionals (`c ? a : b` and `if(c, a, b)`) and the
+/// functions `min`, `max`, `clamp`, `abs`, `floor`, `ceil`, `sqrt`, `sin`, `cos` and `lerp`.
+///
+/// Bound expression attributes are clamped to their bounds.
+pub struct Expression<Var> {
+    pub expression: String,
+    pub vars: HashMap<String, Var>,
+}
+
+impl<Var> Expression<Var> {
+    async fn build<V>(
+        self,
+        bounds: Option<Bounds<f32>>,
+        builder: &mut AttrBuilder<'_>,
+    ) -> Result<ExpressionAttr<V, Var::Attr>>
+    where
+        Var: FreeAttrDecl<f32>,
+    {
+        let (names, decls): (Vec<_>, Vec<_>) = self.vars.into_iter().unzip();
+
+        let expr = parse(&self.expression, names.iter().map(String::as_str))
+            .map_err(|err| anyhow!("Invalid expression '{}': {}", self.expression, err))?;
+
+        let mut vars = Vec::with_capacity(decls.len());
+        for (name, decl) in names.iter().zip(decls) {
+            vars.push(builder.unbound_attr(name, decl).await?);
+        }
+
+        return Ok(ExpressionAttr {
+            expr,
+            values: vec![0.0; vars.len() + 1],
+            vars,
+            bounds,
+            phantom: std::marker::PhantomData,
+        });
+    }
+}
+
+impl<V, Var> FreeAttrDecl<V> for Expression<Var>
+where
+    V: AttrValue + NumCast + Zero,
+    Var: FreeAttrDecl<f32>,
+{
+    const KIND: &'static str = "expression";
+
+    type Attr = ExpressionAttr<V, Var::Attr>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return self.build(None, builder).await;
+    }
+}
+
+impl<V, Var> BoundAttrDecl<V> for Expression<Var>
+where
+    V: AttrValue + Bounded + NumCast + ToPrimitive + Zero,
+    Var: FreeAttrDecl<f32>,
+{
+    const KIND: &'static str = "expression";
+
+    type Attr = ExpressionAttr<V, Var::Attr>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let bounds = Bounds {
+            min: bounds.min.to_f32().ok_or_else(|| anyhow!("Lower bound out of range"))?,
+            max: bounds.max.to_f32().ok_or_else(|| anyhow!("Upper bound out of range"))?,
+        };
+
+        return self.build(Some(bounds), builder).await;
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedFreeAttrDecl, DynBoundAttrDecl, DynFreeAttrDecl};
+    use photonic::input;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub expression: String,
+
+        #[serde(default)]
+        pub vars: HashMap<String, config::Attr<f32>>,
+    }
+
+    type BoxedExpression = Expression<BoxedFreeAttrDecl<f32>>;
+
+    fn produce<Reg: Registry>(config: Config, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<BoxedExpression> {
+        let vars = config
+            .vars
+            .into_iter()
+            .map(|(name, var)| Ok((name.clone(), builder.free_attr(&name, var)?)))
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        return Ok(Expression {
+            expression: config.expression,
+            vars,
+        });
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'expression' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + NumCast + Zero
+    {
+        type Product = BoxedExpression;
+
+        fn produce<Reg: Registry>(config: Self, builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return produce(config, builder);
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'expression' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + NumCast + ToPrimitive + Zero
+    {
+        type Product = BoxedExpression;
+
+        fn produce<Reg: Registry>(config: Self, builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return produce(config, builder);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn eval(source: &str, vars: &[(&str, f32)]) -> f32 {
+        let expr = parse(source, vars.iter().map(|(name, _)| *name)).expect("Valid expression");
+        let values = std::iter::once(0.0).chain(vars.iter().map(|(_, value)| *value)).collect::<Vec<_>>();
+        return expr.eval(&values);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(eval("1 + 2 * 3", &[]), 7.0);
+        assert_eq!(eval("(1 + 2) * 3", &[]), 9.0);
+        assert_eq!(eval("-2 ^ 2", &[]), -4.0);
+        assert_eq!(eval("2 ^ 3 ^ 2", &[]), 512.0);
+        assert_eq!(eval("7 % 4 - 1", &[]), 2.0);
+    }
+
+    #[test]
+    fn variables() {
+        assert_eq!(eval("a * b + time", &[("a", 2.0), ("b", 3.0)]), 6.0);
+        assert!(parse("c", ["a"]).is_err());
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(eval("min(3, 1, 2) + max(1, 5)", &[]), 6.0);
+        assert_eq!(eval("clamp(1.5, 0, 1)", &[]), 1.0);
+        assert_eq!(eval("lerp(2, 4, 0.5)", &[]), 3.0);
+        assert_eq!(eval("sin(pi / 2)", &[]), 1.0);
+        assert!(parse("clamp(1)", []).is_err());
+        assert!(parse("foo(1)", []).is_err());
+    }
+
+    #[test]
+    fn conditionals() {
+        assert_eq!(eval("a > 0.5 ? 1 : 2", &[("a", 0.7)]), 1.0);
+        assert_eq!(eval("if(a > 0.5 && !b, 1, 2)", &[("a", 0.7), ("b", 1.0)]), 2.0);
+        assert_eq!(eval("a == 1 || a != 1", &[("a", 0.0)]), 1.0);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse("1 +", []).is_err());
+        assert!(parse("(1", []).is_err());
+        assert!(parse("1 2", []).is_err());
+        assert!(parse("1 = 2", []).is_err());
+    }
+}
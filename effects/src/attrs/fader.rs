@@ -1,111 +1,163 @@
+use std::time::Duration;
+
 use anyhow::Result;
 
-use photonic::attr::{Bounded, Bounds};
+use photonic::attr::{Bounded, Bounds, FixedAttrDecl};
 use photonic::math::Lerp;
 use photonic::{scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl, FreeAttrDecl};
 
-use crate::easing::Easing;
+use crate::easing::{Easing, TransitionPolicy};
 
-pub struct FaderAttr<V, Input>
+pub struct FaderAttr<V, Input, Speed>
 where
     V: AttrValue + PartialEq + Lerp,
     Input: Attr<V>,
+    Speed: Attr<Duration>,
 {
     input: Input,
 
-    last: Option<V>,
-    next: Option<V>,
+    policy: TransitionPolicy,
+
+    /// Last value seen from the input
+    seen: Option<V>,
+
+    /// Last value returned by the fader
+    current: Option<V>,
+
+    /// Running transition between two values
+    transition: Option<(V, V)>,
+    queued: Option<V>,
 
     fade: f32,
 
-    easing: Easing<f32>,
+    easing: Easing<f32, Speed>,
 }
 
-impl<V, Input> Attr<V> for FaderAttr<V, Input>
+impl<V, Input, Speed> Attr<V> for FaderAttr<V, Input, Speed>
 where
     V: AttrValue + PartialEq + Lerp,
     Input: Attr<V>,
+    Speed: Attr<Duration>,
 {
     fn update(&mut self, ctx: &scene::RenderContext) -> V {
         let curr = self.input.update(ctx);
+        let speed = self.easing.speed.update(ctx);
 
-        let Some(last) = self.last else {
+        let (Some(seen), Some(current)) = (self.seen, self.current) else {
             // First cycle - set initial current value
-            self.last = Some(curr);
+            self.seen = Some(curr);
+            self.current = Some(curr);
             return curr;
         };
 
-        if let Some(next) = self.next {
-            // In transition
-            self.fade += ctx.duration.as_secs_f32() / self.easing.speed.as_secs_f32();
-
-            if self.fade >= 1.0 {
-                // Transition finished
-                self.last = Some(next);
-                self.next = None;
-
-                return next;
+        if curr != seen {
+            self.seen = Some(curr);
+
+            match (self.transition, self.policy) {
+                (None, _) | (Some(_), TransitionPolicy::Retarget) => {
+                    // Start transition from wherever the fader is right now
+                    self.transition = Some((current, curr));
+                    self.queued = None;
+                    self.fade = 0.0;
+                }
+                (Some(_), TransitionPolicy::Queue) => {
+                    self.queued = Some(curr);
+                }
+                (Some(_), TransitionPolicy::Ignore) => {}
             }
-
-            return Lerp::lerp(last, next, (self.easing.func)(self.fade));
         }
 
-        if curr != last {
-            // Start transition
-            self.next = Some(curr);
+        let Some((from, to)) = self.transition else {
+            return current;
+        };
+
+        // In transition
+        self.fade += ctx.duration.as_secs_f32() / speed.as_secs_f32();
+
+        if self.fade >= 1.0 {
+            // Transition finished - continue with the queued target, if any
+            self.transition = self.queued.take().map(|next| (to, next));
             self.fade = 0.0;
+
+            self.current = Some(to);
+            return to;
         }
 
-        return last;
+        let value = Lerp::lerp(from, to, self.easing.curve.apply(self.fade));
+
+        self.current = Some(value);
+        return value;
     }
 }
 
-pub struct Fader<Input> {
+/// Fades between the values of the input.
+///
+/// Every time the input changes, the fader transitions to the new value using the easing. The policy defines what
+/// happens if the input changes while a transition is still running.
+pub struct Fader<Input, Speed = FixedAttrDecl<Duration>> {
     pub input: Input,
 
-    pub easing: Easing<f32>,
+    pub easing: Easing<f32, Speed>,
+
+    pub policy: TransitionPolicy,
 }
 
-impl<Input, V> BoundAttrDecl<V> for Fader<Input>
+impl<Input, Speed, V> BoundAttrDecl<V> for Fader<Input, Speed>
 where
     V: AttrValue + PartialEq + Lerp + Bounded,
     Input: BoundAttrDecl<V>,
+    Speed: FreeAttrDecl<Duration>,
 {
     const KIND: &'static str = "fader";
 
-    type Attr = FaderAttr<V, Input::Attr>;
+    type Attr = FaderAttr<V, Input::Attr, Speed::Attr>;
 
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<Self::Attr> {
-        let input = builder.bound_attr("input", self.input, bounds)?;
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let input = builder.bound_attr("input", self.input, bounds).await?;
+        let speed = builder.unbound_attr("speed", self.easing.speed).await?;
 
         return Ok(FaderAttr {
             input,
-            last: None,
-            next: None,
-            fade: 1.0,
-            easing: self.easing,
+            policy: self.policy,
+            seen: None,
+            current: None,
+            transition: None,
+            queued: None,
+            fade: 0.0,
+            easing: Easing {
+                curve: self.easing.curve,
+                speed,
+            },
         });
     }
 }
 
-impl<Input, V> FreeAttrDecl<V> for Fader<Input>
+impl<Input, Speed, V> FreeAttrDecl<V> for Fader<Input, Speed>
 where
     V: AttrValue + PartialEq + Lerp,
     Input: FreeAttrDecl<V>,
+    Speed: FreeAttrDecl<Duration>,
 {
     const KIND: &'static str = "fader";
 
-    type Attr = FaderAttr<V, Input::Attr>;
+    type Attr = FaderAttr<V, Input::Attr, Speed::Attr>;
 
-    fn materialize(self, builder: &mut AttrBuilder) -> Result<Self::Attr> {
-        let input = builder.unbound_attr("input", self.input)?;
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let input = builder.unbound_attr("input", self.input).await?;
+        let speed = builder.unbound_attr("speed", self.easing.speed).await?;
 
         return Ok(FaderAttr {
             input,
-            last: None,
-            next: None,
-            fade: 1.0,
-            easing: self.easing,
+            policy: self.policy,
+            seen: None,
+            current: None,
+            transition: None,
+            queued: None,
+            fade: 0.0,
+            easing: Easing {
+                curve: self.easing.curve,
+                speed,
+            },
         });
     }
 }
@@ -124,13 +176,17 @@ pub mod dynamic {
     use photonic_dynamic::{builder, config};
 
     use super::*;
+    use crate::easing;
 
     #[derive(Deserialize, Debug)]
     pub struct Config<V>
     where V: AttrValue
     {
         pub input: config::Attr<V>,
-        pub easing: Easing<f32>,
+        pub easing: easing::dynamic::Config,
+
+        #[serde(default)]
+        pub policy: TransitionPolicy,
     }
 
     impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
@@ -149,12 +205,13 @@ pub mod dynamic {
     impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
     where V: AttrValue + input::Coerced + DeserializeOwned + Lerp
     {
-        type Product = Fader<BoxedFreeAttrDecl<V>>;
+        type Product = Fader<BoxedFreeAttrDecl<V>, BoxedFreeAttrDecl<Duration>>;
 
         fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Fader {
                 input: builder.free_attr("input", config.input)?,
-                easing: config.easing,
+                easing: config.easing.func.with_speed_attr(builder.free_attr("speed", config.easing.speed)?)?,
+                policy: config.policy,
             });
         }
     }
@@ -175,13 +232,64 @@ pub mod dynamic {
     impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
     where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + Lerp
     {
-        type Product = Fader<BoxedBoundAttrDecl<V>>;
+        type Product = Fader<BoxedBoundAttrDecl<V>, BoxedFreeAttrDecl<Duration>>;
 
         fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Fader {
                 input: builder.bound_attr("input", config.input)?,
-                easing: config.easing,
+                easing: config.easing.func.with_speed_attr(builder.free_attr("speed", config.easing.speed)?)?,
+                policy: config.policy,
             });
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use palette::rgb::Rgb;
+
+    use photonic::attr::AsFixedAttr;
+    use photonic::Scene;
+
+    use crate::attrs::rgb::RgbColor;
+    use crate::easing::Curve;
+    use crate::nodes::Solid;
+    use crate::testing::Renderer;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn overshooting_curve() {
+        let mut scene = Scene::new();
+
+        let value = scene.input::<f32>("value").unwrap();
+        let sink = value.sink();
+
+        let solid = scene
+            .node("solid", Solid {
+                color: RgbColor {
+                    red: Fader {
+                        input: value.attr(0.0),
+                        easing: Easing::new(Curve::CubicBezier(0.3, -0.8, 0.7, 1.8)),
+                        policy: TransitionPolicy::Retarget,
+                    },
+                    green: 0.0f32.fixed(),
+                    blue: 0.0f32.fixed(),
+                },
+            })
+            .unwrap();
+
+        let mut renderer = Renderer::new(scene, solid, 1).await.unwrap();
+        renderer.render(Duration::ZERO).await.unwrap();
+
+        let (sent, _) = tokio::join!(sink.send_any(1.0.into()), renderer.render(Duration::ZERO));
+        sent.unwrap();
+
+        for _ in 0..10 {
+            let frame = renderer.render(Duration::from_millis(100)).await.unwrap();
+            assert!((0.0..=1.0).contains(&frame[0].red));
+        }
+
+        assert_eq!(renderer.render(Duration::from_millis(100)).await.unwrap()[0], Rgb::new(1.0, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,350 @@
+use std::time::Duration;
+
+use anyhow::{bail, ensure, Result};
+
+use photonic::attr::{Bounded, Bounds};
+use photonic::input::{Input, Poll, Trigger};
+use photonic::math::Lerp;
+use photonic::scene::InputHandle;
+use photonic::{scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl, FreeAttrDecl};
+
+use crate::easing::Curve;
+
+#[derive(Debug, Clone)]
+pub struct Keyframe<V> {
+    /// Time of the keyframe relative to the start of the curve
+    pub time: Duration,
+
+    pub value: V,
+
+    /// Curve of the segment to the next keyframe
+    pub curve: Curve<f32>,
+}
+
+pub struct KeyframesAttr<V>
+where V: AttrValue + Lerp
+{
+    keyframes: Vec<Keyframe<V>>,
+    looping: bool,
+
+    restart: Option<Input<Trigger>>,
+
+    position: Duration,
+}
+
+impl<V> KeyframesAttr<V>
+where V: AttrValue + Lerp
+{
+    fn new(keyframes: Vec<Keyframe<V>>, looping: bool, restart: Option<Input<Trigger>>) -> Result<Self> {
+        ensure!(!keyframes.is_empty(), "Keyframes must not be empty");
+
+        if let Some(window) = keyframes.windows(2).find(|window| window[0].time > window[1].time) {
+            bail!("Keyframes must be ordered by time: {:?} after {:?}", window[1].time, window[0].time);
+        }
+
+        return Ok(Self {
+            keyframes,
+            looping,
+            restart,
+            position: Duration::ZERO,
+        });
+    }
+}
+
+impl<V> Attr<V> for KeyframesAttr<V>
+where V: AttrValue + Lerp
+{
+    fn update(&mut self, ctx: &scene::RenderContext) -> V {
+        let restarted = matches!(self.restart.as_mut().map(|input| input.poll(anyhow::Ok)), Some(Poll::Update(_)));
+
+        let length = self.keyframes.last().expect("Not empty").time;
+
+        self.position = if restarted { Duration::ZERO } else { self.position + ctx.duration };
+
+        if self.looping && !length.is_zero() {
+            self.position = Duration::from_secs_f64(self.position.as_secs_f64() % length.as_secs_f64());
+        }
+
+        // Find the segment containing the current position
+        let next = self.keyframes.partition_point(|keyframe| keyframe.time <= self.position);
+
+        if next == 0 {
+            return self.keyframes[0].value;
+        }
+
+        if next == self.keyframes.len() {
+            return self.keyframes[next - 1].value;
+        }
+
+        let (curr, next) = (&self.keyframes[next - 1], &self.keyframes[next]);
+
+        let t = (self.position - curr.time).as_secs_f32() / (next.time - curr.time).as_secs_f32();
+
+        // Overshooting curves are clipped as values can not be extrapolated
+        return V::lerp(curr.value, next.value, curr.curve.apply(t));
+    }
+}
+
+/// Animates through a list of keyframes.
+///
+/// Between two keyframes, the value is interpolated along the curve of the earlier keyframe. Before the first
+/// keyframe, its value is held - the same applies to the last keyframe if the animation does not loop. Looping
+/// animations restart after the last keyframe.
+///
+/// If a restart input is given, the animation restarts every time the input is triggered.
+pub struct Keyframes<V> {
+    pub keyframes: Vec<Keyframe<V>>,
+
+    pub looping: bool,
+
+    pub restart: Option<InputHandle<Trigger>>,
+}
+
+impl<V> BoundAttrDecl<V> for Keyframes<V>
+where V: AttrValue + Bounded + Lerp
+{
+    const KIND: &'static str = "keyframes";
+
+    type Attr = KeyframesAttr<V>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let keyframes = self
+            .keyframes
+            .into_iter()
+            .map(|keyframe| {
+                return Ok(Keyframe {
+                    value: bounds.ensure(keyframe.value)?,
+                    ..keyframe
+                });
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let restart = self.restart.map(|input| builder.input("restart", input)).transpose()?;
+
+        return KeyframesAttr::new(keyframes, self.looping, restart);
+    }
+}
+
+impl<V> FreeAttrDecl<V> for Keyframes<V>
+where V: AttrValue + Lerp
+{
+    const KIND: &'static str = "keyframes";
+
+    type Attr = KeyframesAttr<V>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let restart = self.restart.map(|input| builder.input("restart", input)).transpose()?;
+
+        return KeyframesAttr::new(self.keyframes, self.looping, restart);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use photonic::attr::AsFixedAttr;
+    use photonic::input::AnyInputValue;
+    use photonic::Scene;
+
+    use crate::attrs::rgb::RgbColor;
+    use crate::nodes::Solid;
+    use crate::testing::Renderer;
+
+    use super::*;
+
+    fn keyframes() -> Vec<Keyframe<f32>> {
+        let keyframe = |time: u64, value: f32| Keyframe {
+            time: Duration::from_millis(time),
+            value,
+            curve: Curve::default(),
+        };
+
+        return vec![keyframe(500, 0.0), keyframe(1500, 1.0), keyframe(2500, 0.5)];
+    }
+
+    /// Renders the animated value at the given times, restarting the animation if the time is `None`.
+    async fn run(looping: bool, times: &[Option<u64>]) -> Vec<f32> {
+        let mut scene = Scene::new();
+
+        let restart = scene.input::<Trigger>("restart").unwrap();
+        let sink = restart.sink();
+
+        let solid = scene
+            .node("solid", Solid {
+                color: RgbColor {
+                    red: Keyframes {
+                        keyframes: keyframes(),
+                        looping,
+                        restart: Some(restart),
+                    },
+                    green: 0.0f32.fixed(),
+                    blue: 0.0f32.fixed(),
+                },
+            })
+            .unwrap();
+
+        let mut renderer = Renderer::new(scene, solid, 1).await.unwrap();
+        renderer.render(Duration::ZERO).await.unwrap();
+
+        let mut values = Vec::new();
+        let mut last = 0;
+        for time in times {
+            match time {
+                Some(time) => {
+                    let frame = renderer.render(Duration::from_millis(time - last)).await.unwrap();
+                    values.push(frame[0].red);
+                    last = *time;
+                }
+                None => {
+                    let (sent, _) =
+                        tokio::join!(sink.send_any(AnyInputValue::Trigger), renderer.render(Duration::ZERO));
+                    sent.unwrap();
+                    last = 0;
+                }
+            }
+        }
+
+        return values;
+    }
+
+    fn assert_values(values: &[f32], expected: &[f32]) {
+        assert_eq!(values.len(), expected.len());
+        for (value, expected) in values.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-4, "{values:?} != {expected:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn segments() {
+        let values =
+            run(false, &[Some(250), Some(500), Some(1000), Some(1500), Some(2000), Some(2500), Some(4000)]).await;
+
+        // Holds the first value before the first and the last value after the last keyframe
+        assert_values(&values, &[0.0, 0.0, 0.5, 1.0, 0.75, 0.5, 0.5]);
+    }
+
+    #[tokio::test]
+    async fn looping() {
+        let values = run(true, &[Some(2000), Some(3000), Some(3500), Some(4500), Some(5000)]).await;
+
+        // Wraps around after the last keyframe
+        assert_values(&values, &[0.75, 0.0, 0.5, 0.75, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn restart() {
+        let values = run(false, &[Some(2000), Some(4000), None, Some(250), Some(1000), None, Some(2000)]).await;
+
+        assert_values(&values, &[0.75, 0.5, 0.0, 0.5, 0.75]);
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::{DynBoundAttrDecl, DynFreeAttrDecl};
+    use photonic::input;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+    use crate::easing;
+
+    #[derive(Deserialize, Debug)]
+    pub struct KeyframeConfig<V>
+    where V: AttrValue
+    {
+        #[serde(deserialize_with = "config::Value::deserialize")]
+        pub time: Duration,
+
+        pub value: V,
+
+        pub easing: Option<easing::dynamic::CurveConfig>,
+    }
+
+    impl<V> TryFrom<KeyframeConfig<V>> for Keyframe<V>
+    where V: AttrValue
+    {
+        type Error = anyhow::Error;
+
+        fn try_from(config: KeyframeConfig<V>) -> Result<Self> {
+            return Ok(Self {
+                time: config.time,
+                value: config.value,
+                curve: config.easing.map(Curve::try_from).transpose()?.unwrap_or_default(),
+            });
+        }
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config<V>
+    where V: AttrValue
+    {
+        pub keyframes: Vec<KeyframeConfig<V>>,
+
+        #[serde(default, rename = "loop")]
+        pub looping: bool,
+
+        pub restart: Option<config::Input>,
+    }
+
+    impl<V> Config<V>
+    where V: AttrValue
+    {
+        fn build<Reg: Registry>(self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Keyframes<V>> {
+            return Ok(Keyframes {
+                keyframes: self.keyframes.into_iter().map(Keyframe::try_from).collect::<Result<_>>()?,
+                looping: self.looping,
+                restart: self.restart.map(|input| builder.input(input)).transpose()?,
+            });
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'keyframes' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Lerp
+    {
+        type Product = Keyframes<V>;
+
+        fn produce<Reg: Registry>(config: Self, builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return config.build(builder);
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'keyframes' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + Lerp
+    {
+        type Product = Keyframes<V>;
+
+        fn produce<Reg: Registry>(config: Self, builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return config.build(builder);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use anyhow::Result;
+
+use photonic::attr::{Bounded, Bounds};
+use photonic::input::{Input, Poll, Trigger};
+use photonic::math::Lerp;
+use photonic::scene::InputHandle;
+use photonic::{scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl, FreeAttrDecl, Random};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "dynamic", derive(serde::Deserialize))]
+#[cfg_attr(feature = "dynamic", serde(rename_all = "snake_case"))]
+pub enum Waveform {
+    Sine,
+    Triangle,
+    Saw,
+    Square,
+    SampleAndHold,
+}
+
+impl Waveform {
+    /// Calculates the value of the waveform in `[0.0, 1.0]` at the given position in the cycle.
+    fn sample(self, position: f32, duty: f32) -> f32 {
+        return match self {
+            Waveform::Sine => 0.5 - 0.5 * f32::cos(position * std::f32::consts::TAU),
+            Waveform::Triangle if position < duty => position / duty,
+            Waveform::Triangle => (1.0 - position) / (1.0 - duty),
+            Waveform::Saw => position,
+            Waveform::Square if position < duty => 1.0,
+            Waveform::Square => 0.0,
+            Waveform::SampleAndHold => unreachable!("Sampled randomly"),
+        };
+    }
+}
+
+pub struct LfoAttr<V, Frequency, Phase, Duty, Depth>
+where
+    V: AttrValue + Lerp,
+    Frequency: Attr<f32>,
+    Phase: Attr<f32>,
+    Duty: Attr<f32>,
+    Depth: Attr<f32>,
+{
+    waveform: Waveform,
+
+    frequency: Frequency,
+    phase: Phase,
+    duty: Duty,
+    depth: Depth,
+
+    sync: Option<Input<Trigger>>,
+
+    bounds: Bounds<V>,
+
+    /// Position in the current cycle without the phase offset
+    position: f32,
+
+    /// Value held by sample-and-hold until the next cycle starts
+    held: f32,
+
+    random: Random,
+}
+
+impl<V, Frequency, Phase, Duty, Depth> Attr<V> for LfoAttr<V, Frequency, Phase, Duty, Depth>
+where
+    V: AttrValue + Lerp,
+    Frequency: Attr<f32>,
+    Phase: Attr<f32>,
+    Duty: Attr<f32>,
+    Depth: Attr<f32>,
+{
+    fn update(&mut self, ctx: &scene::RenderContext) -> V {
+        let frequency = self.frequency.update(ctx);
+        let phase = self.phase.update(ctx);
+        let duty = self.duty.update(ctx);
+        let depth = self.depth.update(ctx);
+
+        let synced = matches!(self.sync.as_mut().map(|sync| sync.poll(anyhow::Ok)), Some(Poll::Update(_)));
+
+        let position = self.position + ctx.duration.as_secs_f32() * frequency;
+        let wrapped = synced || !(0.0..1.0).contains(&position);
+
+        self.position = if synced { 0.0 } else { position.rem_euclid(1.0) };
+
+        let value = match self.waveform {
+            Waveform::SampleAndHold => {
+                if wrapped {
+                    self.held = self.random.range(0.0, 1.0);
+                }
+                self.held
+            }
+            waveform => waveform.sample((self.position + phase).rem_euclid(1.0), duty),
+        };
+
+        // Scale the swing around the center of the bounds
+        let value = 0.5 + (value - 0.5) * depth;
+
+        return V::lerp(self.bounds.min, self.bounds.max, value.clamp(0.0, 1.0));
+    }
+}
+
+/// A low frequency oscillator.
+///
+/// The oscillator swings through the bounds of the attribute with the given frequency in Hz. The phase shifts the
+/// waveform by a fraction of a cycle, the duty cycle defines the high fraction of a square and the peak of a triangle
+/// wave and the depth scales the swing around the center of the bounds.
+///
+/// If a sync input is given, the cycle restarts every time the input is triggered.
+pub struct Lfo<Frequency, Phase, Duty, Depth> {
+    pub waveform: Waveform,
+
+    pub frequency: Frequency,
+    pub phase: Phase,
+    pub duty: Duty,
+    pub depth: Depth,
+
+    pub sync: Option<InputHandle<Trigger>>,
+}
+
+impl<V, Frequency, Phase, Duty, Depth> BoundAttrDecl<V> for Lfo<Frequency, Phase, Duty, Depth>
+where
+    V: AttrValue + Bounded + Lerp,
+    Frequency: FreeAttrDecl<f32>,
+    Phase: BoundAttrDecl<f32>,
+    Duty: BoundAttrDecl<f32>,
+    Depth: BoundAttrDecl<f32>,
+{
+    const KIND: &'static str = "lfo";
+
+    type Attr = LfoAttr<V, Frequency::Attr, Phase::Attr, Duty::Attr, Depth::Attr>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let mut random = Random::new();
+
+        return Ok(LfoAttr {
+            waveform: self.waveform,
+            frequency: builder.unbound_attr("frequency", self.frequency).await?,
+            phase: builder.bound_attr("phase", self.phase, Bounds::normal()).await?,
+            duty: builder.bound_attr("duty", self.duty, Bounds::normal()).await?,
+            depth: builder.bound_attr("depth", self.depth, Bounds::normal()).await?,
+            sync: self.sync.map(|sync| builder.input("sync", sync)).transpose()?,
+            bounds,
+            position: 0.0,
+            held: random.range(0.0, 1.0),
+            random,
+        });
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use anyhow::bail;
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedBoundAttrDecl, BoxedFreeAttrDecl, DynBoundAttrDecl};
+    use photonic::input;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub waveform: Waveform,
+
+        pub frequency: config::Attr<f32>,
+        pub phase: config::Attr<f32>,
+        pub duty: config::Attr<f32>,
+        pub depth: config::Attr<f32>,
+
+        #[serde(default)]
+        pub sync: Option<config::Input>,
+    }
+
+    type BoxedLfo =
+        Lfo<BoxedFreeAttrDecl<f32>, BoxedBoundAttrDecl<f32>, BoxedBoundAttrDecl<f32>, BoxedBoundAttrDecl<f32>>;
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'lfo' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + Lerp
+    {
+        type Product = BoxedLfo;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Lfo {
+                waveform: config.waveform,
+                frequency: builder.free_attr("frequency", config.frequency)?,
+                phase: builder.bound_attr("phase", config.phase)?,
+                duty: builder.bound_attr("duty", config.duty)?,
+                depth: builder.bound_attr("depth", config.depth)?,
+                sync: config.sync.map(|sync| builder.input(sync)).transpose()?,
+            });
+        }
+    }
+}
@@ -44,7 +44,7 @@ where V: AttrValue + Bounded + Num + PartialOrd
     const KIND: &'static str = "looper";
 
     type Attr = LooperAttr<V>;
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         let step = if self.step >= V::zero() {
             self.step
         } else {
@@ -34,9 +34,9 @@ where
 
     type Attr = NoiseAttr<V, Speed::Attr, F>;
 
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> anyhow::Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> anyhow::Result<Self::Attr> {
         return Ok(Self::Attr {
-            speed: builder.unbound_attr("speed", self.speed)?,
+            speed: builder.unbound_attr("speed", self.speed).await?,
             position: 0.0,
             noise: self.noise,
             bounds,
@@ -0,0 +1,231 @@
+use anyhow::Result;
+use palette::rgb::Rgb;
+use palette::{FromColor, Hsl, Srgb};
+
+use photonic::attr::{AttrValueType, Gradient, Range};
+use photonic::input::Variants;
+use photonic::{scene, Attr, AttrBuilder, AttrValue, FreeAttrDecl};
+
+/// A collection of named color palettes.
+///
+/// The palettes follow the ones found in FastLED. Each palette is available as a [`Gradient`] and as a [`Range`] of
+/// its two most characteristic colors for nodes that only take a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "dynamic", derive(serde::Deserialize))]
+#[cfg_attr(feature = "dynamic", serde(rename_all = "snake_case"))]
+pub enum Palette {
+    #[default]
+    Rainbow,
+    Party,
+    Ocean,
+    Lava,
+    Forest,
+    Cloud,
+    Heat,
+}
+
+impl Palette {
+    fn colors(self) -> &'static [u32] {
+        return match self {
+            Self::Rainbow => &[
+                0xFF0000, 0xD52A00, 0xAB5500, 0xAB7F00, 0xABAB00, 0x56D500, 0x00FF00, 0x00D52A, 0x00AB55, 0x0056AA,
+                0x0000FF, 0x2A00D5, 0x5500AB, 0x7F0081, 0xAB0055, 0xD5002B,
+            ],
+            Self::Party => &[
+                0x5500AB, 0x84007C, 0xB5004B, 0xE5001B, 0xE81700, 0xB84700, 0xAB7700, 0xABAB00, 0xAB5500, 0xDD2200,
+                0xF2000E, 0xC2003E, 0x8F0071, 0x5F00A1, 0x2F00D0, 0x0007F9,
+            ],
+            Self::Ocean => &[
+                0x191970, 0x00008B, 0x191970, 0x000080, 0x00008B, 0x0000CD, 0x2E8B57, 0x008080, 0x5F9EA0, 0x0000FF,
+                0x008B8B, 0x6495ED, 0x7FFFD4, 0x2E8B57, 0x00FFFF, 0x87CEFA,
+            ],
+            Self::Lava => &[
+                0x000000, 0x800000, 0x000000, 0x800000, 0x8B0000, 0x8B0000, 0x800000, 0x8B0000, 0x8B0000, 0x8B0000,
+                0xFF0000, 0xFFA500, 0xFFFFFF, 0xFFA500, 0xFF0000, 0x8B0000,
+            ],
+            Self::Forest => &[
+                0x006400, 0x006400, 0x556B2F, 0x006400, 0x008000, 0x228B22, 0x6B8E23, 0x008000, 0x2E8B57, 0x66CDAA,
+                0x32CD32, 0x9ACD32, 0x90EE90, 0x7CFC00, 0x66CDAA, 0x228B22,
+            ],
+            Self::Cloud => &[
+                0x0000FF, 0x00008B, 0x00008B, 0x00008B, 0x00008B, 0x00008B, 0x00008B, 0x00008B, 0x0000FF, 0x00008B,
+                0x87CEEB, 0x87CEEB, 0xADD8E6, 0xFFFFFF, 0xADD8E6, 0x87CEEB,
+            ],
+            Self::Heat => &[
+                0x000000, 0x330000, 0x660000, 0x990000, 0xCC0000, 0xFF0000, 0xFF3300, 0xFF6600, 0xFF9900, 0xFFCC00,
+                0xFFFF00, 0xFFFF33, 0xFFFF66, 0xFFFF99, 0xFFFFCC, 0xFFFFFF,
+            ],
+        };
+    }
+
+    /// The palette as gradient with all colors evenly distributed.
+    pub fn gradient(self) -> Gradient {
+        let colors = self.colors().iter().map(|&color| Srgb::<u8>::from(color).into_format::<f32>());
+        return Gradient::evenly(colors).expect("Valid palette");
+    }
+
+    /// The palette reduced to a range.
+    ///
+    /// Hue is interpolated along the shorter way around the color wheel, so the range can only cover a part of
+    /// multi-colored palettes.
+    pub fn range(self) -> Range<Hsl> {
+        let (a, b) = match self {
+            Self::Rainbow => (Hsl::new(0.0, 1.0, 0.5), Hsl::new(179.0, 1.0, 0.5)),
+            Self::Party => (Hsl::new(270.0, 1.0, 0.35), Hsl::new(40.0, 1.0, 0.35)),
+            Self::Ocean => (Hsl::new(240.0, 1.0, 0.25), Hsl::new(180.0, 1.0, 0.5)),
+            Self::Lava => (Hsl::new(0.0, 1.0, 0.2), Hsl::new(39.0, 1.0, 0.5)),
+            Self::Forest => (Hsl::new(120.0, 1.0, 0.2), Hsl::new(90.0, 0.6, 0.5)),
+            Self::Cloud => (Hsl::new(240.0, 1.0, 0.3), Hsl::new(195.0, 0.6, 0.9)),
+            Self::Heat => (Hsl::new(0.0, 1.0, 0.25), Hsl::new(60.0, 1.0, 0.75)),
+        };
+
+        return Range(a, b);
+    }
+}
+
+impl Variants for Palette {
+    const ALL: &'static [Self] =
+        &[Self::Rainbow, Self::Party, Self::Ocean, Self::Lava, Self::Forest, Self::Cloud, Self::Heat];
+}
+
+impl AttrValue for Palette {
+    const TYPE: AttrValueType = AttrValueType::Integer;
+}
+
+/// Values which can be created from a palette.
+pub trait FromPalette {
+    fn from_palette(palette: Palette) -> Self;
+}
+
+impl FromPalette for Gradient {
+    fn from_palette(palette: Palette) -> Self {
+        return palette.gradient();
+    }
+}
+
+impl FromPalette for Range<Hsl> {
+    fn from_palette(palette: Palette) -> Self {
+        return palette.range();
+    }
+}
+
+impl FromPalette for Range<Rgb> {
+    fn from_palette(palette: Palette) -> Self {
+        return palette.range().map(Rgb::from_color);
+    }
+}
+
+pub struct PalettesAttr<V, Select>
+where
+    V: AttrValue,
+    Select: Attr<Palette>,
+{
+    select: Select,
+
+    /// The last selected palette and its value
+    current: Option<(Palette, V)>,
+}
+
+impl<V, Select> Attr<V> for PalettesAttr<V, Select>
+where
+    V: AttrValue + FromPalette,
+    Select: Attr<Palette>,
+{
+    fn update(&mut self, ctx: &scene::RenderContext) -> V {
+        let palette = self.select.update(ctx);
+
+        return match self.current {
+            Some((current, value)) if current == palette => value,
+            _ => {
+                let value = V::from_palette(palette);
+                self.current = Some((palette, value));
+                value
+            }
+        };
+    }
+}
+
+/// Provides the selected palette.
+pub struct Palettes<Select> {
+    pub select: Select,
+}
+
+impl<V, Select> FreeAttrDecl<V> for Palettes<Select>
+where
+    V: AttrValue + FromPalette,
+    Select: FreeAttrDecl<Palette>,
+{
+    const KIND: &'static str = "palette";
+
+    type Attr = PalettesAttr<V, Select::Attr>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(PalettesAttr {
+            select: builder.unbound_attr("select", self.select).await?,
+            current: None,
+        });
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use anyhow::bail;
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedFreeAttrDecl, DynFreeAttrDecl};
+    use photonic::input;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub select: config::Attr<Palette>,
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'palette' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + FromPalette
+    {
+        type Product = Palettes<BoxedFreeAttrDecl<Palette>>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Palettes {
+                select: builder.free_attr("select", config.select)?,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gradients() {
+        for palette in Palette::ALL {
+            let gradient = palette.gradient();
+
+            let stops = gradient.stops();
+            assert_eq!(stops.len(), palette.colors().len());
+            assert_eq!(stops.first().map(|stop| stop.position), Some(0.0));
+            assert_eq!(stops.last().map(|stop| stop.position), Some(1.0));
+            assert!(stops.windows(2).all(|w| w[0].position < w[1].position));
+        }
+    }
+}
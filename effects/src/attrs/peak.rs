@@ -44,8 +44,8 @@ where
 
     type Attr = PeakAttr<V, Input::Attr>;
 
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<Self::Attr> {
-        let input = builder.bound_attr("input", self.input, bounds)?;
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let input = builder.bound_attr("input", self.input, bounds).await?;
 
         return Ok(PeakAttr {
             input,
@@ -64,8 +64,8 @@ where
 
     type Attr = PeakAttr<V, Input::Attr>;
 
-    fn materialize(self, builder: &mut AttrBuilder) -> Result<Self::Attr> {
-        let input = builder.unbound_attr("input", self.input)?;
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let input = builder.unbound_attr("input", self.input).await?;
 
         return Ok(PeakAttr {
             input,
@@ -47,7 +47,7 @@ where V: AttrValue + SampleUniform + Bounded
 
     type Attr = RandomAttr<V>;
 
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         let mut random = SmallRng::from_os_rng();
         let uniform = Uniform::new_inclusive(bounds.min, bounds.max)?;
 
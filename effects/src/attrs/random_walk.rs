@@ -0,0 +1,228 @@
+use anyhow::{bail, Result};
+
+use photonic::attr::{Bounded, Bounds};
+use photonic::math::Lerp;
+use photonic::{scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl, FreeAttrDecl, Random};
+
+pub struct RandomWalkAttr<V, Step>
+where
+    V: AttrValue + Lerp,
+    Step: Attr<f32>,
+{
+    step: Step,
+
+    bounds: Bounds<V>,
+
+    /// Position of the walker relative to the bounds
+    position: f32,
+
+    random: Random,
+}
+
+impl<V, Step> RandomWalkAttr<V, Step>
+where
+    V: AttrValue + Lerp,
+    Step: Attr<f32>,
+{
+    fn new(step: Step, bounds: Bounds<V>) -> Self {
+        let mut random = Random::new();
+
+        // Start at a random position
+        let position = random.range(0.0, 1.0);
+
+        return Self {
+            step,
+            bounds,
+            position,
+            random,
+        };
+    }
+}
+
+impl<V, Step> Attr<V> for RandomWalkAttr<V, Step>
+where
+    V: AttrValue + Lerp,
+    Step: Attr<f32>,
+{
+    fn update(&mut self, ctx: &scene::RenderContext) -> V {
+        let step = self.step.update(ctx);
+
+        // Uniform steps scaled to unit variance - the deviation grows with the square root of time
+        let delta = self.random.range(-1.0f32, 1.0) * 3.0f32.sqrt() * step * ctx.duration.as_secs_f32().sqrt();
+
+        // Reflect at the bounds
+        let position = (self.position + delta).rem_euclid(2.0);
+        self.position = if position > 1.0 { 2.0 - position } else { position };
+
+        return V::lerp(self.bounds.min, self.bounds.max, self.position.clamp(0.0, 1.0));
+    }
+}
+
+/// A bounded random walk.
+///
+/// The value moves randomly with the step as standard deviation per second, relative to the bounds. The walk is
+/// reflected at the bounds.
+///
+/// Unbound attributes walk within the given bounds, bound attributes within the bounds they are materialized with.
+pub struct RandomWalk<Step, V> {
+    pub step: Step,
+    pub bounds: Option<Bounds<V>>,
+}
+
+impl<V, Step> BoundAttrDecl<V> for RandomWalk<Step, V>
+where
+    V: AttrValue + Bounded + Lerp,
+    Step: FreeAttrDecl<f32>,
+{
+    const KIND: &'static str = "random-walk";
+
+    type Attr = RandomWalkAttr<V, Step::Attr>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let step = builder.unbound_attr("step", self.step).await?;
+        return Ok(RandomWalkAttr::new(step, bounds));
+    }
+}
+
+impl<V, Step> FreeAttrDecl<V> for RandomWalk<Step, V>
+where
+    V: AttrValue + Lerp,
+    Step: FreeAttrDecl<f32>,
+{
+    const KIND: &'static str = "random-walk";
+
+    type Attr = RandomWalkAttr<V, Step::Attr>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let Some(bounds) = self.bounds else {
+            bail!("Random walk of an unbound attribute requires bounds");
+        };
+
+        let step = builder.unbound_attr("step", self.step).await?;
+        return Ok(RandomWalkAttr::new(step, bounds));
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedFreeAttrDecl, DynBoundAttrDecl, DynFreeAttrDecl};
+    use photonic::input;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config<V>
+    where V: AttrValue
+    {
+        pub step: config::Attr<f32>,
+
+        /// Bounds to walk within for unbound attributes - bound attributes use their own bounds
+        pub min: Option<V>,
+        pub max: Option<V>,
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'random-walk' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Lerp
+    {
+        type Product = RandomWalk<BoxedFreeAttrDecl<f32>, V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            let (Some(min), Some(max)) = (config.min, config.max) else {
+                bail!("Attribute 'random-walk' requires 'min' and 'max' if unbound");
+            };
+
+            return Ok(RandomWalk {
+                step: builder.free_attr("step", config.step)?,
+                bounds: Some(Bounds {
+                    min,
+                    max,
+                }),
+            });
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'random-walk' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + Lerp
+    {
+        type Product = RandomWalk<BoxedFreeAttrDecl<f32>, V>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(RandomWalk {
+                step: builder.free_attr("step", config.step)?,
+                bounds: None,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use palette::rgb::Rgb;
+
+    use photonic::attr::AsFixedAttr;
+    use photonic::Scene;
+
+    use crate::nodes::Solid;
+    use crate::testing::Renderer;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn color() {
+        let mut scene = Scene::new();
+
+        let solid = scene
+            .node("solid", Solid {
+                color: RandomWalk {
+                    step: 2.0f32.fixed(),
+                    bounds: Some(Bounds {
+                        min: Rgb::new(1.0, 0.0, 0.0),
+                        max: Rgb::new(0.0, 0.0, 1.0),
+                    }),
+                },
+            })
+            .unwrap();
+
+        let mut renderer = Renderer::new(scene, solid, 1).await.unwrap();
+
+        for _ in 0..100 {
+            let color = renderer.render(Duration::from_millis(100)).await.unwrap()[0];
+            assert_eq!(color.green, 0.0);
+            assert!((color.red + color.blue - 1.0).abs() < 1e-5);
+        }
+    }
+}
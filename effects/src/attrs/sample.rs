@@ -0,0 +1,251 @@
+use anyhow::Result;
+use palette::convert::FromColorUnclamped;
+use palette::rgb::Rgb;
+use palette::LinSrgb;
+
+use photonic::attr::{Bounded, Bounds};
+use photonic::math::Lerp;
+use photonic::{
+    scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl, BufferReader, FreeAttrDecl, Node, NodeDecl, NodeHandle,
+    NodeRef, Opacity,
+};
+
+/// Defines how the pixels of the sampled node are reduced to a single value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "dynamic", derive(serde::Deserialize))]
+#[cfg_attr(feature = "dynamic", serde(tag = "reduction", rename_all = "snake_case"))]
+pub enum Reduction {
+    /// The pixel at the given index
+    Pixel { index: usize },
+
+    /// The average color of all pixels
+    Average,
+
+    /// The brightest of all pixels
+    Max,
+
+    /// The average luminance of all pixels
+    Luminance,
+}
+
+/// Values an attribute sampling a node can produce.
+pub trait SampleValue: AttrValue + Lerp {
+    fn from_sample(color: Rgb, luminance: f32) -> Self;
+}
+
+impl SampleValue for f32 {
+    fn from_sample(_color: Rgb, luminance: f32) -> Self {
+        return luminance;
+    }
+}
+
+impl SampleValue for Rgb {
+    fn from_sample(color: Rgb, _luminance: f32) -> Self {
+        return color;
+    }
+}
+
+fn luminance(color: LinSrgb) -> f32 {
+    return 0.2126 * color.red + 0.7152 * color.green + 0.0722 * color.blue;
+}
+
+pub struct SampleAttr<V, Source>
+where
+    V: SampleValue,
+    Source: Node + 'static,
+{
+    source: NodeRef<Source>,
+    reduction: Reduction,
+
+    /// Bounds to map the luminance into - unbound attributes use the sample directly
+    bounds: Option<Bounds<V>>,
+}
+
+impl<V, Source> SampleAttr<V, Source>
+where
+    V: SampleValue,
+    Source: Node + 'static,
+    Rgb: FromColorUnclamped<Source::Element>,
+{
+    fn sample(&self, ctx: &scene::RenderContext) -> (Rgb, f32) {
+        let source = &ctx[self.source];
+
+        let pixels = source.iter().map(|pixel| Rgb::from_color_unclamped(pixel.opaque()).into_linear());
+
+        let color: LinSrgb = match self.reduction {
+            Reduction::Pixel {
+                index,
+            } => {
+                let index = index.min(source.size().saturating_sub(1));
+                Rgb::from_color_unclamped(source.get(index).opaque()).into_linear()
+            }
+
+            Reduction::Average => {
+                let (sum, count) = pixels.fold((LinSrgb::new(0.0, 0.0, 0.0), 0usize), |(sum, count), pixel| {
+                    return (sum + pixel, count + 1);
+                });
+                sum / count.max(1) as f32
+            }
+
+            Reduction::Max => {
+                pixels.max_by(|a, b| luminance(*a).total_cmp(&luminance(*b))).unwrap_or(LinSrgb::new(0.0, 0.0, 0.0))
+            }
+
+            Reduction::Luminance => {
+                let (sum, count) =
+                    pixels.fold((0.0, 0usize), |(sum, count), pixel| (sum + luminance(pixel), count + 1));
+                let luminance = sum / count.max(1) as f32;
+                LinSrgb::new(luminance, luminance, luminance)
+            }
+        };
+
+        return (Rgb::from_linear(color), luminance(color));
+    }
+}
+
+impl<V, Source> Attr<V> for SampleAttr<V, Source>
+where
+    V: SampleValue,
+    Source: Node + 'static,
+    Rgb: FromColorUnclamped<Source::Element>,
+{
+    fn update(&mut self, ctx: &scene::RenderContext) -> V {
+        let (color, luminance) = self.sample(ctx);
+
+        return match self.bounds {
+            Some(bounds) => V::lerp(bounds.min, bounds.max, luminance.clamp(0.0, 1.0)),
+            None => V::from_sample(color, luminance),
+        };
+    }
+}
+
+/// Samples the output of a node.
+///
+/// The pixels of the node are reduced to a single color. Decimal attributes use the luminance of this color. If used
+/// as bound attribute, the luminance is mapped into the bounds of the attribute.
+///
+/// The sampled node is rendered before the node owning this attribute.
+pub struct Sample<Source>
+where Source: NodeDecl
+{
+    pub source: NodeHandle<Source>,
+    pub reduction: Reduction,
+}
+
+impl<V, Source> FreeAttrDecl<V> for Sample<Source>
+where
+    V: SampleValue,
+    Source: NodeDecl,
+    Source::Node: 'static,
+    <Source::Node as Node>::Element: Default,
+    Rgb: FromColorUnclamped<<Source::Node as Node>::Element>,
+{
+    const KIND: &'static str = "sample";
+
+    type Attr = SampleAttr<V, Source::Node>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(SampleAttr {
+            source: builder.node("source", self.source).await?,
+            reduction: self.reduction,
+            bounds: None,
+        });
+    }
+}
+
+impl<V, Source> BoundAttrDecl<V> for Sample<Source>
+where
+    V: SampleValue + Bounded,
+    Source: NodeDecl,
+    Source::Node: 'static,
+    <Source::Node as Node>::Element: Default,
+    Rgb: FromColorUnclamped<<Source::Node as Node>::Element>,
+{
+    const KIND: &'static str = "sample";
+
+    type Attr = SampleAttr<V, Source::Node>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(SampleAttr {
+            source: builder.node("source", self.source).await?,
+            reduction: self.reduction,
+            bounds: Some(bounds),
+        });
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use anyhow::bail;
+    use palette::Srgba;
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedNodeDecl, DynBoundAttrDecl, DynFreeAttrDecl};
+    use photonic::input;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub source: config::Node,
+
+        #[serde(flatten)]
+        pub reduction: Reduction,
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'sample' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + SampleValue
+    {
+        type Product = Sample<BoxedNodeDecl<Srgba>>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Sample {
+                source: builder.node("source", config.source)?,
+                reduction: config.reduction,
+            });
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'sample' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + SampleValue
+    {
+        type Product = Sample<BoxedNodeDecl<Srgba>>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Sample {
+                source: builder.node("source", config.source)?,
+                reduction: config.reduction,
+            });
+        }
+    }
+}
@@ -53,7 +53,7 @@ where V: AttrValue + Bounded
 
     type Attr = SequenceAttr<V>;
 
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         let values = self.values.into_iter().map(|v| bounds.ensure(v)).collect::<Result<Vec<_>>>()?;
 
         let next = self.next.map(|input| builder.input("next", input)).transpose()?;
@@ -75,7 +75,7 @@ where V: AttrValue
 
     type Attr = SequenceAttr<V>;
 
-    fn materialize(self, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         let next = self.next.map(|input| builder.input("next", input)).transpose()?;
         let prev = self.prev.map(|input| builder.input("prev", input)).transpose()?;
 
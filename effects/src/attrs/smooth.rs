@@ -0,0 +1,375 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use num_traits::Float;
+
+use photonic::attr::{Bounded, Bounds};
+use photonic::{scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl, FreeAttrDecl};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "dynamic", derive(serde::Deserialize))]
+#[cfg_attr(feature = "dynamic", serde(rename_all = "snake_case"))]
+pub enum Smoothing {
+    /// Follow the input directly - only the rate limits apply
+    None,
+
+    /// Exponential moving average with the time as time constant
+    Exponential,
+
+    /// Critically damped spring settling roughly within the time
+    Spring,
+}
+
+pub struct SmoothAttr<V, Input, Time, Rise, Fall>
+where
+    V: AttrValue + Float,
+    Input: Attr<V>,
+    Time: Attr<Duration>,
+    Rise: Attr<f32>,
+    Fall: Attr<f32>,
+{
+    input: Input,
+
+    smoothing: Smoothing,
+
+    time: Time,
+    rise: Rise,
+    fall: Fall,
+
+    value: Option<V>,
+    velocity: V,
+}
+
+impl<V, Input, Time, Rise, Fall> Attr<V> for SmoothAttr<V, Input, Time, Rise, Fall>
+where
+    V: AttrValue + Float,
+    Input: Attr<V>,
+    Time: Attr<Duration>,
+    Rise: Attr<f32>,
+    Fall: Attr<f32>,
+{
+    fn update(&mut self, ctx: &scene::RenderContext) -> V {
+        let target = self.input.update(ctx);
+
+        let time = V::from(self.time.update(ctx).as_secs_f32()).expect("Can cast");
+        let rise = V::from(self.rise.update(ctx)).expect("Can cast");
+        let fall = V::from(self.fall.update(ctx)).expect("Can cast");
+
+        let dt = V::from(ctx.duration.as_secs_f32()).expect("Can cast");
+
+        // Start at the input on first update
+        let Some(value) = self.value else {
+            self.value = Some(target);
+            return target;
+        };
+
+        let next = match self.smoothing {
+            _ if time <= V::zero() => target,
+            Smoothing::None => target,
+            Smoothing::Exponential => value + (target - value) * (V::one() - (-dt / time).exp()),
+            Smoothing::Spring => {
+                // See "Critically Damped Ease-In/Ease-Out Smoothing" in Game Programming Gems 4
+                let omega = V::from(2.0).expect("Can cast") / time;
+                let x = omega * dt;
+                let decay = V::one()
+                    / (V::one()
+                        + x
+                        + V::from(0.48).expect("Can cast") * x * x
+                        + V::from(0.235).expect("Can cast") * x * x * x);
+
+                let change = value - target;
+                let temp = (self.velocity + omega * change) * dt;
+                self.velocity = (self.velocity - omega * temp) * decay;
+
+                target + (change + temp) * decay
+            }
+        };
+
+        // Limit the rate of change
+        let next = (next - value).max(-fall * dt).min(rise * dt) + value;
+
+        // Keep the spring from building up momentum while the rate is limited
+        if self.smoothing == Smoothing::Spring {
+            self.velocity = self.velocity.max(-fall).min(rise);
+        }
+
+        self.value = Some(next);
+
+        return next;
+    }
+}
+
+/// Smooths a noisy input.
+///
+/// The input is either followed by an exponential moving average or by a critically damped spring - both controlled by
+/// the time. Additionally, the rate of change is limited to the rise and fall rates given in units per second.
+pub struct Smooth<Input, Time, Rise, Fall> {
+    pub input: Input,
+
+    pub smoothing: Smoothing,
+
+    pub time: Time,
+    pub rise: Rise,
+    pub fall: Fall,
+}
+
+impl<V, Input, Time, Rise, Fall> BoundAttrDecl<V> for Smooth<Input, Time, Rise, Fall>
+where
+    V: AttrValue + Float + Bounded,
+    Input: BoundAttrDecl<V>,
+    Time: FreeAttrDecl<Duration>,
+    Rise: FreeAttrDecl<f32>,
+    Fall: FreeAttrDecl<f32>,
+{
+    const KIND: &'static str = "smooth";
+
+    type Attr = SmoothAttr<V, Input::Attr, Time::Attr, Rise::Attr, Fall::Attr>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(SmoothAttr {
+            input: builder.bound_attr("input", self.input, bounds).await?,
+            smoothing: self.smoothing,
+            time: builder.unbound_attr("time", self.time).await?,
+            rise: builder.unbound_attr("rise", self.rise).await?,
+            fall: builder.unbound_attr("fall", self.fall).await?,
+            value: None,
+            velocity: V::zero(),
+        });
+    }
+}
+
+impl<V, Input, Time, Rise, Fall> FreeAttrDecl<V> for Smooth<Input, Time, Rise, Fall>
+where
+    V: AttrValue + Float,
+    Input: FreeAttrDecl<V>,
+    Time: FreeAttrDecl<Duration>,
+    Rise: FreeAttrDecl<f32>,
+    Fall: FreeAttrDecl<f32>,
+{
+    const KIND: &'static str = "smooth";
+
+    type Attr = SmoothAttr<V, Input::Attr, Time::Attr, Rise::Attr, Fall::Attr>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(SmoothAttr {
+            input: builder.unbound_attr("input", self.input).await?,
+            smoothing: self.smoothing,
+            time: builder.unbound_attr("time", self.time).await?,
+            rise: builder.unbound_attr("rise", self.rise).await?,
+            fall: builder.unbound_attr("fall", self.fall).await?,
+            value: None,
+            velocity: V::zero(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use photonic::attr::AsFixedAttr;
+    use photonic::Scene;
+
+    use crate::attrs::rgb::RgbColor;
+    use crate::nodes::Solid;
+    use crate::testing::Renderer;
+
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(20);
+
+    /// Renders the smoothed value starting at zero while setting the input to the given targets for a number of frames.
+    async fn run(smoothing: Smoothing, time: Duration, rise: f32, fall: f32, steps: &[(f32, usize)]) -> Vec<f32> {
+        let mut scene = Scene::new();
+
+        let value = scene.input::<f32>("value").unwrap();
+        let sink = value.sink();
+
+        let solid = scene
+            .node("solid", Solid {
+                color: RgbColor {
+                    red: Smooth {
+                        input: value.attr(0.0),
+                        smoothing,
+                        time: time.fixed(),
+                        rise: rise.fixed(),
+                        fall: fall.fixed(),
+                    },
+                    green: 0.0f32.fixed(),
+                    blue: 0.0f32.fixed(),
+                },
+            })
+            .unwrap();
+
+        let mut renderer = Renderer::new(scene, solid, 1).await.unwrap();
+        renderer.render(Duration::ZERO).await.unwrap();
+
+        let mut values = Vec::new();
+        for (target, frames) in steps {
+            let (sent, _) = tokio::join!(sink.send_any((*target).into()), renderer.render(Duration::ZERO));
+            sent.unwrap();
+
+            for _ in 0..*frames {
+                values.push(renderer.render(FRAME).await.unwrap()[0].red);
+            }
+        }
+
+        return values;
+    }
+
+    #[tokio::test]
+    async fn exponential_converges() {
+        let time = Duration::from_millis(200);
+        let values = run(Smoothing::Exponential, time, f32::INFINITY, f32::INFINITY, &[(1.0, 100)]).await;
+
+        assert!(values.windows(2).all(|values| values[0] <= values[1]));
+
+        // Reaches 1 - 1/e after one time constant
+        let frames = (time.as_millis() / FRAME.as_millis()) as usize;
+        assert!((values[frames - 1] - (1.0 - (-1.0f32).exp())).abs() < 1e-3);
+
+        assert!((values.last().unwrap() - 1.0).abs() < 1e-3);
+    }
+
+    #[tokio::test]
+    async fn spring_settles() {
+        let values =
+            run(Smoothing::Spring, Duration::from_millis(500), f32::INFINITY, f32::INFINITY, &[(1.0, 150), (0.0, 150)])
+                .await;
+
+        let (up, down) = values.split_at(150);
+
+        // Critically damped - the spring approaches the target without oscillating
+        assert!(up.iter().all(|value| *value <= 1.0 + 1e-3));
+        assert!(down.iter().all(|value| *value >= -1e-3));
+
+        assert!((up.last().unwrap() - 1.0).abs() < 1e-3);
+        assert!(down.last().unwrap().abs() < 1e-3);
+    }
+
+    #[tokio::test]
+    async fn rise_and_fall() {
+        let values = run(Smoothing::None, Duration::ZERO, 1.0, 4.0, &[(1.0, 60), (0.0, 20)]).await;
+
+        // Rises by 1 per second
+        assert!((values[9] - 0.2).abs() < 1e-4);
+        assert!((values[49] - 1.0).abs() < 1e-4);
+        assert!((values[59] - 1.0).abs() < 1e-4);
+
+        // Falls by 4 per second
+        assert!((values[60 + 4] - 0.6).abs() < 1e-4);
+        assert!(values[60 + 12].abs() < 1e-4);
+    }
+
+    #[tokio::test]
+    async fn spring_limited() {
+        let values = run(Smoothing::Spring, Duration::from_millis(100), 0.5, 0.5, &[(1.0, 20), (0.0, 100)]).await;
+
+        let (up, down) = values.split_at(20);
+
+        // Rising is limited
+        assert!((up.last().unwrap() - 0.2).abs() < 1e-4);
+
+        // The spring must not gather momentum while being limited and keep on rising after the target changed
+        assert!(down[0] < *up.last().unwrap());
+        assert!(down.windows(2).all(|values| values[0] >= values[1]));
+
+        assert!(down.last().unwrap().abs() < 1e-3);
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use anyhow::bail;
+
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedBoundAttrDecl, BoxedFreeAttrDecl, DynBoundAttrDecl, DynFreeAttrDecl};
+    use photonic::input;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    fn unlimited() -> config::Attr<f32> {
+        return config::Attr::Fixed(f32::INFINITY);
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config<V>
+    where V: AttrValue
+    {
+        pub input: config::Attr<V>,
+
+        pub smoothing: Smoothing,
+
+        pub time: config::Attr<Duration>,
+
+        #[serde(default = "unlimited")]
+        pub rise: config::Attr<f32>,
+
+        #[serde(default = "unlimited")]
+        pub fall: config::Attr<f32>,
+    }
+
+    type BoxedSmooth<Input> =
+        Smooth<Input, BoxedFreeAttrDecl<Duration>, BoxedFreeAttrDecl<f32>, BoxedFreeAttrDecl<f32>>;
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'smooth' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Float
+    {
+        type Product = BoxedSmooth<BoxedFreeAttrDecl<V>>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Smooth {
+                input: builder.free_attr("input", config.input)?,
+                smoothing: config.smoothing,
+                time: builder.free_attr("time", config.time)?,
+                rise: builder.free_attr("rise", config.rise)?,
+                fall: builder.free_attr("fall", config.fall)?,
+            });
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'smooth' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl<V> Producible<dyn DynBoundAttrDecl<V>> for Config<V>
+    where V: AttrValue + input::Coerced + DeserializeOwned + Bounded + Float
+    {
+        type Product = BoxedSmooth<BoxedBoundAttrDecl<V>>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Smooth {
+                input: builder.bound_attr("input", config.input)?,
+                smoothing: config.smoothing,
+                time: builder.free_attr("time", config.time)?,
+                rise: builder.free_attr("rise", config.rise)?,
+                fall: builder.free_attr("fall", config.fall)?,
+            });
+        }
+    }
+}
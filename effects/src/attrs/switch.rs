@@ -46,11 +46,11 @@ where
 
     type Attr = SwitchAttr<V, I::Attr>;
 
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         return Ok(SwitchAttr {
             value_released: bounds.ensure(self.value_release)?,
             value_pressed: bounds.ensure(self.value_pressed)?,
-            pressed: builder.unbound_attr("pressed", self.pressed)?,
+            pressed: builder.unbound_attr("pressed", self.pressed).await?,
         });
     }
 }
@@ -64,11 +64,11 @@ where
 
     type Attr = SwitchAttr<V, I::Attr>;
 
-    fn materialize(self, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         return Ok(SwitchAttr {
             value_released: self.value_release,
             value_pressed: self.value_pressed,
-            pressed: builder.unbound_attr("input", self.pressed)?,
+            pressed: builder.unbound_attr("input", self.pressed).await?,
         });
     }
 }
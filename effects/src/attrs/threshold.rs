@@ -0,0 +1,260 @@
+use std::time::Duration;
+
+use anyhow::Result;
+
+use photonic::attr::FixedAttrDecl;
+use photonic::{scene, Attr, AttrBuilder, FreeAttrDecl};
+
+pub struct ThresholdAttr<Input, On, Off, MinOn, MinOff>
+where
+    Input: Attr<f32>,
+    On: Attr<f32>,
+    Off: Attr<f32>,
+    MinOn: Attr<Duration>,
+    MinOff: Attr<Duration>,
+{
+    input: Input,
+
+    on: On,
+    off: Off,
+
+    min_on: MinOn,
+    min_off: MinOff,
+
+    active: bool,
+
+    /// Time since the last change of the state
+    elapsed: Duration,
+}
+
+impl<Input, On, Off, MinOn, MinOff> Attr<bool> for ThresholdAttr<Input, On, Off, MinOn, MinOff>
+where
+    Input: Attr<f32>,
+    On: Attr<f32>,
+    Off: Attr<f32>,
+    MinOn: Attr<Duration>,
+    MinOff: Attr<Duration>,
+{
+    fn update(&mut self, ctx: &scene::RenderContext) -> bool {
+        let value = self.input.update(ctx);
+
+        let on = self.on.update(ctx);
+        let off = self.off.update(ctx);
+
+        let min_on = self.min_on.update(ctx);
+        let min_off = self.min_off.update(ctx);
+
+        self.elapsed = self.elapsed.saturating_add(ctx.duration);
+
+        let change = match self.active {
+            false => value > on && self.elapsed >= min_off,
+            true => value < off && self.elapsed >= min_on,
+        };
+
+        if change {
+            self.active = !self.active;
+            self.elapsed = Duration::ZERO;
+        }
+
+        return self.active;
+    }
+}
+
+/// Converts a decimal input to a boolean.
+///
+/// The threshold switches on if the input rises above the on-threshold and switches off if the input falls below the
+/// off-threshold. Choosing the off-threshold lower than the on-threshold avoids flickering of noisy inputs. In addition,
+/// every state is held for at least its minimum time.
+///
+/// Use a [`Switch`](crate::attrs::Switch) to map the boolean to other values.
+pub struct Threshold<Input, On, Off, MinOn = FixedAttrDecl<Duration>, MinOff = FixedAttrDecl<Duration>> {
+    pub input: Input,
+
+    pub on: On,
+    pub off: Off,
+
+    pub min_on: MinOn,
+    pub min_off: MinOff,
+}
+
+impl<Input, On, Off, MinOn, MinOff> FreeAttrDecl<bool> for Threshold<Input, On, Off, MinOn, MinOff>
+where
+    Input: FreeAttrDecl<f32>,
+    On: FreeAttrDecl<f32>,
+    Off: FreeAttrDecl<f32>,
+    MinOn: FreeAttrDecl<Duration>,
+    MinOff: FreeAttrDecl<Duration>,
+{
+    const KIND: &'static str = "threshold";
+
+    type Attr = ThresholdAttr<Input::Attr, On::Attr, Off::Attr, MinOn::Attr, MinOff::Attr>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(ThresholdAttr {
+            input: builder.unbound_attr("input", self.input).await?,
+            on: builder.unbound_attr("on", self.on).await?,
+            off: builder.unbound_attr("off", self.off).await?,
+            min_on: builder.unbound_attr("min_on", self.min_on).await?,
+            min_off: builder.unbound_attr("min_off", self.min_off).await?,
+            active: false,
+            elapsed: Duration::MAX,
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use photonic::attr::AsFixedAttr;
+    use photonic::Scene;
+
+    use crate::attrs::rgb::RgbColor;
+    use crate::attrs::Switch;
+    use crate::nodes::Solid;
+    use crate::testing::Renderer;
+
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(20);
+
+    /// Renders the state of the threshold while setting the input to the given values for a number of frames.
+    async fn run(min_on: Duration, steps: &[(f32, usize)]) -> Vec<Vec<bool>> {
+        let mut scene = Scene::new();
+
+        let value = scene.input::<f32>("value").unwrap();
+        let sink = value.sink();
+
+        let solid = scene
+            .node("solid", Solid {
+                color: RgbColor {
+                    red: Switch {
+                        value_release: 0.0,
+                        value_pressed: 1.0,
+                        pressed: Threshold {
+                            input: value.attr(0.0),
+                            on: 0.6.fixed(),
+                            off: 0.4.fixed(),
+                            min_on: min_on.fixed(),
+                            min_off: Duration::ZERO.fixed(),
+                        },
+                    },
+                    green: 0.0f32.fixed(),
+                    blue: 0.0f32.fixed(),
+                },
+            })
+            .unwrap();
+
+        let mut renderer = Renderer::new(scene, solid, 1).await.unwrap();
+        renderer.render(Duration::ZERO).await.unwrap();
+
+        let mut states = Vec::new();
+        for (value, frames) in steps {
+            let (sent, _) = tokio::join!(sink.send_any((*value).into()), renderer.render(Duration::ZERO));
+            sent.unwrap();
+
+            let mut step = Vec::new();
+            for _ in 0..*frames {
+                step.push(renderer.render(FRAME).await.unwrap()[0].red == 1.0);
+            }
+            states.push(step);
+        }
+
+        return states;
+    }
+
+    #[tokio::test]
+    async fn hysteresis() {
+        let states = run(Duration::ZERO, &[(0.5, 2), (0.7, 2), (0.5, 2), (0.3, 2), (0.5, 2), (0.7, 2)]).await;
+
+        assert_eq!(states, vec![
+            vec![false, false], // Below on
+            vec![true, true],   // Rises above on
+            vec![true, true],   // Dips between off and on
+            vec![false, false], // Falls below off
+            vec![false, false], // Rises between off and on
+            vec![true, true],   // Rises above on
+        ]);
+    }
+
+    #[tokio::test]
+    async fn min_on() {
+        let states = run(Duration::from_millis(100), &[(0.7, 1), (0.5, 1), (0.3, 5), (0.7, 6), (0.3, 2)]).await;
+
+        assert_eq!(states, vec![
+            vec![true],
+            vec![true],
+            // Falls below off before min_on - held until min_on passed
+            vec![true, true, false, false, false],
+            vec![true, true, true, true, true, true],
+            // Falls below off after min_on
+            vec![false, false],
+        ]);
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use anyhow::bail;
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedFreeAttrDecl, DynFreeAttrDecl};
+    use photonic::{input, AttrValue};
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    fn immediately() -> config::Attr<Duration> {
+        return config::Attr::Fixed(Duration::ZERO);
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub input: config::Attr<f32>,
+
+        pub on: config::Attr<f32>,
+        pub off: config::Attr<f32>,
+
+        #[serde(default = "immediately")]
+        pub min_on: config::Attr<Duration>,
+
+        #[serde(default = "immediately")]
+        pub min_off: config::Attr<Duration>,
+    }
+
+    type BoxedThreshold = Threshold<
+        BoxedFreeAttrDecl<f32>,
+        BoxedFreeAttrDecl<f32>,
+        BoxedFreeAttrDecl<f32>,
+        BoxedFreeAttrDecl<Duration>,
+        BoxedFreeAttrDecl<Duration>,
+    >;
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'threshold' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl Producible<dyn DynFreeAttrDecl<bool>> for Config {
+        type Product = BoxedThreshold;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Threshold {
+                input: builder.free_attr("input", config.input)?,
+                on: builder.free_attr("on", config.on)?,
+                off: builder.free_attr("off", config.off)?,
+                min_on: builder.free_attr("min_on", config.min_on)?,
+                min_off: builder.free_attr("min_off", config.min_off)?,
+            });
+        }
+    }
+}
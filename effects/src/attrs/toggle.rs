@@ -0,0 +1,101 @@
+use anyhow::Result;
+
+use photonic::input::{Input, Poll, Trigger};
+use photonic::scene::InputHandle;
+use photonic::{scene, Attr, AttrBuilder, FreeAttrDecl};
+
+pub struct ToggleAttr {
+    initial: bool,
+    current: bool,
+
+    trigger: Input<Trigger>,
+    reset: Option<Input<Trigger>>,
+}
+
+impl Attr<bool> for ToggleAttr {
+    fn update(&mut self, _ctx: &scene::RenderContext) -> bool {
+        if let Some(Poll::Update(_)) = self.reset.as_mut().map(|input| input.poll(anyhow::Ok)) {
+            self.current = self.initial;
+        }
+
+        if let Poll::Update(_) = self.trigger.poll(anyhow::Ok) {
+            self.current = !self.current;
+        }
+
+        return self.current;
+    }
+}
+
+/// A boolean flipped every time the trigger input fires.
+///
+/// The reset input sets the toggle back to its initial state.
+pub struct Toggle {
+    pub initial: bool,
+
+    pub trigger: InputHandle<Trigger>,
+    pub reset: Option<InputHandle<Trigger>>,
+}
+
+impl FreeAttrDecl<bool> for Toggle {
+    const KIND: &'static str = "toggle";
+
+    type Attr = ToggleAttr;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(ToggleAttr {
+            initial: self.initial,
+            current: self.initial,
+            trigger: builder.input("trigger", self.trigger)?,
+            reset: self.reset.map(|input| builder.input("reset", input)).transpose()?,
+        });
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use anyhow::bail;
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+
+    use photonic::boxed::DynFreeAttrDecl;
+    use photonic::{input, AttrValue};
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        #[serde(default)]
+        pub initial: bool,
+
+        pub trigger: config::Input,
+        pub reset: Option<config::Input>,
+    }
+
+    impl<V> Producible<dyn DynFreeAttrDecl<V>> for Config
+    where V: AttrValue + input::Coerced + DeserializeOwned
+    {
+        default type Product = !;
+
+        default fn produce<Reg: Registry>(
+            _config: Self,
+            _builder: builder::AttrBuilder<'_, Reg>,
+        ) -> Result<Self::Product> {
+            bail!("Attribute 'toggle' no available for value type {}", std::any::type_name::<V>());
+        }
+    }
+
+    impl Producible<dyn DynFreeAttrDecl<bool>> for Config {
+        type Product = Toggle;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::AttrBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Toggle {
+                initial: config.initial,
+                trigger: builder.input(config.trigger)?,
+                reset: config.reset.map(|input| builder.input(input)).transpose()?,
+            });
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use palette::rgb::Rgb;
+
+use photonic::attr::AttrValueType;
+use photonic::input::Variants;
+use photonic::AttrValue;
+
+/// Modes to blend a color on top of a base color.
+///
+/// The separable modes are applied to each channel independently. The non-separable modes (`Hue`, `Saturation`,
+/// `Color` and `Luminosity`) combine components of both colors as defined by the W3C compositing specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "dynamic", derive(serde::Deserialize))]
+#[cfg_attr(feature = "dynamic", serde(rename_all = "snake_case"))]
+pub enum BlendMode {
+    /// Use the color on top
+    #[default]
+    Normal,
+
+    Add,
+    Screen,
+    Multiply,
+    Overlay,
+    Difference,
+    Lighten,
+    Darken,
+
+    /// Hue of the top with saturation and luminosity of the base
+    Hue,
+
+    /// Saturation of the top with hue and luminosity of the base
+    Saturation,
+
+    /// Hue and saturation of the top with luminosity of the base
+    Color,
+
+    /// Luminosity of the top with hue and saturation of the base
+    Luminosity,
+}
+
+impl BlendMode {
+    /// Blends the top color onto the base color.
+    pub fn blend(self, base: Rgb, top: Rgb) -> Rgb {
+        let separable = |f: fn(f32, f32) -> f32| {
+            return Rgb::new(f(base.red, top.red), f(base.green, top.green), f(base.blue, top.blue));
+        };
+
+        return match self {
+            Self::Normal => top,
+            Self::Add => separable(|b, s| (b + s).min(1.0)),
+            Self::Screen => separable(|b, s| b + s - b * s),
+            Self::Multiply => separable(|b, s| b * s),
+            Self::Overlay => separable(|b, s| if b <= 0.5 { 2.0 * b * s } else { 1.0 - 2.0 * (1.0 - b) * (1.0 - s) }),
+            Self::Difference => separable(|b, s| (b - s).abs()),
+            Self::Lighten => separable(f32::max),
+            Self::Darken => separable(f32::min),
+            Self::Hue => set_lum(set_sat(top, sat(base)), lum(base)),
+            Self::Saturation => set_lum(set_sat(base, sat(top)), lum(base)),
+            Self::Color => set_lum(top, lum(base)),
+            Self::Luminosity => set_lum(base, lum(top)),
+        };
+    }
+}
+
+fn lum(c: Rgb) -> f32 {
+    return 0.3 * c.red + 0.59 * c.green + 0.11 * c.blue;
+}
+
+fn sat(c: Rgb) -> f32 {
+    return c.red.max(c.green).max(c.blue) - c.red.min(c.green).min(c.blue);
+}
+
+fn clip(c: Rgb) -> Rgb {
+    let l = lum(c);
+    let n = c.red.min(c.green).min(c.blue);
+    let x = c.red.max(c.green).max(c.blue);
+
+    let scale = |f: &dyn Fn(f32) -> f32| Rgb::new(f(c.red), f(c.green), f(c.blue));
+
+    let c = if n < 0.0 { scale(&|v| l + (v - l) * l / (l - n)) } else { c };
+    let c = if x > 1.0 { scale(&|v| l + (v - l) * (1.0 - l) / (x - l)) } else { c };
+
+    return c;
+}
+
+fn set_lum(c: Rgb, l: f32) -> Rgb {
+    let d = l - lum(c);
+    return clip(Rgb::new(c.red + d, c.green + d, c.blue + d));
+}
+
+fn set_sat(c: Rgb, s: f32) -> Rgb {
+    let n = c.red.min(c.green).min(c.blue);
+    let x = c.red.max(c.green).max(c.blue);
+
+    if x <= n {
+        return Rgb::new(0.0, 0.0, 0.0);
+    }
+
+    // Stretch the mid channel proportionally and move min and max to the new saturation
+    let f = |v: f32| (v - n) * s / (x - n);
+    return Rgb::new(f(c.red), f(c.green), f(c.blue));
+}
+
+impl AttrValue for BlendMode {
+    const TYPE: AttrValueType = AttrValueType::Integer;
+}
+
+impl Variants for BlendMode {
+    const ALL: &'static [Self] = &[
+        Self::Normal,
+        Self::Add,
+        Self::Screen,
+        Self::Multiply,
+        Self::Overlay,
+        Self::Difference,
+        Self::Lighten,
+        Self::Darken,
+        Self::Hue,
+        Self::Saturation,
+        Self::Color,
+        Self::Luminosity,
+    ];
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_rgb(actual: Rgb, expected: (f32, f32, f32)) {
+        let expected: Rgb = Rgb::new(expected.0, expected.1, expected.2);
+        assert!(
+            (actual.red - expected.red).abs() < 1e-4
+                && (actual.green - expected.green).abs() < 1e-4
+                && (actual.blue - expected.blue).abs() < 1e-4,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    const BASE: Rgb = Rgb::new(0.25, 0.75, 0.5);
+    const TOP: Rgb = Rgb::new(0.5, 0.2, 0.8);
+
+    #[test]
+    fn clip_to_gamut() {
+        assert_rgb(clip(Rgb::new(1.2, 0.5, 0.1)), (1.0, 0.562172, 0.311985));
+        assert_rgb(clip(Rgb::new(-0.2, 0.5, 0.8)), (0.0, 0.432314, 0.617591));
+        assert_rgb(clip(BASE), (0.25, 0.75, 0.5));
+    }
+
+    #[test]
+    fn set_saturation() {
+        assert_rgb(set_sat(Rgb::new(0.2, 0.6, 0.4), 0.5), (0.0, 0.5, 0.25));
+        assert_rgb(set_sat(Rgb::new(0.3, 0.3, 0.3), 0.5), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn separable() {
+        assert_rgb(BlendMode::Overlay.blend(BASE, TOP), (0.25, 0.6, 0.8));
+        assert_rgb(BlendMode::Screen.blend(BASE, TOP), (0.625, 0.8, 0.9));
+        assert_rgb(BlendMode::Multiply.blend(BASE, TOP), (0.125, 0.15, 0.4));
+        assert_rgb(BlendMode::Difference.blend(BASE, TOP), (0.25, 0.55, 0.3));
+    }
+
+    #[test]
+    fn non_separable() {
+        assert_rgb(BlendMode::Hue.blend(BASE, TOP), (0.6925, 0.4425, 0.9425));
+        assert_rgb(BlendMode::Saturation.blend(BASE, TOP), (0.1855, 0.7855, 0.4855));
+        assert_rgb(BlendMode::Color.blend(BASE, TOP), (0.711149, 0.422297, 1.0));
+        assert_rgb(BlendMode::Luminosity.blend(BASE, TOP), (0.0335, 0.5335, 0.2835));
+    }
+}
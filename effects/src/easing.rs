@@ -3,32 +3,131 @@ use std::time::Duration;
 
 use num_traits::Float;
 
+use photonic::attr::{AsFixedAttr, FixedAttrDecl};
+
+/// The curve of an easing mapping the progress of a transition to the progress of the value.
+#[derive(Debug, Clone)]
+pub enum Curve<F: Float> {
+    /// A plain easing function
+    Func(fn(F) -> F),
+
+    /// A CSS-style cubic bezier curve with the control points `(x1, y1)` and `(x2, y2)`
+    CubicBezier(F, F, F, F),
+
+    /// Jumps in the given number of equal steps, each at the end of its interval
+    Steps(usize),
+
+    /// Linear interpolation between `(x, y)` points ordered by `x`
+    Piecewise(Vec<(F, F)>),
+}
+
+impl<F: Float> Curve<F> {
+    /// Maps the progress `t` of a transition to the progress of the value.
+    ///
+    /// The result is clamped to `[0, 1]` as it is used to interpolate between values. Curves overshooting the target,
+    /// like a bezier curve with control points outside of `[0, 1]`, are therefore cut off at the start and end value.
+    pub fn apply(&self, t: F) -> F {
+        let value = match self {
+            Curve::Func(func) => func(t),
+            Curve::CubicBezier(x1, y1, x2, y2) => cubic_bezier(*x1, *y1, *x2, *y2, t),
+            Curve::Steps(steps) => {
+                let steps = F::from((*steps).max(1)).expect("Can cast");
+                (t.max(F::zero()) * steps).floor().min(steps) / steps
+            }
+            Curve::Piecewise(points) => {
+                let next = points.partition_point(|(x, _)| *x <= t);
+
+                if next == 0 {
+                    points.first().map_or(t, |(_, y)| *y)
+                } else if next == points.len() {
+                    points[next - 1].1
+                } else {
+                    let ((x0, y0), (x1, y1)) = (points[next - 1], points[next]);
+                    y0 + (y1 - y0) * (t - x0) / (x1 - x0)
+                }
+            }
+        };
+
+        return value.max(F::zero()).min(F::one());
+    }
+}
+
+impl<F: Float> Default for Curve<F> {
+    fn default() -> Self {
+        return Self::from(Easings::Linear);
+    }
+}
+
+impl<F: Float> From<fn(F) -> F> for Curve<F> {
+    fn from(func: fn(F) -> F) -> Self {
+        return Self::Func(func);
+    }
+}
+
+impl<F: Float> From<Easings> for Curve<F> {
+    fn from(easing: Easings) -> Self {
+        return Self::Func(easing.func());
+    }
+}
+
 #[derive(Debug)]
-pub struct Easing<F: Float> {
-    pub func: fn(F) -> F,
-    pub speed: Duration,
+pub struct Easing<F: Float, Speed = FixedAttrDecl<Duration>> {
+    pub curve: Curve<F>,
+    pub speed: Speed,
 }
 
 impl<F: Float> Easing<F> {
-    pub fn new(func: fn(F) -> F) -> Self {
+    pub fn new(curve: impl Into<Curve<F>>) -> Self {
         return Self {
-            func,
-            speed: Duration::from_secs(1),
+            curve: curve.into(),
+            speed: Duration::from_secs(1).fixed(),
         };
     }
 
     pub fn with_speed(mut self, speed: Duration) -> Self {
-        self.speed = speed;
+        self.speed = speed.fixed();
         return self;
     }
 }
 
+impl<F: Float, Speed> Easing<F, Speed> {
+    /// Use an attribute to control the speed of the easing.
+    pub fn with_speed_attr<S>(self, speed: S) -> Easing<F, S> {
+        return Easing {
+            curve: self.curve,
+            speed,
+        };
+    }
+}
+
 impl<F: Float> From<fn(F) -> F> for Easing<F> {
     fn from(func: fn(F) -> F) -> Self {
         return Self::new(func);
     }
 }
 
+impl<F: Float> From<Curve<F>> for Easing<F> {
+    fn from(curve: Curve<F>) -> Self {
+        return Self::new(curve);
+    }
+}
+
+/// Defines how a running transition reacts to a new target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "dynamic", derive(photonic_dynamic::serde::Deserialize))]
+#[cfg_attr(feature = "dynamic", serde(rename_all = "snake_case"))]
+pub enum TransitionPolicy {
+    /// Start a new transition from the current intermediate value to the new target
+    #[default]
+    Retarget,
+
+    /// Finish the running transition before starting a new one to the latest target
+    Queue,
+
+    /// Drop all targets arriving while a transition is running
+    Ignore,
+}
+
 #[derive(Debug)]
 #[cfg_attr(feature = "dynamic", derive(photonic_dynamic::serde::Deserialize))]
 #[cfg_attr(feature = "dynamic", serde(rename_all = "snake_case"))]
@@ -60,12 +159,17 @@ impl Easings {
     pub fn with_speed<F: Float>(self, speed: Duration) -> Easing<F> {
         return Easing::from(self).with_speed(speed);
     }
-}
 
-impl<F: Float> From<Easings> for Easing<F> {
-    fn from(value: Easings) -> Self {
+    pub fn with_speed_attr<F: Float, Speed>(self, speed: Speed) -> Easing<F, Speed> {
+        return Easing {
+            curve: Curve::from(self),
+            speed,
+        };
+    }
+
+    pub fn func<F: Float>(self) -> fn(F) -> F {
         use ezing::*;
-        return match value {
+        return match self {
             Easings::Instant => |_| F::one(),
             Easings::Linear => linear,
             Easings::Quadratic(EasingDirection::In) => quad_in,
@@ -98,25 +202,153 @@ impl<F: Float> From<Easings> for Easing<F> {
             Easings::Bounce(EasingDirection::In) => bounce_in,
             Easings::Bounce(EasingDirection::Out) => bounce_out,
             Easings::Bounce(EasingDirection::InOut) => bounce_inout,
+        };
+    }
+}
+
+impl<F: Float> From<Easings> for Easing<F> {
+    fn from(value: Easings) -> Self {
+        return Easing::new(value);
+    }
+}
+
+/// Evaluates a CSS-style cubic bezier curve at `x`.
+///
+/// The curve starts at `(0, 0)` and ends at `(1, 1)` with the control points `(x1, y1)` and `(x2, y2)`. The control
+/// points must have their `x` coordinates in `[0, 1]` to keep the curve a function of `x`.
+pub fn cubic_bezier<F: Float>(x1: F, y1: F, x2: F, y2: F, x: F) -> F {
+    let three = F::from(3.0).expect("Can cast");
+
+    // Polynomial coefficients of the curve in one dimension
+    let coefficients = |p1: F, p2: F| {
+        let c = three * p1;
+        let b = three * (p2 - p1) - c;
+        let a = F::one() - c - b;
+        return (a, b, c);
+    };
+
+    let (ax, bx, cx) = coefficients(x1, x2);
+    let (ay, by, cy) = coefficients(y1, y2);
+
+    let sample_x = |t: F| ((ax * t + bx) * t + cx) * t;
+    let sample_y = |t: F| ((ay * t + by) * t + cy) * t;
+    let slope_x = |t: F| (three * ax * t + (bx + bx)) * t + cx;
+
+    let epsilon = F::from(1e-6).expect("Can cast");
+
+    if x <= F::zero() {
+        return F::zero();
+    }
+
+    if x >= F::one() {
+        return F::one();
+    }
+
+    // Try Newton's method first as it converges fast for most curves
+    let mut t = x;
+    for _ in 0..8 {
+        let error = sample_x(t) - x;
+        if error.abs() < epsilon {
+            return sample_y(t);
+        }
+
+        let slope = slope_x(t);
+        if slope.abs() < epsilon {
+            break;
+        }
+
+        t = t - error / slope;
+    }
+
+    // Fall back to bisection
+    let (mut lo, mut hi) = (F::zero(), F::one());
+    t = x;
+    for _ in 0..32 {
+        let value = sample_x(t);
+        if (value - x).abs() < epsilon {
+            break;
+        }
+
+        if value < x {
+            lo = t;
+        } else {
+            hi = t;
         }
-        .into();
+
+        t = (lo + hi) / (F::one() + F::one());
     }
+
+    return sample_y(t);
 }
 
 #[cfg(feature = "dynamic")]
-impl<'de, F: Float> serde::Deserialize<'de> for Easing<F> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where D: serde::Deserializer<'de> {
-        #[derive(Debug, serde::Deserialize)]
-        struct S {
-            func: Easings,
-
-            #[serde(with = "humantime_serde")]
-            speed: Duration,
+pub mod dynamic {
+    use anyhow::{ensure, Result};
+    use serde::Deserialize;
+
+    use photonic_dynamic::config;
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    #[serde(rename_all = "snake_case")]
+    pub enum CurveConfig {
+        /// Control points of a cubic bezier curve as `[x1, y1, x2, y2]`
+        CubicBezier([f32; 4]),
+
+        Steps(usize),
+
+        /// Points of a piecewise-linear curve as `[x, y]` pairs
+        Piecewise(Vec<[f32; 2]>),
+
+        #[serde(untagged)]
+        Named(Easings),
+    }
+
+    impl CurveConfig {
+        pub fn with_speed_attr<Speed>(self, speed: Speed) -> Result<Easing<f32, Speed>> {
+            return Ok(Easing {
+                curve: Curve::try_from(self)?,
+                speed,
+            });
         }
+    }
+
+    impl TryFrom<CurveConfig> for Curve<f32> {
+        type Error = anyhow::Error;
 
-        let s = S::deserialize(deserializer)?;
+        fn try_from(config: CurveConfig) -> Result<Self> {
+            return Ok(match config {
+                CurveConfig::CubicBezier([x1, y1, x2, y2]) => {
+                    ensure!(
+                        (0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2),
+                        "Cubic bezier control points must have x in [0, 1]: {x1}, {x2}"
+                    );
+                    Curve::CubicBezier(x1, y1, x2, y2)
+                }
+
+                CurveConfig::Steps(steps) => {
+                    ensure!(steps > 0, "Steps must be positive");
+                    Curve::Steps(steps)
+                }
+
+                CurveConfig::Piecewise(points) => {
+                    ensure!(points.len() >= 2, "Piecewise curve requires at least two points");
+                    ensure!(
+                        points.windows(2).all(|window| window[0][0] <= window[1][0]),
+                        "Piecewise curve points must be ordered by x"
+                    );
+                    Curve::Piecewise(points.into_iter().map(|[x, y]| (x, y)).collect())
+                }
+
+                CurveConfig::Named(easing) => Curve::from(easing),
+            });
+        }
+    }
 
-        return Ok(s.func.with_speed(s.speed));
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub func: CurveConfig,
+        pub speed: config::Attr<Duration>,
     }
 }
@@ -4,8 +4,12 @@
 pub mod attrs;
 pub mod nodes;
 
+pub mod blend;
 pub mod easing;
 
+#[cfg(test)]
+mod testing;
+
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
     use serde::de::DeserializeOwned;
@@ -24,7 +28,12 @@ pub mod dynamic {
                 "blackout" => factory::<crate::nodes::blackout::dynamic::Config>(),
                 "brightness" => factory::<crate::nodes::brightness::dynamic::Config>(),
                 "color-wheel" => factory::<crate::nodes::color_wheel::dynamic::Config>(),
+                "fire" => factory::<crate::nodes::fire::dynamic::Config>(),
+                "gradient" => factory::<crate::nodes::gradient::dynamic::Config>(),
+                "gradient-map" => factory::<crate::nodes::gradient_map::dynamic::Config>(),
                 "larson" => factory::<crate::nodes::larson::dynamic::Config>(),
+                "layers" => factory::<crate::nodes::layers::dynamic::Config>(),
+                "mask" => factory::<crate::nodes::mask::dynamic::Config>(),
                 "noise" => factory::<crate::nodes::noise::dynamic::Config>(),
                 "overlay" => factory::<crate::nodes::overlay::dynamic::Config>(),
                 "raindrops" => factory::<crate::nodes::raindrops::dynamic::Config>(),
@@ -40,9 +49,26 @@ pub mod dynamic {
             return Some(match kind {
                 "button" => factory::<crate::attrs::button::dynamic::Config<V>>(),
                 "switch" => factory::<crate::attrs::switch::dynamic::Config<V>>(),
+                "toggle" => factory::<crate::attrs::toggle::dynamic::Config>(),
+                "threshold" => factory::<crate::attrs::threshold::dynamic::Config>(),
                 "fader" => factory::<crate::attrs::fader::dynamic::Config<V>>(),
+                "sample" => factory::<crate::attrs::sample::dynamic::Config>(),
+                "keyframes" => factory::<crate::attrs::keyframes::dynamic::Config<V>>(),
                 "sequence" => factory::<crate::attrs::sequence::dynamic::Config<V>>(),
+                "palette" => factory::<crate::attrs::palettes::dynamic::Config>(),
+                "smooth" => factory::<crate::attrs::smooth::dynamic::Config<V>>(),
                 "peak" => factory::<crate::attrs::peak::dynamic::Config<V>>(),
+                "expression" => factory::<crate::attrs::expression::dynamic::Config>(),
+                "add" => factory::<crate::attrs::arithmetic::dynamic::AddConfig<V>>(),
+                "mul" => factory::<crate::attrs::arithmetic::dynamic::MulConfig<V>>(),
+                "min" => factory::<crate::attrs::arithmetic::dynamic::MinConfig<V>>(),
+                "max" => factory::<crate::attrs::arithmetic::dynamic::MaxConfig<V>>(),
+                "mix" => factory::<crate::attrs::arithmetic::dynamic::MixConfig<V>>(),
+                "clamp" => factory::<crate::attrs::arithmetic::dynamic::ClampConfig<V>>(),
+                "invert" => factory::<crate::attrs::arithmetic::dynamic::InvertConfig<V>>(),
+                "quantize" => factory::<crate::attrs::arithmetic::dynamic::QuantizeConfig<V>>(),
+                "random-walk" => factory::<crate::attrs::random_walk::dynamic::Config<V>>(),
+                "drunkard" => factory::<crate::attrs::drunkard::dynamic::Config<V>>(),
                 _ => crate::attrs::color::free_attr(kind)?,
             });
         }
@@ -50,14 +76,31 @@ pub mod dynamic {
         fn bound_attr<Reg: registry::Registry, V>(kind: &str) -> Option<BoundAttrFactory<Reg, V>>
         where V: AttrValue + DeserializeOwned + input::Coerced + Bounded {
             return Some(match kind {
+                "adsr" => factory::<crate::attrs::adsr::dynamic::Config>(),
                 "button" => factory::<crate::attrs::button::dynamic::Config<V>>(),
                 "switch" => factory::<crate::attrs::switch::dynamic::Config<V>>(),
                 "fader" => factory::<crate::attrs::fader::dynamic::Config<V>>(),
+                "lfo" => factory::<crate::attrs::lfo::dynamic::Config>(),
                 "looper" => factory::<crate::attrs::looper::dynamic::Config<V>>(),
+                "counter" => factory::<crate::attrs::counter::dynamic::Config<V>>(),
                 "noise" => factory::<crate::attrs::noise::dynamic::Config>(),
                 "random" => factory::<crate::attrs::random::dynamic::Config>(),
+                "random-walk" => factory::<crate::attrs::random_walk::dynamic::Config<V>>(),
+                "drunkard" => factory::<crate::attrs::drunkard::dynamic::Config<V>>(),
+                "sample" => factory::<crate::attrs::sample::dynamic::Config>(),
+                "keyframes" => factory::<crate::attrs::keyframes::dynamic::Config<V>>(),
                 "sequence" => factory::<crate::attrs::sequence::dynamic::Config<V>>(),
+                "smooth" => factory::<crate::attrs::smooth::dynamic::Config<V>>(),
                 "peak" => factory::<crate::attrs::peak::dynamic::Config<V>>(),
+                "expression" => factory::<crate::attrs::expression::dynamic::Config>(),
+                "add" => factory::<crate::attrs::arithmetic::dynamic::AddConfig<V>>(),
+                "mul" => factory::<crate::attrs::arithmetic::dynamic::MulConfig<V>>(),
+                "min" => factory::<crate::attrs::arithmetic::dynamic::MinConfig<V>>(),
+                "max" => factory::<crate::attrs::arithmetic::dynamic::MaxConfig<V>>(),
+                "mix" => factory::<crate::attrs::arithmetic::dynamic::MixConfig<V>>(),
+                "clamp" => factory::<crate::attrs::arithmetic::dynamic::ClampConfig<V>>(),
+                "invert" => factory::<crate::attrs::arithmetic::dynamic::InvertConfig<V>>(),
+                "quantize" => factory::<crate::attrs::arithmetic::dynamic::QuantizeConfig<V>>(),
                 _ => return None,
             });
         }
@@ -2,7 +2,12 @@ pub use alert::Alert;
 pub use blackout::Blackout;
 pub use brightness::Brightness;
 pub use color_wheel::ColorWheel;
+pub use fire::{Fire, FireDirection};
+pub use gradient::Gradient;
+pub use gradient_map::{GradientIndex, GradientMap};
 pub use larson::Larson;
+pub use layers::{Layer, Layers};
+pub use mask::{Mask, MaskChannel};
 pub use noise::Noise;
 pub use overlay::Overlay;
 pub use raindrops::Raindrops;
@@ -14,7 +19,12 @@ pub mod alert;
 pub mod blackout;
 pub mod brightness;
 pub mod color_wheel;
+pub mod fire;
+pub mod gradient;
+pub mod gradient_map;
 pub mod larson;
+pub mod layers;
+pub mod mask;
 pub mod noise;
 pub mod overlay;
 pub mod raindrops;
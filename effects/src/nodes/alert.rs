@@ -1,4 +1,5 @@
 use std::ops::Neg;
+use std::time::Duration;
 
 use anyhow::Result;
 use palette::Hsv;
@@ -23,7 +24,7 @@ impl<Hue, Block, Speed> NodeDecl for Alert<Hue, Block, Speed>
 where
     Hue: BoundAttrDecl<f32>,
     Block: BoundAttrDecl<i64>,
-    Speed: FreeAttrDecl<f32>, // TODO: Make speed an attr of duration
+    Speed: FreeAttrDecl<Duration>,
 {
     const KIND: &'static str = "alert";
 
@@ -31,9 +32,9 @@ where
 
     async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
         return Ok(Self::Node {
-            hue: builder.bound_attr("hue", self.hue, (0.0, 360.0))?,
-            block: builder.bound_attr("block", self.block, (0, builder.size as i64))?,
-            speed: builder.unbound_attr("speed", self.speed)?,
+            hue: builder.bound_attr("hue", self.hue, (0.0, 360.0)).await?,
+            block: builder.bound_attr("block", self.block, (0, builder.size as i64)).await?,
+            speed: builder.unbound_attr("speed", self.speed).await?,
 
             time: 0.0,
         });
@@ -44,7 +45,7 @@ impl<Hue, Block, Speed> Node for AlertNode<Hue, Block, Speed>
 where
     Hue: Attr<f32>,
     Block: Attr<i64>,
-    Speed: Attr<f32>,
+    Speed: Attr<Duration>,
 {
     type Element = Hsv;
 
@@ -53,7 +54,7 @@ where
         let block = self.block.update(ctx);
         let speed = self.speed.update(ctx);
 
-        self.time += ctx.duration.as_secs_f32() / speed;
+        self.time += ctx.duration.as_secs_f32() / speed.as_secs_f32();
         self.time %= 2.0;
 
         let value = math::clamp(f32::sin(self.time * std::f32::consts::PI), (-1.0, 1.0));
@@ -70,7 +71,7 @@ where
 
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
-    use palette::rgb::Rgb;
+    use palette::Srgba;
     use serde::Deserialize;
 
     use photonic::boxed::{BoxedBoundAttrDecl, BoxedFreeAttrDecl, DynNodeDecl};
@@ -84,11 +85,11 @@ pub mod dynamic {
     pub struct Config {
         pub hue: config::Attr<f32>,
         pub block: config::Attr<i64>,
-        pub speed: config::Attr<f32>,
+        pub speed: config::Attr<Duration>,
     }
 
-    impl Producible<dyn DynNodeDecl<Rgb>> for Config {
-        type Product = Alert<BoxedBoundAttrDecl<f32>, BoxedBoundAttrDecl<i64>, BoxedFreeAttrDecl<f32>>;
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product = Alert<BoxedBoundAttrDecl<f32>, BoxedBoundAttrDecl<i64>, BoxedFreeAttrDecl<Duration>>;
 
         fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Alert {
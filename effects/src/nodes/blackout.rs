@@ -45,7 +45,7 @@ where
     async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
         return Ok(Self::Node {
             source: builder.node("source", self.source).await?,
-            active: builder.bound_attr("active", self.active, Bounds::normal())?,
+            active: builder.bound_attr("active", self.active, Bounds::normal()).await?,
             value: self.value,
             range: self.range.unwrap_or(0..builder.size),
         });
@@ -79,6 +79,7 @@ where
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
     use palette::rgb::Rgb;
+    use palette::Srgba;
     use serde::Deserialize;
 
     use photonic::boxed::{BoxedBoundAttrDecl, BoxedNodeDecl, DynNodeDecl};
@@ -97,13 +98,13 @@ pub mod dynamic {
         pub range: Option<Range<usize>>,
     }
 
-    impl Producible<dyn DynNodeDecl<Rgb>> for Config {
-        type Product = Blackout<BoxedNodeDecl<Rgb>, BoxedBoundAttrDecl<f32>>;
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product = Blackout<BoxedNodeDecl<Srgba>, BoxedBoundAttrDecl<f32>>;
         fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Blackout {
                 source: builder.node("source", config.source)?,
                 active: builder.bound_attr("active", config.active)?,
-                value: config.value,
+                value: config.value.into(),
                 range: config.range,
             });
         }
@@ -45,7 +45,7 @@ where
 
     async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
         return Ok(Self::Node {
-            value: builder.bound_attr("value", self.value, Bounds::normal())?,
+            value: builder.bound_attr("value", self.value, Bounds::normal()).await?,
             source: builder.node("source", self.source).await?,
             range: self.range.unwrap_or(0..builder.size),
         });
@@ -76,7 +76,7 @@ where
 
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
-    use palette::rgb::Rgb;
+    use palette::Srgba;
     use serde::Deserialize;
 
     use photonic::boxed::{BoxedBoundAttrDecl, BoxedNodeDecl, DynNodeDecl};
@@ -94,8 +94,8 @@ pub mod dynamic {
         pub range: Option<Range<usize>>,
     }
 
-    impl Producible<dyn DynNodeDecl<Rgb>> for Config {
-        type Product = Brightness<BoxedNodeDecl<Rgb>, BoxedBoundAttrDecl<f32>>;
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product = Brightness<BoxedNodeDecl<Srgba>, BoxedBoundAttrDecl<f32>>;
         fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Brightness {
                 source: builder.node("source", config.source)?,
@@ -0,0 +1,209 @@
+use anyhow::Result;
+use palette::rgb::Rgb;
+
+use photonic::attr::{Attr, AttrValueType, Bounds, Gradient};
+use photonic::decl::{BoundAttrDecl, FreeAttrDecl, NodeDecl};
+use photonic::input::Variants;
+use photonic::{AttrValue, Buffer, Node, NodeBuilder, Random, RenderContext};
+
+/// The simulation follows the classic Fire2012 effect which is tuned for this many updates per second. All rates are
+/// scaled by the actual frame duration relative to it.
+const REFERENCE_FPS: f32 = 60.0;
+
+/// Number of cells at the base of the fire where sparks can ignite.
+const SPARKING_ZONE: usize = 7;
+
+/// The direction in which the flames rise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "dynamic", derive(serde::Deserialize))]
+#[cfg_attr(feature = "dynamic", serde(rename_all = "snake_case"))]
+pub enum FireDirection {
+    /// Flames start at the first pixel and rise towards the last one
+    #[default]
+    Forward,
+
+    /// Flames start at the last pixel and rise towards the first one
+    Backward,
+}
+
+impl AttrValue for FireDirection {
+    const TYPE: AttrValueType = AttrValueType::Integer;
+}
+
+impl Variants for FireDirection {
+    const ALL: &'static [Self] = &[Self::Forward, Self::Backward];
+}
+
+/// A one dimensional fire simulation.
+///
+/// Each pixel holds an amount of heat. Every frame, all cells cool down randomly, heat drifts up and diffuses, and new
+/// sparks ignite randomly near the base. The heat is then mapped through the gradient to get the color of each pixel.
+///
+/// Higher `cooling` gives shorter flames, higher `sparking` gives a more active fire. Both are in `[0, 1]`.
+pub struct Fire<Cooling, Sparking, Direction, Colors> {
+    pub cooling: Cooling,
+    pub sparking: Sparking,
+    pub direction: Direction,
+
+    pub gradient: Colors,
+}
+
+pub struct FireNode<Cooling, Sparking, Direction, Colors>
+where
+    Cooling: Attr<f32>,
+    Sparking: Attr<f32>,
+    Direction: Attr<FireDirection>,
+    Colors: Attr<Gradient>,
+{
+    cooling: Cooling,
+    sparking: Sparking,
+    direction: Direction,
+
+    gradient: Colors,
+
+    heat: Box<[f32]>,
+
+    random: Random,
+}
+
+impl<Cooling, Sparking, Direction, Colors> NodeDecl for Fire<Cooling, Sparking, Direction, Colors>
+where
+    Cooling: BoundAttrDecl<f32>,
+    Sparking: BoundAttrDecl<f32>,
+    Direction: FreeAttrDecl<FireDirection>,
+    Colors: FreeAttrDecl<Gradient>,
+{
+    const KIND: &'static str = "fire";
+
+    type Node = FireNode<Cooling::Attr, Sparking::Attr, Direction::Attr, Colors::Attr>;
+
+    async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
+        return Ok(Self::Node {
+            cooling: builder.bound_attr("cooling", self.cooling, Bounds::normal()).await?,
+            sparking: builder.bound_attr("sparking", self.sparking, Bounds::normal()).await?,
+            direction: builder.unbound_attr("direction", self.direction).await?,
+            gradient: builder.unbound_attr("gradient", self.gradient).await?,
+            heat: vec![0.0; builder.size].into_boxed_slice(),
+            random: Random::new(),
+        });
+    }
+}
+
+impl<Cooling, Sparking, Direction, Colors> Node for FireNode<Cooling, Sparking, Direction, Colors>
+where
+    Cooling: Attr<f32>,
+    Sparking: Attr<f32>,
+    Direction: Attr<FireDirection>,
+    Colors: Attr<Gradient>,
+{
+    type Element = Rgb;
+
+    fn update(&mut self, ctx: &RenderContext, out: &mut Buffer<Self::Element>) -> Result<()> {
+        let cooling = self.cooling.update(ctx);
+        let sparking = self.sparking.update(ctx);
+        let direction = self.direction.update(ctx);
+        let gradient = self.gradient.update(ctx);
+
+        let size = self.heat.len();
+        if size == 0 {
+            return Ok(());
+        }
+
+        let frames = ctx.duration.as_secs_f32() * REFERENCE_FPS;
+
+        // Cool down every cell a little
+        let cooldown = (cooling * 1000.0 / size as f32 + 2.0) / 255.0 * frames;
+        for heat in self.heat.iter_mut() {
+            *heat = (*heat - self.random.range(0.0, cooldown)).max(0.0);
+        }
+
+        // Heat drifts up and diffuses a little
+        let drift = frames.min(1.0);
+        for k in (2..size).rev() {
+            let diffused = (self.heat[k - 1] + self.heat[k - 2] * 2.0) / 3.0;
+            self.heat[k] += (diffused - self.heat[k]) * drift;
+        }
+
+        // Randomly ignite new sparks near the bottom
+        if self.random.rate((sparking * REFERENCE_FPS) as f64, ctx.duration) {
+            let k = self.random.range(0, SPARKING_ZONE.min(size) - 1);
+            self.heat[k] = (self.heat[k] + self.random.range(160.0, 255.0) / 255.0).min(1.0);
+        }
+
+        out.update(|i, _| {
+            let k = match direction {
+                FireDirection::Forward => i,
+                FireDirection::Backward => size - 1 - i,
+            };
+
+            return gradient.sample(self.heat[k]);
+        });
+
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use palette::Srgba;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedBoundAttrDecl, BoxedFreeAttrDecl, DynNodeDecl};
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use crate::attrs::Palette;
+
+    use super::*;
+
+    fn cooling() -> config::Attr<f32> {
+        return config::Attr::Fixed(0.55);
+    }
+
+    fn sparking() -> config::Attr<f32> {
+        return config::Attr::Fixed(0.47);
+    }
+
+    fn forward() -> config::Attr<FireDirection> {
+        return config::Attr::Fixed(FireDirection::Forward);
+    }
+
+    fn heat() -> config::Attr<Gradient> {
+        return config::Attr::Fixed(Palette::Heat.gradient());
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        #[serde(default = "cooling")]
+        pub cooling: config::Attr<f32>,
+
+        #[serde(default = "sparking")]
+        pub sparking: config::Attr<f32>,
+
+        #[serde(default = "forward")]
+        pub direction: config::Attr<FireDirection>,
+
+        #[serde(default = "heat")]
+        pub gradient: config::Attr<Gradient>,
+    }
+
+    type BoxedFire = Fire<
+        BoxedBoundAttrDecl<f32>,
+        BoxedBoundAttrDecl<f32>,
+        BoxedFreeAttrDecl<FireDirection>,
+        BoxedFreeAttrDecl<Gradient>,
+    >;
+
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product = BoxedFire;
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Fire {
+                cooling: builder.bound_attr("cooling", config.cooling)?,
+                sparking: builder.bound_attr("sparking", config.sparking)?,
+                direction: builder.free_attr("direction", config.direction)?,
+                gradient: builder.free_attr("gradient", config.gradient)?,
+            });
+        }
+    }
+}
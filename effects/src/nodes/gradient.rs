@@ -0,0 +1,121 @@
+use anyhow::Result;
+use palette::rgb::Rgb;
+
+use photonic::attr::{self, Attr};
+use photonic::decl::{FreeAttrDecl, NodeDecl};
+use photonic::{Buffer, Node, NodeBuilder, RenderContext};
+
+/// Spreads a gradient across the strip.
+///
+/// The gradient is repeated `repeat` times over the length of the strip and scrolls by `speed` gradient lengths per
+/// second. A `repeat` of zero or less fills the whole strip with the color at the current scroll position.
+pub struct Gradient<Colors, Speed, Repeat> {
+    pub gradient: Colors,
+    pub speed: Speed,
+    pub repeat: Repeat,
+}
+
+pub struct GradientNode<Colors, Speed, Repeat>
+where
+    Colors: Attr<attr::Gradient>,
+    Speed: Attr<f32>,
+    Repeat: Attr<f32>,
+{
+    gradient: Colors,
+    speed: Speed,
+    repeat: Repeat,
+
+    position: f32,
+}
+
+impl<Colors, Speed, Repeat> NodeDecl for Gradient<Colors, Speed, Repeat>
+where
+    Colors: FreeAttrDecl<attr::Gradient>,
+    Speed: FreeAttrDecl<f32>,
+    Repeat: FreeAttrDecl<f32>,
+{
+    const KIND: &'static str = "gradient";
+
+    type Node = GradientNode<Colors::Attr, Speed::Attr, Repeat::Attr>;
+
+    async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
+        return Ok(Self::Node {
+            gradient: builder.unbound_attr("gradient", self.gradient).await?,
+            speed: builder.unbound_attr("speed", self.speed).await?,
+            repeat: builder.unbound_attr("repeat", self.repeat).await?,
+            position: 0.0,
+        });
+    }
+}
+
+impl<Colors, Speed, Repeat> Node for GradientNode<Colors, Speed, Repeat>
+where
+    Colors: Attr<attr::Gradient>,
+    Speed: Attr<f32>,
+    Repeat: Attr<f32>,
+{
+    type Element = Rgb;
+
+    fn update(&mut self, ctx: &RenderContext, out: &mut Buffer<Self::Element>) -> Result<()> {
+        let gradient = self.gradient.update(ctx);
+        let speed = self.speed.update(ctx);
+        let repeat = self.repeat.update(ctx);
+
+        self.position = (self.position + ctx.duration.as_secs_f32() * speed).rem_euclid(1.0);
+
+        if repeat <= 0.0 {
+            out.fill(gradient.sample(self.position));
+        } else {
+            let size = out.size() as f32;
+            out.update(|i, _| {
+                let position = (i as f32 / size * repeat - self.position).rem_euclid(1.0);
+                return gradient.sample(position);
+            });
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use palette::Srgba;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedFreeAttrDecl, DynNodeDecl};
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    fn still() -> config::Attr<f32> {
+        return config::Attr::Fixed(0.0);
+    }
+
+    fn once() -> config::Attr<f32> {
+        return config::Attr::Fixed(1.0);
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub gradient: config::Attr<attr::Gradient>,
+
+        #[serde(default = "still")]
+        pub speed: config::Attr<f32>,
+
+        #[serde(default = "once")]
+        pub repeat: config::Attr<f32>,
+    }
+
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product = Gradient<BoxedFreeAttrDecl<attr::Gradient>, BoxedFreeAttrDecl<f32>, BoxedFreeAttrDecl<f32>>;
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Gradient {
+                gradient: builder.free_attr("gradient", config.gradient)?,
+                speed: builder.free_attr("speed", config.speed)?,
+                repeat: builder.free_attr("repeat", config.repeat)?,
+            });
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use anyhow::Result;
+use palette::rgb::Rgb;
+use palette::{FromColor, Hsv, IntoColor, SrgbLuma};
+
+use photonic::attr::{Attr, Gradient};
+use photonic::decl::{FreeAttrDecl, NodeDecl};
+use photonic::{Buffer, BufferReader, Node, NodeBuilder, NodeHandle, NodeRef, Opacity, RenderContext};
+
+/// The property of the source used as position in the gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "dynamic", derive(serde::Deserialize))]
+#[cfg_attr(feature = "dynamic", serde(rename_all = "snake_case"))]
+pub enum GradientIndex {
+    #[default]
+    Luminance,
+    Hue,
+    Saturation,
+    Value,
+    Red,
+    Green,
+    Blue,
+    Alpha,
+}
+
+impl GradientIndex {
+    /// Returns the position of the given element in `[0, 1]`.
+    pub fn index<E>(self, element: E) -> f32
+    where E: IntoColor<Rgb> + Copy + Opacity {
+        let rgb: Rgb = element.into_color();
+
+        let value = match self {
+            Self::Luminance => SrgbLuma::from_color(rgb).luma,
+            Self::Hue => Hsv::from_color(rgb).hue.into_positive_degrees() / 360.0,
+            Self::Saturation => Hsv::from_color(rgb).saturation,
+            Self::Value => Hsv::from_color(rgb).value,
+            Self::Red => rgb.red,
+            Self::Green => rgb.green,
+            Self::Blue => rgb.blue,
+            Self::Alpha => element.opacity(),
+        };
+
+        return value.clamp(0.0, 1.0);
+    }
+}
+
+/// Maps each pixel of a source through a gradient.
+///
+/// The selected index of the source pixel is used as position in the gradient. This allows to colorize scalar fields
+/// like noise or brightness masks with a palette.
+pub struct GradientMap<Source, Colors>
+where Source: NodeDecl
+{
+    pub source: NodeHandle<Source>,
+
+    pub gradient: Colors,
+    pub index: GradientIndex,
+}
+
+pub struct GradientMapNode<Source, Colors>
+where
+    Source: Node + 'static,
+    Colors: Attr<Gradient>,
+{
+    source: NodeRef<Source>,
+
+    gradient: Colors,
+    index: GradientIndex,
+}
+
+impl<Source, Colors> NodeDecl for GradientMap<Source, Colors>
+where
+    Source: NodeDecl + 'static,
+    <Source::Node as Node>::Element: IntoColor<Rgb>,
+    Colors: FreeAttrDecl<Gradient>,
+{
+    const KIND: &'static str = "gradient_map";
+
+    type Node = GradientMapNode<Source::Node, Colors::Attr>;
+
+    async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
+        return Ok(Self::Node {
+            source: builder.node("source", self.source).await?,
+            gradient: builder.unbound_attr("gradient", self.gradient).await?,
+            index: self.index,
+        });
+    }
+}
+
+impl<Source, Colors> Node for GradientMapNode<Source, Colors>
+where
+    Source: Node,
+    Source::Element: IntoColor<Rgb>,
+    Colors: Attr<Gradient>,
+{
+    type Element = Rgb;
+
+    fn update(&mut self, ctx: &RenderContext, out: &mut Buffer<Self::Element>) -> Result<()> {
+        let gradient = self.gradient.update(ctx);
+
+        let source = &ctx[self.source];
+
+        out.update(|i, _| gradient.sample(self.index.index(source.get(i))));
+
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use palette::Srgba;
+    use serde::Deserialize;
+
+    use photonic::boxed::{BoxedFreeAttrDecl, BoxedNodeDecl, DynNodeDecl};
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub source: config::Node,
+
+        pub gradient: config::Attr<Gradient>,
+
+        #[serde(default)]
+        pub index: GradientIndex,
+    }
+
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product = GradientMap<BoxedNodeDecl<Srgba>, BoxedFreeAttrDecl<Gradient>>;
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(GradientMap {
+                source: builder.node("source", config.source)?,
+                gradient: builder.free_attr("gradient", config.gradient)?,
+                index: config.index,
+            });
+        }
+    }
+}
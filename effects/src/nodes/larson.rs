@@ -37,9 +37,9 @@ where
 
     async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
         return Ok(Self::Node {
-            hue: builder.bound_attr("hue", self.hue, (0.0, 360.0))?,
-            width: builder.bound_attr("width", self.width, (0.0, builder.size as f32))?,
-            speed: builder.unbound_attr("speed", self.speed)?,
+            hue: builder.bound_attr("hue", self.hue, (0.0, 360.0)).await?,
+            width: builder.bound_attr("width", self.width, (0.0, builder.size as f32)).await?,
+            speed: builder.unbound_attr("speed", self.speed).await?,
             position: 0.0,
             direction: Direction::Positive,
         });
@@ -94,7 +94,7 @@ where
 
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
-    use palette::rgb::Rgb;
+    use palette::Srgba;
     use serde::Deserialize;
 
     use photonic::boxed::{BoxedBoundAttrDecl, BoxedFreeAttrDecl, DynNodeDecl};
@@ -111,7 +111,7 @@ pub mod dynamic {
         pub speed: config::Attr<f32>,
     }
 
-    impl Producible<dyn DynNodeDecl<Rgb>> for Config {
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
         type Product = Larson<BoxedBoundAttrDecl<f32>, BoxedBoundAttrDecl<f32>, BoxedFreeAttrDecl<f32>>;
         fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Larson {
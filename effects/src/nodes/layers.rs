@@ -0,0 +1,313 @@
+use anyhow::Result;
+use palette::convert::FromColorUnclamped;
+use palette::rgb::Rgb;
+use palette::{FromColor, IntoColor};
+
+use photonic::attr::{AsFixedAttr, Bounds};
+use photonic::boxed::{
+    Boxed, BoxedAttr, BoxedBoundAttrDecl, BoxedFreeAttrDecl, BoxedNode, BoxedNodeDecl, DynBoundAttrDecl,
+    DynFreeAttrDecl,
+};
+use photonic::math::Lerp;
+use photonic::{Attr, Buffer, BufferReader, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, Opacity, RenderContext};
+
+use crate::blend::BlendMode;
+use crate::nodes::MaskChannel;
+
+/// A single layer of a [`Layers`] node.
+pub struct Layer<E>
+where E: Default + Copy + Opacity
+{
+    source: NodeHandle<BoxedNodeDecl<E>>,
+
+    opacity: BoxedBoundAttrDecl<f32>,
+    mode: BoxedFreeAttrDecl<BlendMode>,
+    enabled: BoxedFreeAttrDecl<bool>,
+
+    mask: Option<NodeHandle<BoxedNodeDecl<Rgb>>>,
+}
+
+impl<E> Layer<E>
+where E: Default + Copy + Opacity + 'static
+{
+    /// Creates a fully opaque and enabled layer using the normal blend mode.
+    pub fn new<Decl>(source: NodeHandle<Decl>) -> Self
+    where
+        Decl: NodeDecl + 'static,
+        E: FromColorUnclamped<<<Decl as NodeDecl>::Node as Node>::Element>,
+    {
+        return Self {
+            source: source.boxed(),
+            opacity: 1.0f32.fixed().boxed(),
+            mode: BlendMode::Normal.fixed().boxed(),
+            enabled: true.fixed().boxed(),
+            mask: None,
+        };
+    }
+
+    pub fn with_opacity(mut self, opacity: impl Boxed<dyn DynBoundAttrDecl<f32>>) -> Self {
+        self.opacity = opacity.boxed();
+        return self;
+    }
+
+    pub fn with_mode(mut self, mode: impl Boxed<dyn DynFreeAttrDecl<BlendMode>>) -> Self {
+        self.mode = mode.boxed();
+        return self;
+    }
+
+    pub fn with_enabled(mut self, enabled: impl Boxed<dyn DynFreeAttrDecl<bool>>) -> Self {
+        self.enabled = enabled.boxed();
+        return self;
+    }
+
+    /// Limits the layer to the luminance of the mask.
+    ///
+    /// The luminance of each pixel of the mask scales the opacity of the layer at that pixel.
+    pub fn with_mask<Decl>(mut self, mask: NodeHandle<Decl>) -> Self
+    where
+        Decl: NodeDecl + 'static,
+        Rgb: FromColorUnclamped<<<Decl as NodeDecl>::Node as Node>::Element>,
+    {
+        self.mask = Some(mask.boxed());
+        return self;
+    }
+}
+
+/// Composites any number of layers.
+///
+/// The layers are blended in order on top of each other, starting from black. Each layer is combined with the result
+/// of the layers below using its blend mode and faded in by its opacity and the alpha of its pixels. Disabled layers
+/// are skipped.
+pub struct Layers<E>
+where E: Default + Copy + Opacity
+{
+    layers: Vec<Layer<E>>,
+}
+
+impl<E> Layers<E>
+where E: Default + Copy + Opacity
+{
+    pub fn new() -> Self {
+        return Self {
+            layers: Vec::new(),
+        };
+    }
+
+    pub fn with_layer(mut self, layer: Layer<E>) -> Self {
+        self.layers.push(layer);
+        return self;
+    }
+}
+
+impl<E> Default for Layers<E>
+where E: Default + Copy + Opacity
+{
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+struct LayerNode<E>
+where E: Default + Copy + Opacity + 'static
+{
+    source: NodeRef<BoxedNode<E>>,
+
+    opacity: BoxedAttr<f32>,
+    mode: BoxedAttr<BlendMode>,
+    enabled: BoxedAttr<bool>,
+
+    mask: Option<NodeRef<BoxedNode<Rgb>>>,
+}
+
+pub struct LayersNode<E>
+where E: Default + Copy + Opacity + 'static
+{
+    layers: Vec<LayerNode<E>>,
+}
+
+impl<E> NodeDecl for Layers<E>
+where E: Default + Copy + Opacity + Lerp + IntoColor<Rgb> + FromColor<Rgb> + 'static
+{
+    const KIND: &'static str = "layers";
+
+    type Node = LayersNode<E>;
+
+    async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
+        let mut layers = Vec::new();
+        for (i, layer) in self.layers.into_iter().enumerate() {
+            let source = builder.node(format!("layer-{i}"), layer.source).await?;
+            let mask = match layer.mask {
+                Some(mask) => Some(builder.node(format!("mask-{i}"), mask).await?),
+                None => None,
+            };
+
+            layers.push(LayerNode {
+                source,
+                opacity: builder.bound_attr(format!("opacity-{i}"), layer.opacity, Bounds::normal()).await?,
+                mode: builder.unbound_attr(format!("mode-{i}"), layer.mode).await?,
+                enabled: builder.unbound_attr(format!("enabled-{i}"), layer.enabled).await?,
+                mask,
+            });
+        }
+
+        return Ok(Self::Node {
+            layers,
+        });
+    }
+}
+
+impl<E> Node for LayersNode<E>
+where E: Default + Copy + Opacity + Lerp + IntoColor<Rgb> + FromColor<Rgb> + 'static
+{
+    type Element = E;
+
+    fn update(&mut self, ctx: &RenderContext, out: &mut Buffer<Self::Element>) -> Result<()> {
+        out.update(|_, _| E::default());
+
+        for layer in &mut self.layers {
+            let opacity = layer.opacity.update(ctx);
+            let mode = layer.mode.update(ctx);
+            let enabled = layer.enabled.update(ctx);
+
+            if !enabled {
+                continue;
+            }
+
+            let source = &ctx[layer.source];
+            let mask = layer.mask.map(|mask| &ctx[mask]);
+
+            out.update(|i, &base| {
+                let top = source.get(i);
+
+                let alpha = top.opacity();
+                let top = top.with_opacity(1.0);
+
+                let top = match mode {
+                    BlendMode::Normal => top,
+                    mode => E::from_color(mode.blend(base.into_color(), top.into_color())),
+                };
+
+                let opacity = match mask {
+                    Some(mask) => opacity * alpha * MaskChannel::Luminance.intensity(mask.get(i)),
+                    None => opacity * alpha,
+                };
+
+                return E::lerp(base, top, opacity);
+            });
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use palette::Srgba;
+    use serde::Deserialize;
+
+    use photonic::boxed::DynNodeDecl;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    fn opaque() -> config::Attr<f32> {
+        return config::Attr::Fixed(1.0);
+    }
+
+    fn normal() -> config::Attr<BlendMode> {
+        return config::Attr::Fixed(BlendMode::Normal);
+    }
+
+    fn enabled() -> config::Attr<bool> {
+        return config::Attr::Fixed(true);
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct LayerConfig {
+        pub source: config::Node,
+
+        #[serde(default = "opaque")]
+        pub opacity: config::Attr<f32>,
+
+        #[serde(default = "normal")]
+        pub mode: config::Attr<BlendMode>,
+
+        #[serde(default = "enabled")]
+        pub enabled: config::Attr<bool>,
+
+        pub mask: Option<config::Node>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub layers: Vec<LayerConfig>,
+    }
+
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product = Layers<Srgba>;
+
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
+            let layers = config
+                .layers
+                .into_iter()
+                .enumerate()
+                .map(|(i, layer)| {
+                    return Ok(Layer {
+                        source: builder.node(&format!("layers.{i}.source"), layer.source)?,
+                        opacity: builder.bound_attr(&format!("layers.{i}.opacity"), layer.opacity)?,
+                        mode: builder.free_attr(&format!("layers.{i}.mode"), layer.mode)?,
+                        enabled: builder.free_attr(&format!("layers.{i}.enabled"), layer.enabled)?,
+                        mask: layer
+                            .mask
+                            .map(|mask| builder.node(&format!("layers.{i}.mask"), mask).map(NodeHandle::boxed))
+                            .transpose()?,
+                    });
+                })
+                .collect::<Result<_>>()?;
+
+            return Ok(Layers {
+                layers,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use palette::Srgba;
+
+    use photonic::Scene;
+
+    use crate::nodes::Solid;
+    use crate::testing::Renderer;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn transparent_layer() {
+        let mut scene = Scene::new();
+
+        let bottom = scene
+            .node("bottom", Solid {
+                color: Rgb::new(1.0, 0.0, 0.0).fixed(),
+            })
+            .unwrap();
+
+        let top = scene
+            .node("top", Buffer::<Srgba>::from_generator(2, |i| Srgba::new(0.0, 0.0, 1.0, [0.5, 0.0][i])))
+            .unwrap();
+
+        let layers = scene
+            .node("layers", Layers::<Srgba>::new().with_layer(Layer::new(bottom)).with_layer(Layer::new(top)))
+            .unwrap();
+
+        let mut renderer = Renderer::new(scene, layers, 2).await.unwrap();
+        let frame = renderer.render(Duration::ZERO).await.unwrap();
+
+        assert_eq!(frame, vec![Rgb::new(0.5, 0.0, 0.5), Rgb::new(1.0, 0.0, 0.0)]);
+    }
+}
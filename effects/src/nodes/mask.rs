@@ -0,0 +1,166 @@
+use anyhow::Result;
+use palette::rgb::Rgb;
+use palette::{Darken, FromColor, IntoColor, SrgbLuma};
+
+use photonic::boxed::{BoxedNode, BoxedNodeDecl};
+use photonic::math::Lerp;
+use photonic::{Buffer, BufferReader, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, Opacity, RenderContext};
+
+/// The channel of the mask used as intensity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "dynamic", derive(serde::Deserialize))]
+#[cfg_attr(feature = "dynamic", serde(rename_all = "snake_case"))]
+pub enum MaskChannel {
+    #[default]
+    Luminance,
+    Red,
+    Green,
+    Blue,
+    Alpha,
+}
+
+impl MaskChannel {
+    /// Returns the intensity of the given element in `[0, 1]`.
+    pub fn intensity<E>(self, element: E) -> f32
+    where E: IntoColor<Rgb> + Copy + Opacity {
+        let rgb: Rgb = element.into_color();
+
+        let value = match self {
+            Self::Luminance => SrgbLuma::from_color(rgb).luma,
+            Self::Red => rgb.red,
+            Self::Green => rgb.green,
+            Self::Blue => rgb.blue,
+            Self::Alpha => element.opacity(),
+        };
+
+        return value.clamp(0.0, 1.0);
+    }
+}
+
+/// Confines a source to the regions of a mask.
+///
+/// The intensity of each pixel of the mask scales the brightness of the source at that pixel. If a background is
+/// given, the mask fades between the background and the source instead.
+pub struct Mask<Source, Mask>
+where
+    Source: NodeDecl,
+    Mask: NodeDecl,
+{
+    pub source: NodeHandle<Source>,
+    pub mask: NodeHandle<Mask>,
+
+    pub background: Option<NodeHandle<BoxedNodeDecl<<Source::Node as Node>::Element>>>,
+
+    pub channel: MaskChannel,
+}
+
+pub struct MaskNode<Source, Mask>
+where
+    Source: Node + 'static,
+    Mask: Node + 'static,
+{
+    source: NodeRef<Source>,
+    mask: NodeRef<Mask>,
+
+    background: Option<NodeRef<BoxedNode<Source::Element>>>,
+
+    channel: MaskChannel,
+}
+
+impl<Source, Mask> NodeDecl for self::Mask<Source, Mask>
+where
+    Source: NodeDecl + 'static,
+    Mask: NodeDecl + 'static,
+    <Source::Node as Node>::Element: Lerp + Darken<Scalar = f32> + 'static,
+    <Mask::Node as Node>::Element: IntoColor<Rgb>,
+{
+    const KIND: &'static str = "mask";
+
+    type Node = MaskNode<Source::Node, Mask::Node>;
+
+    async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
+        let source = builder.node("source", self.source).await?;
+        let mask = builder.node("mask", self.mask).await?;
+        let background = match self.background {
+            Some(background) => Some(builder.node("background", background).await?),
+            None => None,
+        };
+
+        return Ok(Self::Node {
+            source,
+            mask,
+            background,
+            channel: self.channel,
+        });
+    }
+}
+
+impl<Source, Mask> Node for MaskNode<Source, Mask>
+where
+    Source: Node,
+    Mask: Node,
+    Source::Element: Lerp + Darken<Scalar = f32>,
+    Mask::Element: IntoColor<Rgb>,
+{
+    type Element = Source::Element;
+
+    fn update(&mut self, ctx: &RenderContext, out: &mut Buffer<Self::Element>) -> Result<()> {
+        let source = &ctx[self.source];
+        let mask = &ctx[self.mask];
+
+        match self.background {
+            Some(background) => {
+                let background = &ctx[background];
+                out.update(|i, _| {
+                    let intensity = self.channel.intensity(mask.get(i));
+                    return Self::Element::lerp(background.get(i), source.get(i), intensity);
+                });
+            }
+
+            None => {
+                out.update(|i, _| {
+                    let intensity = self.channel.intensity(mask.get(i));
+                    return source.get(i).darken(1.0 - intensity);
+                });
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "dynamic")]
+pub mod dynamic {
+    use palette::Srgba;
+    use serde::Deserialize;
+
+    use photonic::boxed::DynNodeDecl;
+    use photonic_dynamic::factory::Producible;
+    use photonic_dynamic::registry::Registry;
+    use photonic_dynamic::{builder, config};
+
+    use super::*;
+
+    #[derive(Deserialize, Debug)]
+    pub struct Config {
+        pub source: config::Node,
+        pub mask: config::Node,
+
+        pub background: Option<config::Node>,
+
+        #[serde(default)]
+        pub channel: MaskChannel,
+    }
+
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product = Mask<BoxedNodeDecl<Srgba>, BoxedNodeDecl<Srgba>>;
+        fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
+            return Ok(Mask {
+                source: builder.node("source", config.source)?,
+                mask: builder.node("mask", config.mask)?,
+                background: config.background.map(|background| builder.node("background", background)).transpose()?,
+                channel: config.channel,
+            });
+        }
+    }
+}
@@ -48,10 +48,10 @@ where
 
     async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
         return Ok(Self::Node {
-            speed: builder.unbound_attr("speed", self.speed)?,
-            stretch: builder.unbound_attr("stretch", self.stretch)?,
-            shift: builder.unbound_attr("shift", self.shift)?,
-            scale: builder.unbound_attr("scale", self.scale)?,
+            speed: builder.unbound_attr("speed", self.speed).await?,
+            stretch: builder.unbound_attr("stretch", self.stretch).await?,
+            shift: builder.unbound_attr("shift", self.shift).await?,
+            scale: builder.unbound_attr("scale", self.scale).await?,
             position: 0.0,
             noise: self.noise,
         });
@@ -87,7 +87,7 @@ where
 
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
-    use palette::rgb::Rgb;
+    use palette::Srgba;
     use serde::Deserialize;
 
     use photonic::boxed::{BoxedFreeAttrDecl, DynNodeDecl};
@@ -144,7 +144,7 @@ pub mod dynamic {
         Box<dyn NoiseFn<f64, 2>>,
     >;
 
-    impl Producible<dyn DynNodeDecl<Rgb>> for Config {
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
         type Product = BoxedNoise;
         fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Noise {
@@ -1,15 +1,21 @@
 use anyhow::Result;
-use palette::IntoColor;
+use palette::rgb::Rgb;
+use palette::{FromColor, IntoColor};
 
-use photonic::attr::Bounds;
+use photonic::attr::{AsFixedAttr, Bounds, FixedAttrDecl};
 use photonic::math::Lerp;
 use photonic::{
-    Attr, BoundAttrDecl, Buffer, BufferReader, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, RenderContext,
+    Attr, BoundAttrDecl, Buffer, BufferReader, FreeAttrDecl, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, Opacity,
+    RenderContext,
 };
 
-// TODO: Support blend modes
+use crate::blend::BlendMode;
 
-pub struct Overlay<Base, Pave, Blend>
+/// Blends the pave on top of the base.
+///
+/// The pave is combined with the base using the blend mode. The blend attribute fades between the base and the
+/// combined result. Pixels of the pave with an alpha channel are additionally faded in by their alpha.
+pub struct Overlay<Base, Pave, Blend, Mode = FixedAttrDecl<BlendMode>>
 where
     Base: NodeDecl,
     Pave: NodeDecl,
@@ -17,9 +23,47 @@ where
     pub base: NodeHandle<Base>,
     pub pave: NodeHandle<Pave>,
     pub blend: Blend,
+    pub mode: Mode,
 }
 
-pub struct OverlayNode<Base, Pave, Blend>
+impl<Base, Pave, Blend> Overlay<Base, Pave, Blend>
+where
+    Base: NodeDecl,
+    Pave: NodeDecl,
+{
+    /// Blends the pave on top of the base using the normal blend mode.
+    pub fn new(base: NodeHandle<Base>, pave: NodeHandle<Pave>, blend: Blend) -> Self {
+        return Self {
+            base,
+            pave,
+            blend,
+            mode: BlendMode::Normal.fixed(),
+        };
+    }
+
+    pub fn with_mode(mut self, mode: BlendMode) -> Self {
+        self.mode = mode.fixed();
+        return self;
+    }
+}
+
+impl<Base, Pave, Blend, Mode> Overlay<Base, Pave, Blend, Mode>
+where
+    Base: NodeDecl,
+    Pave: NodeDecl,
+{
+    /// Use an attribute to control the blend mode.
+    pub fn with_mode_attr<M>(self, mode: M) -> Overlay<Base, Pave, Blend, M> {
+        return Overlay {
+            base: self.base,
+            pave: self.pave,
+            blend: self.blend,
+            mode,
+        };
+    }
+}
+
+pub struct OverlayNode<Base, Pave, Blend, Mode>
 where
     Base: Node + 'static,
     Pave: Node + 'static,
@@ -28,35 +72,39 @@ where
     pave: NodeRef<Pave>,
 
     blend: Blend,
+    mode: Mode,
 }
 
-impl<Base, Pave, Blend> NodeDecl for Overlay<Base, Pave, Blend>
+impl<Base, Pave, Blend, Mode> NodeDecl for Overlay<Base, Pave, Blend, Mode>
 where
     Base: NodeDecl + 'static,
     Pave: NodeDecl + 'static,
     Blend: BoundAttrDecl<f32>,
-    <<Base as NodeDecl>::Node as Node>::Element: Lerp,
+    Mode: FreeAttrDecl<BlendMode>,
+    <<Base as NodeDecl>::Node as Node>::Element: Lerp + IntoColor<Rgb> + FromColor<Rgb>,
     <<Pave as NodeDecl>::Node as Node>::Element: IntoColor<<<Base as NodeDecl>::Node as Node>::Element>,
 {
     const KIND: &'static str = "overlay";
 
-    type Node = OverlayNode<Base::Node, Pave::Node, Blend::Attr>;
+    type Node = OverlayNode<Base::Node, Pave::Node, Blend::Attr, Mode::Attr>;
 
     async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
         return Ok(Self::Node {
             base: builder.node("base", self.base).await?,
             pave: builder.node("pave", self.pave).await?,
-            blend: builder.bound_attr("blend", self.blend, Bounds::normal())?,
+            blend: builder.bound_attr("blend", self.blend, Bounds::normal()).await?,
+            mode: builder.unbound_attr("mode", self.mode).await?,
         });
     }
 }
 
-impl<Base, Pave, Blend> Node for OverlayNode<Base, Pave, Blend>
+impl<Base, Pave, Blend, Mode> Node for OverlayNode<Base, Pave, Blend, Mode>
 where
     Base: Node,
     Pave: Node,
     Blend: Attr<f32>,
-    Base::Element: Lerp,
+    Mode: Attr<BlendMode>,
+    Base::Element: Lerp + IntoColor<Rgb> + FromColor<Rgb>,
     Pave::Element: IntoColor<Base::Element>,
 {
     type Element = Base::Element;
@@ -66,13 +114,22 @@ where
         let pave = &ctx[self.pave];
 
         let blend = self.blend.update(ctx);
+        let mode = self.mode.update(ctx);
 
         out.update(|i, _| {
             let base = base.get(i);
             let pave = pave.get(i);
 
-            // TODO: Blending modes
-            return Self::Element::lerp(base, pave.into_color(), blend);
+            // The alpha of the pave fades it in on top of the base
+            let alpha = pave.opacity();
+            let pave = IntoColor::<Self::Element>::into_color(pave).with_opacity(1.0);
+
+            let top = match mode {
+                BlendMode::Normal => pave,
+                mode => Self::Element::from_color(mode.blend(base.into_color(), pave.into_color())),
+            };
+
+            return Self::Element::lerp(base, top, blend * alpha);
         });
 
         return Ok(());
@@ -81,31 +138,76 @@ where
 
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
-    use palette::rgb::Rgb;
+    use palette::Srgba;
     use serde::Deserialize;
 
-    use photonic::boxed::{BoxedBoundAttrDecl, BoxedNodeDecl, DynNodeDecl};
+    use photonic::boxed::{BoxedBoundAttrDecl, BoxedFreeAttrDecl, BoxedNodeDecl, DynNodeDecl};
     use photonic_dynamic::factory::Producible;
     use photonic_dynamic::registry::Registry;
     use photonic_dynamic::{builder, config};
 
     use super::*;
 
+    fn normal() -> config::Attr<BlendMode> {
+        return config::Attr::Fixed(BlendMode::Normal);
+    }
+
     #[derive(Deserialize, Debug)]
     pub struct Config {
         pub base: config::Node,
         pub pave: config::Node,
         pub blend: config::Attr<f32>,
+
+        #[serde(default = "normal")]
+        pub mode: config::Attr<BlendMode>,
     }
 
-    impl Producible<dyn DynNodeDecl<Rgb>> for Config {
-        type Product = Overlay<BoxedNodeDecl<Rgb>, BoxedNodeDecl<Rgb>, BoxedBoundAttrDecl<f32>>;
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product =
+            Overlay<BoxedNodeDecl<Srgba>, BoxedNodeDecl<Srgba>, BoxedBoundAttrDecl<f32>, BoxedFreeAttrDecl<BlendMode>>;
         fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Overlay {
                 base: builder.node("base", config.base)?,
                 pave: builder.node("pave", config.pave)?,
                 blend: builder.bound_attr("blend", config.blend)?,
+                mode: builder.free_attr("mode", config.mode)?,
             });
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use palette::Srgba;
+
+    use photonic::{Buffer, Scene};
+
+    use crate::nodes::Solid;
+    use crate::testing::Renderer;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn transparent_pave() {
+        let mut scene = Scene::new();
+
+        let base = scene
+            .node("base", Solid {
+                color: Rgb::new(1.0, 0.0, 0.0).fixed(),
+            })
+            .unwrap();
+
+        let pave = scene
+            .node("pave", Buffer::<Srgba>::from_generator(2, |i| Srgba::new(0.0, 0.0, 1.0, [0.5, 0.0][i])))
+            .unwrap();
+
+        let overlay = scene.node("overlay", Overlay::new(base, pave, 1.0f32.fixed())).unwrap();
+
+        let mut renderer = Renderer::new(scene, overlay, 2).await.unwrap();
+        let frame = renderer.render(Duration::ZERO).await.unwrap();
+
+        assert_eq!(frame, vec![Rgb::new(0.5, 0.0, 0.5), Rgb::new(1.0, 0.0, 0.0)]);
+    }
+}
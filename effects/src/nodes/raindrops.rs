@@ -45,9 +45,9 @@ where
 
     async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
         return Ok(Self::Node {
-            rate: builder.bound_attr("rate", self.rate, Bounds::normal())?,
-            color: builder.unbound_attr("color", self.color)?,
-            decay: builder.unbound_attr("decay", self.decay)?,
+            rate: builder.bound_attr("rate", self.rate, Bounds::normal()).await?,
+            color: builder.unbound_attr("color", self.color).await?,
+            decay: builder.unbound_attr("decay", self.decay).await?,
             drops: (0..builder.size).map(|_| Raindrop::default()).collect::<Vec<_>>().into_boxed_slice(),
             random: Random::new(),
         });
@@ -89,6 +89,7 @@ where
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
     use palette::rgb::Rgb;
+    use palette::Srgba;
     use serde::Deserialize;
 
     use photonic::attr::FreeAttrDeclExt;
@@ -109,7 +110,7 @@ pub mod dynamic {
     type BoxedRaindrops =
         Raindrops<BoxedBoundAttrDecl<f32>, BoxedFreeAttrDecl<Range<Hsl>>, BoxedFreeAttrDecl<Range<f32>>>;
 
-    impl Producible<dyn DynNodeDecl<Rgb>> for Config {
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
         type Product = BoxedRaindrops;
         fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Raindrops {
@@ -2,41 +2,61 @@ use anyhow::Result;
 use palette::convert::FromColorUnclamped;
 use std::time::Duration;
 
+use photonic::attr::FixedAttrDecl;
 use photonic::boxed::{Boxed, DynNode, DynNodeDecl};
 use photonic::math::Lerp;
 use photonic::{
-    Attr, BoundAttrDecl, Buffer, BufferReader, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, RenderContext,
+    Attr, BoundAttrDecl, Buffer, BufferReader, FreeAttrDecl, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, Opacity,
+    RenderContext,
 };
 
-use crate::easing::{Easing, Easings};
+use crate::easing::{Easing, Easings, TransitionPolicy};
 
-pub struct Select<E, Value>
+pub struct Select<E, Value, Speed = FixedAttrDecl<Duration>>
 where
     Value: BoundAttrDecl<usize>,
-    E: Default + Copy,
+    E: Default + Copy + Opacity,
 {
     value: Value,
 
     sources: Vec<NodeHandle<Box<dyn DynNodeDecl<E>>>>,
 
-    easing: Easing<f32>,
+    easing: Easing<f32, Speed>,
+
+    policy: TransitionPolicy,
 }
 
 impl<E, Value> Select<E, Value>
 where
     Value: BoundAttrDecl<usize>,
-    E: Default + Copy,
+    E: Default + Copy + Opacity,
 {
     pub fn with_value(value: Value) -> Self {
         return Self {
             value,
             sources: vec![],
             easing: Easings::Instant.with_speed(Duration::ZERO),
+            policy: TransitionPolicy::default(),
+        };
+    }
+}
+
+impl<E, Value, Speed> Select<E, Value, Speed>
+where
+    Value: BoundAttrDecl<usize>,
+    E: Default + Copy + Opacity,
+{
+    pub fn with_easing<S>(self, easing: impl Into<Easing<f32, S>>) -> Select<E, Value, S> {
+        return Select {
+            value: self.value,
+            sources: self.sources,
+            easing: easing.into(),
+            policy: self.policy,
         };
     }
 
-    pub fn with_easing(mut self, easing: impl Into<Easing<f32>>) -> Self {
-        self.easing = easing.into();
+    pub fn with_policy(mut self, policy: TransitionPolicy) -> Self {
+        self.policy = policy;
         return self;
     }
 
@@ -50,30 +70,63 @@ where
     }
 }
 
-pub struct SelectNode<E, Value>
+pub struct SelectNode<E, Value, Speed>
 where
     Value: Attr<usize>,
-    E: Default + Copy + 'static,
+    Speed: Attr<Duration>,
+    E: Default + Copy + Opacity + 'static,
 {
     sources: Vec<NodeRef<Box<dyn DynNode<E>>>>,
     value: Value,
 
-    last: Option<usize>,
-    next: Option<usize>,
+    policy: TransitionPolicy,
+
+    /// Last value seen from the value attribute
+    seen: Option<usize>,
+
+    /// Blend of the sources the running transition started from
+    from: Vec<f32>,
+
+    /// Source the running transition fades to
+    target: usize,
+    queued: Option<usize>,
 
     fade: f32,
 
-    easing: Easing<f32>,
+    easing: Easing<f32, Speed>,
 }
 
-impl<E, Value> NodeDecl for Select<E, Value>
+impl<E, Value, Speed> SelectNode<E, Value, Speed>
+where
+    Value: Attr<usize>,
+    Speed: Attr<Duration>,
+    E: Default + Copy + Opacity + 'static,
+{
+    /// Starts a transition from the current blend of sources to the given target.
+    fn retarget(&mut self, target: usize) {
+        let fade = self.easing.curve.apply(self.fade);
+
+        for (i, weight) in self.from.iter_mut().enumerate() {
+            *weight *= 1.0 - fade;
+            if i == self.target {
+                *weight += fade;
+            }
+        }
+
+        self.target = target;
+        self.fade = 0.0;
+    }
+}
+
+impl<E, Value, Speed> NodeDecl for Select<E, Value, Speed>
 where
     Value: BoundAttrDecl<usize>,
-    E: Default + Copy + Lerp + 'static,
+    Speed: FreeAttrDecl<Duration>,
+    E: Default + Copy + Opacity + Lerp + 'static,
 {
     const KIND: &'static str = "select";
 
-    type Node = SelectNode<E, Value::Attr>;
+    type Node = SelectNode<E, Value::Attr, Speed::Attr>;
 
     async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
         let mut sources = Vec::new();
@@ -81,89 +134,129 @@ where
             sources.push(builder.node(format!("source-{i}"), source).await?);
         }
 
-        let value = builder.bound_attr("value", self.value, (0, sources.len() - 1))?;
+        let value = builder.bound_attr("value", self.value, (0, sources.len() - 1)).await?;
+        let speed = builder.unbound_attr("speed", self.easing.speed).await?;
+        let easing = Easing {
+            curve: self.easing.curve,
+            speed,
+        };
 
         return Ok(Self::Node {
+            from: vec![0.0; sources.len()],
             sources,
             value,
-            last: None,
-            next: None,
-            fade: 0.0,
-            easing: self.easing,
+            policy: self.policy,
+            seen: None,
+            target: 0,
+            queued: None,
+            fade: 1.0,
+            easing,
         });
     }
 }
 
-impl<E, Value> Node for SelectNode<E, Value>
+impl<E, Value, Speed> Node for SelectNode<E, Value, Speed>
 where
     Value: Attr<usize>,
-    E: Default + Copy + Lerp,
+    Speed: Attr<Duration>,
+    E: Default + Copy + Opacity + Lerp,
 {
     type Element = E;
 
     fn update(&mut self, ctx: &RenderContext, out: &mut Buffer<Self::Element>) -> Result<()> {
         let curr = self.value.update(ctx);
+        let speed = self.easing.speed.update(ctx);
 
-        let Some(last) = self.last else {
+        let Some(seen) = self.seen else {
             // First cycle - set initial current value
-            self.last = Some(curr);
+            self.seen = Some(curr);
+            self.target = curr;
+            self.from[curr] = 1.0;
 
             out.blit_from(&ctx[self.sources[curr]]);
             return Ok(());
         };
 
-        if let Some(next) = self.next {
+        if curr != seen {
+            self.seen = Some(curr);
+
+            match (self.fade < 1.0, self.policy) {
+                (false, _) | (true, TransitionPolicy::Retarget) => {
+                    self.retarget(curr);
+                    self.queued = None;
+                }
+                (true, TransitionPolicy::Queue) => {
+                    self.queued = Some(curr);
+                }
+                (true, TransitionPolicy::Ignore) => {}
+            }
+        }
+
+        if self.fade < 1.0 {
             // In transition
-            self.fade += ctx.duration.as_secs_f32() / self.easing.speed.as_secs_f32();
+            self.fade += ctx.duration.as_secs_f32() / speed.as_secs_f32();
 
             if self.fade >= 1.0 {
-                // Transition finished
-                self.last = Some(next);
-                self.next = None;
+                // Transition finished - continue with the queued target, if any
+                self.fade = 1.0;
 
-                out.blit_from(&ctx[self.sources[next]]);
-                return Ok(());
+                if let Some(next) = self.queued.take() {
+                    self.retarget(next);
+                }
             }
+        }
 
-            let source = &ctx[self.sources[last]];
-            let target = &ctx[self.sources[next]];
-
-            out.blit_from(BufferReader::lerp(source, target, (self.easing.func)(self.fade)));
+        if self.fade >= 1.0 {
+            out.blit_from(&ctx[self.sources[self.target]]);
             return Ok(());
         }
 
-        if curr != last {
-            // Start transition
-            self.next = Some(curr);
-            self.fade = 0.0;
-        }
+        let fade = self.easing.curve.apply(self.fade);
+        let target = &ctx[self.sources[self.target]];
+
+        out.update(|i, _| {
+            // Blend all sources contributing to the start of the transition
+            let mut total = 0.0;
+            let mut source = E::default();
+            for (weight, node) in self.from.iter().zip(self.sources.iter()) {
+                if *weight > 0.0 {
+                    total += weight;
+                    source = E::lerp(source, ctx[*node].get(i), weight / total);
+                }
+            }
+
+            return E::lerp(source, target.get(i), fade);
+        });
 
-        out.blit_from(&ctx[self.sources[last]]);
         return Ok(());
     }
 }
 
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
-    use palette::rgb::Rgb;
+    use palette::Srgba;
     use serde::Deserialize;
 
-    use photonic::boxed::BoxedBoundAttrDecl;
+    use photonic::boxed::{BoxedBoundAttrDecl, BoxedFreeAttrDecl};
     use photonic_dynamic::factory::Producible;
     use photonic_dynamic::registry::Registry;
     use photonic_dynamic::{builder, config};
 
     use super::*;
+    use crate::easing;
 
     #[derive(Deserialize, Debug)]
     pub struct Config {
         pub sources: Vec<config::Node>,
         pub value: config::Attr<usize>,
-        pub easing: Easing<f32>,
+        pub easing: easing::dynamic::Config,
+
+        #[serde(default)]
+        pub policy: TransitionPolicy,
     }
 
-    impl Producible<dyn DynNodeDecl<Rgb>> for Config {
-        type Product = Select<Rgb, BoxedBoundAttrDecl<usize>>;
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product = Select<Srgba, BoxedBoundAttrDecl<usize>, BoxedFreeAttrDecl<Duration>>;
         fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
             let sources = config
                 .sources
@@ -175,7 +268,8 @@ pub mod dynamic {
             return Ok(Select {
                 sources,
                 value: builder.bound_attr("value", config.value)?,
-                easing: config.easing,
+                easing: config.easing.func.with_speed_attr(builder.free_attr("speed", config.easing.speed)?)?,
+                policy: config.policy,
             });
         }
     }
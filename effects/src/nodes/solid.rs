@@ -24,7 +24,7 @@ where Color: FreeAttrDecl<Rgb>
 
     async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
         return Ok(Self::Node {
-            color: builder.unbound_attr("color", self.color)?,
+            color: builder.unbound_attr("color", self.color).await?,
         });
     }
 }
@@ -45,6 +45,7 @@ where Color: Attr<Rgb>
 
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
+    use palette::Srgba;
     use serde::Deserialize;
 
     use photonic::boxed::{BoxedFreeAttrDecl, DynNodeDecl};
@@ -59,7 +60,7 @@ pub mod dynamic {
         pub color: config::Attr<Rgb>,
     }
 
-    impl Producible<dyn DynNodeDecl<Rgb>> for Config {
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
         type Product = Solid<BoxedFreeAttrDecl<Rgb>>;
         fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Solid {
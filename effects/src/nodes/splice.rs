@@ -1,6 +1,6 @@
 use anyhow::{bail, Result};
 
-use photonic::{Buffer, BufferReader, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, RenderContext};
+use photonic::{Buffer, BufferReader, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, Opacity, RenderContext};
 
 pub struct Splice<N1, N2>
 where
@@ -43,7 +43,7 @@ where
     N2: NodeDecl,
     <N1 as NodeDecl>::Node: Node<Element = E> + 'static,
     <N2 as NodeDecl>::Node: Node<Element = E> + 'static,
-    E: Default + Copy,
+    E: Default + Copy + Opacity,
 {
     const KIND: &'static str = "splice";
 
@@ -65,7 +65,7 @@ impl<N1, N2, E> Node for SpliceNode<N1, N2>
 where
     N1: Node<Element = E> + 'static,
     N2: Node<Element = E> + 'static,
-    E: Default + Copy,
+    E: Default + Copy + Opacity,
 {
     type Element = E;
 
@@ -87,7 +87,7 @@ where
 
 #[cfg(feature = "dynamic")]
 pub mod dynamic {
-    use palette::rgb::Rgb;
+    use palette::Srgba;
     use serde::Deserialize;
 
     use photonic::boxed::{BoxedNodeDecl, DynNodeDecl};
@@ -104,8 +104,8 @@ pub mod dynamic {
         pub split: isize,
     }
 
-    impl Producible<dyn DynNodeDecl<Rgb>> for Config {
-        type Product = Splice<BoxedNodeDecl<Rgb>, BoxedNodeDecl<Rgb>>;
+    impl Producible<dyn DynNodeDecl<Srgba>> for Config {
+        type Product = Splice<BoxedNodeDecl<Srgba>, BoxedNodeDecl<Srgba>>;
         fn produce<Reg: Registry>(config: Self, mut builder: builder::NodeBuilder<'_, Reg>) -> Result<Self::Product> {
             return Ok(Splice {
                 n1: builder.node("n1", config.n1)?,
@@ -0,0 +1,82 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use anyhow::Result;
+use palette::rgb::Rgb;
+use palette::FromColor;
+
+use photonic::{BufferReader, Loop, Node, NodeDecl, NodeHandle, Output, OutputDecl, Scene};
+
+/// Output keeping a copy of the last rendered frame.
+pub struct Capture {
+    size: usize,
+    frame: Rc<RefCell<Vec<Rgb>>>,
+}
+
+impl OutputDecl for Capture {
+    const KIND: &'static str = "capture";
+
+    type Output = Self;
+
+    async fn materialize(self) -> Result<Self::Output> {
+        return Ok(self);
+    }
+}
+
+impl Output for Capture {
+    const KIND: &'static str = "capture";
+
+    type Element = Rgb;
+
+    async fn render(&mut self, out: impl BufferReader<Element = Self::Element>) -> Result<()> {
+        *self.frame.borrow_mut() = out.iter().collect();
+        return Ok(());
+    }
+
+    fn size(&self) -> usize {
+        return self.size;
+    }
+}
+
+/// Renders a scene frame by frame.
+pub struct Renderer<N>
+where
+    N: Node + 'static,
+    Rgb: FromColor<N::Element>,
+{
+    main: Loop<N, Capture>,
+    frame: Rc<RefCell<Vec<Rgb>>>,
+}
+
+impl<N> Renderer<N>
+where
+    N: Node + 'static,
+    Rgb: FromColor<N::Element>,
+{
+    pub async fn new<Decl>(scene: Scene, root: NodeHandle<Decl>, size: usize) -> Result<Self>
+    where
+        Decl: NodeDecl<Node = N>,
+        N::Element: Default,
+    {
+        let frame = Rc::new(RefCell::new(Vec::new()));
+
+        let main = scene
+            .run(root, Capture {
+                size,
+                frame: frame.clone(),
+            })
+            .await?;
+
+        return Ok(Self {
+            main,
+            frame,
+        });
+    }
+
+    /// Renders the next frame after the given duration.
+    pub async fn render(&mut self, duration: Duration) -> Result<Vec<Rgb>> {
+        self.main.render(duration).await?;
+        return Ok(self.frame.borrow().clone());
+    }
+}
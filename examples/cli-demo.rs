@@ -32,7 +32,7 @@ async fn main() -> Result<()> {
 
     let mut scene = scene.run(brightness, output).await?;
 
-    let cli = photonic_interface_cli::stdio::CLI;
+    let cli = photonic_interface_cli::stdio::CLI::default();
     scene.serve("CLI", cli);
 
     let grpc = photonic_interface_grpc::GRPC::new()?;
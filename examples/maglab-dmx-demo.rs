@@ -10,7 +10,7 @@ use photonic::attr::{AsFixedAttr, Range};
 use photonic::node::map::Map;
 use photonic::{Rgbw, Scene, WithWhite};
 use photonic_effects::attrs::{Button, Fader, Sequence, Switch};
-use photonic_effects::easing::{EasingDirection, Easings};
+use photonic_effects::easing::{EasingDirection, Easings, TransitionPolicy};
 use photonic_effects::nodes::{Alert, Blackout, Brightness, ColorWheel, Noise, Overlay, Raindrops, Select};
 use photonic_output_net::netdmx::{Channel, Channels, Fixture, NetDmxSender};
 use photonic_output_terminal::Terminal;
@@ -22,6 +22,8 @@ async fn main() -> Result<()> {
     let input_next = scene.input("next")?;
     let input_prev = scene.input("prev")?;
 
+    let input_preset = scene.input("preset")?;
+
     let raindrops = scene.node("raindrops", Raindrops {
         rate: 0.3.fixed(),
         decay: (0.96, 0.98).fixed(),
@@ -36,6 +38,7 @@ async fn main() -> Result<()> {
                 ],
             },
             easing: Easings::Quadratic(EasingDirection::InOut).with_speed(Duration::from_secs(2)),
+            policy: TransitionPolicy::Retarget,
         },
     })?;
 
@@ -75,6 +78,7 @@ async fn main() -> Result<()> {
         value: Fader {
             input: input_brightness.attr(0.0),
             easing: Easings::Cubic(EasingDirection::InOut).with_speed(Duration::from_secs(1)),
+            policy: TransitionPolicy::Retarget,
         },
         source: animation,
         range: None,
@@ -83,23 +87,23 @@ async fn main() -> Result<()> {
     let alert = scene.node("alert", Alert {
         hue: 0.0.fixed(),
         block: 1.fixed(),
-        speed: 1.0.fixed(),
+        speed: Duration::from_secs(1).fixed(),
     })?;
 
     let input_alert = scene.input("alert")?;
-    let alert = scene.node("alert_overlay", Overlay {
-        base: brightness,
-        pave: alert,
-        blend: Fader {
+    let alert = scene.node(
+        "alert_overlay",
+        Overlay::new(brightness, alert, Fader {
             input: Button {
                 value_release: 0.0,
                 value_pressed: 1.0,
-                hold_time: Duration::from_secs(5),
+                hold_time: Duration::from_secs(5).fixed(),
                 trigger: input_alert,
             },
             easing: Easings::Quartic(EasingDirection::InOut).with_speed(Duration::from_secs(1)),
-        },
-    })?;
+            policy: TransitionPolicy::Retarget,
+        }),
+    )?;
 
     // let uv_larson = scene.node("uv_larson", Larson {
     //     hue: 0.0.fixed(),
@@ -128,6 +132,7 @@ async fn main() -> Result<()> {
                 pressed: input_kitchen.attr(false),
             },
             easing: Easings::Linear.with_speed(Duration::from_secs(1)),
+            policy: TransitionPolicy::Retarget,
         },
         value: Srgb::new(0.0, 0.0, 0.0).full(),
         range: Some(0..2),
@@ -170,13 +175,21 @@ async fn main() -> Result<()> {
     // };
     // scene.serve("restore", restore);
 
-    let cli = photonic_interface_cli::stdio::CLI;
+    let presets = photonic_interface_presets::Presets::with_path("/tmp/photonic.example.presets").with_trigger(
+        input_preset,
+        "default",
+        Duration::from_secs(3),
+    );
+    scene.serve("presets", presets.clone());
+
+    let cli = photonic_interface_cli::stdio::CLI::default().with_presets(presets.clone());
     scene.serve("CLI", cli);
 
-    let mqtt = photonic_interface_mqtt::MQTT::with_url("mqtt://localhost:1883?client_id=photonic")?;
+    let mqtt = photonic_interface_mqtt::MQTT::with_url("mqtt://localhost:1883?client_id=photonic")?
+        .with_presets(presets.clone());
     scene.serve("MQTT", mqtt);
 
-    let grpc = photonic_interface_grpc::GRPC::new()?;
+    let grpc = photonic_interface_grpc::GRPC::new()?.with_presets(presets);
     scene.serve("GRPC", grpc);
 
     return scene.run(20).await;
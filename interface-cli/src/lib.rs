@@ -1,17 +1,27 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
-use palette::rgb::Rgb;
 use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, BufWriter};
 
-use photonic::attr::Range;
-use photonic::input::{InputSink, Trigger};
+use photonic::input::AnyInputValue;
 use photonic::interface::Introspection;
+use photonic_interface_presets::Presets;
+use photonic_interface_scheduler::Scheduler;
 
 pub mod stdio;
 pub mod telnet;
 
-async fn run(i: impl AsyncRead + Unpin, o: impl AsyncWrite + Unpin, introspection: Arc<Introspection>) -> Result<()> {
+/// Number of upcoming scheduled events listed by default.
+const SCHEDULE_UPCOMING: usize = 10;
+
+async fn run(
+    i: impl AsyncRead + Unpin,
+    o: impl AsyncWrite + Unpin,
+    introspection: Arc<Introspection>,
+    presets: Option<Presets>,
+    scheduler: Option<Scheduler>,
+) -> Result<()> {
     let i = BufReader::new(i);
     let mut o = BufWriter::new(o);
 
@@ -72,23 +82,10 @@ async fn run(i: impl AsyncRead + Unpin, o: impl AsyncWrite + Unpin, introspectio
                 if let Some(input) = line.get(1) {
                     if let Some(input) = introspection.inputs.get(input) {
                         if let Some(value) = line.get(2) {
-                            let res: Result<()> = (async {
-                                match &input.sink() {
-                                    InputSink::Trigger(sink) => sink.send(Trigger::next()).await,
-                                    InputSink::Boolean(sink) => sink.send(value.parse()?).await,
-                                    InputSink::Integer(sink) => sink.send(value.parse()?).await,
-                                    InputSink::Decimal(sink) => sink.send(value.parse()?).await,
-                                    InputSink::Color(sink) => {
-                                        sink.send(value.parse::<Rgb<_, u8>>()?.into_format()).await
-                                    }
-                                    InputSink::IntegerRange(sink) => sink.send(value.parse()?).await,
-                                    InputSink::DecimalRange(sink) => sink.send(value.parse()?).await,
-                                    InputSink::ColorRange(sink) => {
-                                        sink.send(value.parse::<Range<Rgb<_, u8>>>()?.map(Rgb::into_format)).await
-                                    }
-                                }
-                            })
-                            .await;
+                            let res = match AnyInputValue::parse(input.value_type(), value) {
+                                Ok(value) => input.sink().send_any(value).await,
+                                Err(err) => Err(err),
+                            };
 
                             match res {
                                 Ok(()) => {}
@@ -114,6 +111,65 @@ async fn run(i: impl AsyncRead + Unpin, o: impl AsyncWrite + Unpin, introspectio
                 }
             }
 
+            Some("preset") => {
+                let Some(presets) = &presets else {
+                    o.write_all("Presets not available\n".as_bytes()).await?;
+                    continue;
+                };
+
+                let res: Result<()> = (async {
+                    match (line.get(1).map(String::as_str), line.get(2)) {
+                        (None, _) => {
+                            for name in presets.list().await? {
+                                o.write_all(format!("{name}\n").as_bytes()).await?;
+                            }
+                        }
+                        (Some("save"), Some(name)) => presets.save(name).await?,
+                        (Some("delete"), Some(name)) => presets.delete(name).await?,
+                        (Some("recall"), Some(name)) => {
+                            let fade = match line.get(3) {
+                                Some(fade) => humantime::parse_duration(fade)?,
+                                None => Duration::ZERO,
+                            };
+                            presets.recall(name, fade).await?
+                        }
+                        (Some(command), _) => {
+                            o.write_all(
+                                format!("Usage: preset [save|recall|delete] <name> [<fade>] - got '{command}'\n")
+                                    .as_bytes(),
+                            )
+                            .await?
+                        }
+                    }
+                    return Ok(());
+                })
+                .await;
+
+                if let Err(err) = res {
+                    o.write_all(format!("Preset failed: {err:#}\n").as_bytes()).await?;
+                }
+            }
+
+            Some("schedule") => {
+                let Some(scheduler) = &scheduler else {
+                    o.write_all("Scheduler not available\n".as_bytes()).await?;
+                    continue;
+                };
+
+                let count = match line.get(1).map(|count| count.parse()) {
+                    Some(Ok(count)) => count,
+                    Some(Err(err)) => {
+                        o.write_all(format!("Invalid count: {err}\n").as_bytes()).await?;
+                        continue;
+                    }
+                    None => SCHEDULE_UPCOMING,
+                };
+
+                for event in scheduler.upcoming().take(count) {
+                    o.write_all(format!("{event}\n").as_bytes()).await?;
+                }
+            }
+
             Some(unknown) => {
                 o.write_all(format!("Unknown command: '{unknown}'\n").as_bytes()).await?;
                 continue;
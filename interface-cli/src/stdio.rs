@@ -3,14 +3,32 @@ use std::sync::Arc;
 use tokio::io::{stdin, stdout};
 
 use photonic::interface::{Interface, Introspection};
+use photonic_interface_presets::Presets;
+use photonic_interface_scheduler::Scheduler;
 
-pub struct CLI;
+#[derive(Default)]
+pub struct CLI {
+    pub presets: Option<Presets>,
+    pub scheduler: Option<Scheduler>,
+}
+
+impl CLI {
+    pub fn with_presets(mut self, presets: Presets) -> Self {
+        self.presets = Some(presets);
+        return self;
+    }
+
+    pub fn with_scheduler(mut self, scheduler: Scheduler) -> Self {
+        self.scheduler = Some(scheduler);
+        return self;
+    }
+}
 
 impl Interface for CLI {
     async fn listen(self, introspection: Arc<Introspection>) -> anyhow::Result<()> {
         let i = stdin();
         let o = stdout();
 
-        return super::run(i, o, introspection).await;
+        return super::run(i, o, introspection, self.presets, self.scheduler).await;
     }
 }
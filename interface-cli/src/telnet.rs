@@ -1,10 +1,16 @@
 use photonic::interface::{Interface, Introspection};
+use photonic_interface_presets::Presets;
+use photonic_interface_scheduler::Scheduler;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::net::TcpListener;
 
 pub struct CLI {
     pub address: SocketAddr,
+
+    pub presets: Option<Presets>,
+
+    pub scheduler: Option<Scheduler>,
 }
 
 impl Interface for CLI {
@@ -13,12 +19,14 @@ impl Interface for CLI {
 
         loop {
             let introspection = introspection.clone();
+            let presets = self.presets.clone();
+            let scheduler = self.scheduler.clone();
 
             let (mut stream, _remote) = listener.accept().await?;
 
             tokio::spawn(async move {
                 let (i, o) = stream.split();
-                super::run(i, o, introspection).await
+                super::run(i, o, introspection, presets, scheduler).await
             });
         }
     }
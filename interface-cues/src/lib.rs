@@ -0,0 +1,392 @@
+use std::collections::HashMap;
+use std::future;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use tokio::time::Instant;
+use tokio_stream::StreamMap;
+
+use photonic::input::{AnyInputValue, Input, InputValue, Trigger};
+use photonic::interface::{Interface, Introspection};
+use photonic::math::Lerp;
+use photonic::scene::InputHandle;
+use photonic::InputInfo;
+
+use crate::show::{Resolved, Show};
+
+pub mod show;
+
+/// Interval between two updates of the running cue
+const UPDATE_INTERVAL: Duration = Duration::from_millis(20);
+
+fn lerp(a: &AnyInputValue, b: &AnyInputValue, i: f32) -> AnyInputValue {
+    return match (a, b) {
+        (AnyInputValue::Decimal(a), AnyInputValue::Decimal(b)) => AnyInputValue::Decimal(Lerp::lerp(*a, *b, i)),
+        (AnyInputValue::Color(a), AnyInputValue::Color(b)) => AnyInputValue::Color(Lerp::lerp(*a, *b, i)),
+        (AnyInputValue::Gradient(a), AnyInputValue::Gradient(b)) => Lerp::lerp(**a, **b, i).into(),
+        (_, b) => b.clone(),
+    };
+}
+
+async fn recv<V>(input: &mut Option<Input<V>>) -> V
+where V: InputValue {
+    return match input {
+        Some(input) => input.recv().await,
+        None => future::pending().await,
+    };
+}
+
+struct Fade {
+    input: Arc<InputInfo>,
+
+    source: AnyInputValue,
+    target: AnyInputValue,
+
+    start: Duration,
+    duration: Duration,
+}
+
+struct Player {
+    show: Resolved,
+
+    /// Index of the current cue - `None` if the show has not been started
+    current: Option<usize>,
+
+    /// Time elapsed since the start of the current cue
+    time: Duration,
+
+    /// Actions of the current cue already executed
+    executed: Vec<bool>,
+
+    fades: Vec<Fade>,
+}
+
+impl Player {
+    async fn enter(&mut self, index: usize, values: &HashMap<String, AnyInputValue>) {
+        // Complete fades of the previous cue
+        for fade in self.fades.drain(..) {
+            if let Err(err) = fade.input.sink().send_any(fade.target).await {
+                eprintln!("🎬 Failed to fade input '{}': {}", fade.input.name(), err);
+            }
+        }
+
+        let cue = &self.show.cues[index];
+        eprintln!("🎬 Entering cue {}: {}", index, cue.name);
+
+        self.current = Some(index);
+        self.time = Duration::ZERO;
+        self.executed = vec![false; cue.actions.len()];
+
+        self.execute(values).await;
+    }
+
+    async fn advance(&mut self, duration: Duration, values: &HashMap<String, AnyInputValue>) {
+        if self.current.is_none() {
+            return;
+        }
+
+        self.time += duration;
+
+        self.execute(values).await;
+
+        // Follow to the next cues - each cue is passed at most once per tick, so a chain of cues following immediately
+        // does not run in circles
+        for _ in 0..self.show.cues.len() {
+            let Some(current) = self.current else {
+                return;
+            };
+
+            let Some(follow) = self.show.cues[current].follow.filter(|follow| self.time >= *follow) else {
+                return;
+            };
+
+            // Carry the time passed since the follow time over to the next cue to avoid drifting of follow chains
+            let overshoot = self.time - follow;
+
+            self.go(values).await;
+
+            if self.current.is_some() {
+                self.time = overshoot;
+                self.execute(values).await;
+            }
+        }
+    }
+
+    /// Execute the due actions of the current cue and update its running fades.
+    async fn execute(&mut self, values: &HashMap<String, AnyInputValue>) {
+        let Some(current) = self.current else {
+            return;
+        };
+
+        let cue = &self.show.cues[current];
+
+        for (action, executed) in cue.actions.iter().zip(self.executed.iter_mut()) {
+            if *executed || action.delay > self.time {
+                continue;
+            }
+
+            *executed = true;
+
+            if !action.fade.is_zero() {
+                if let Some(source) = values.get(action.input.name()) {
+                    self.fades.push(Fade {
+                        input: action.input.clone(),
+                        source: source.clone(),
+                        target: action.value.clone(),
+                        start: self.time,
+                        duration: action.fade,
+                    });
+                    continue;
+                }
+            }
+
+            if let Err(err) = action.input.sink().send_any(action.value.clone()).await {
+                eprintln!("🎬 Failed to send input '{}': {}", action.input.name(), err);
+            }
+        }
+
+        for fade in self.fades.iter() {
+            let i = f32::min((self.time - fade.start).as_secs_f32() / fade.duration.as_secs_f32(), 1.0);
+
+            if let Err(err) = fade.input.sink().send_any(lerp(&fade.source, &fade.target, i)).await {
+                eprintln!("🎬 Failed to fade input '{}': {}", fade.input.name(), err);
+            }
+        }
+
+        let time = self.time;
+        self.fades.retain(|fade| time < fade.start + fade.duration);
+    }
+
+    async fn go(&mut self, values: &HashMap<String, AnyInputValue>) {
+        let next = match self.current {
+            None => 0,
+            Some(current) if current + 1 < self.show.cues.len() => current + 1,
+            Some(_) if self.show.looping => 0,
+            Some(_) => {
+                eprintln!("🎬 End of show");
+
+                // Next go starts the show over
+                self.current = None;
+                return;
+            }
+        };
+
+        self.enter(next, values).await;
+    }
+
+    async fn back(&mut self, values: &HashMap<String, AnyInputValue>) {
+        let prev = match self.current {
+            None => return,
+            Some(0) if self.show.looping => self.show.cues.len() - 1,
+            Some(0) => 0,
+            Some(current) => current - 1,
+        };
+
+        self.enter(prev, values).await;
+    }
+}
+
+/// A cue list sequencing input values.
+///
+/// The show is loaded from a YAML file containing a list of cues. Each cue sends values to inputs, optionally
+/// delayed and faded, and can automatically advance to the next cue after a given time.
+///
+/// The show is controlled by inputs which are available to all other interfaces. The optional `cue` input reports the
+/// index of the current cue and allows to jump to a cue by setting its index.
+pub struct Cues {
+    pub path: PathBuf,
+
+    go: Option<InputHandle<Trigger>>,
+    back: Option<InputHandle<Trigger>>,
+    pause: Option<InputHandle<Trigger>>,
+    cue: Option<InputHandle<i64>>,
+}
+
+impl Cues {
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        return Self {
+            path: path.into(),
+            go: None,
+            back: None,
+            pause: None,
+            cue: None,
+        };
+    }
+
+    /// Advance to the next cue when the given input is triggered.
+    pub fn with_go(mut self, go: InputHandle<Trigger>) -> Self {
+        self.go = Some(go);
+        return self;
+    }
+
+    /// Return to the previous cue when the given input is triggered.
+    pub fn with_back(mut self, back: InputHandle<Trigger>) -> Self {
+        self.back = Some(back);
+        return self;
+    }
+
+    /// Pause or resume the show when the given input is triggered.
+    pub fn with_pause(mut self, pause: InputHandle<Trigger>) -> Self {
+        self.pause = Some(pause);
+        return self;
+    }
+
+    /// Report the index of the current cue on the given input and jump to any cue whose index is set on it.
+    pub fn with_cue(mut self, cue: InputHandle<i64>) -> Self {
+        self.cue = Some(cue);
+        return self;
+    }
+}
+
+impl Interface for Cues {
+    async fn listen(self, introspection: Arc<Introspection>) -> Result<()> {
+        let show = tokio::fs::read_to_string(&self.path)
+            .await
+            .with_context(|| format!("Failed to read show file: {}", self.path.display()))?;
+        let show: Show =
+            serde_yaml::from_str(&show).with_context(|| format!("Invalid show file: {}", self.path.display()))?;
+        let show = show.resolve(&introspection).with_context(|| format!("Invalid show: {}", self.path.display()))?;
+
+        let mut go = self.go.map(InputHandle::detach);
+        let mut back = self.back.map(InputHandle::detach);
+        let mut pause = self.pause.map(InputHandle::detach);
+        let mut cue = self.cue.map(InputHandle::detach);
+
+        // Track the current values of all inputs to fade from
+        let mut values = HashMap::new();
+        let mut inputs = introspection
+            .inputs
+            .iter()
+            .map(|(name, input)| (name.clone(), input.subscribe()))
+            .collect::<StreamMap<_, _>>();
+
+        let mut player = Player {
+            show,
+            current: None,
+            time: Duration::ZERO,
+            executed: Vec::new(),
+            fades: Vec::new(),
+        };
+
+        let mut paused = false;
+
+        let mut interval = tokio::time::interval(UPDATE_INTERVAL);
+        let mut last = Instant::now();
+
+        loop {
+            let current = player.current;
+
+            tokio::select! {
+                now = interval.tick() => {
+                    let duration = now - last;
+                    last = now;
+
+                    if !paused {
+                        player.advance(duration, &values).await;
+                    }
+                }
+
+                Some((name, value)) = inputs.next() => {
+                    values.insert(name, value);
+                }
+
+                _ = recv(&mut go) => {
+                    player.go(&values).await;
+                }
+
+                _ = recv(&mut back) => {
+                    player.back(&values).await;
+                }
+
+                _ = recv(&mut pause) => {
+                    paused = !paused;
+                    eprintln!("🎬 Show {}", if paused { "paused" } else { "resumed" });
+                }
+
+                index = recv(&mut cue) => {
+                    match usize::try_from(index).ok().filter(|index| *index < player.show.cues.len()) {
+                        Some(index) => player.enter(index, &values).await,
+                        None => eprintln!("🎬 No such cue: {index}"),
+                    }
+                }
+            }
+
+            if player.current != current {
+                if let (Some(cue), Some(current)) = (&cue, player.current) {
+                    cue.publish(current as i64);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::show::ResolvedCue;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn follow_immediately_in_loop() {
+        let cue = |name: &str| ResolvedCue {
+            name: name.to_owned(),
+            actions: Vec::new(),
+            follow: Some(Duration::ZERO),
+        };
+
+        let mut player = Player {
+            show: Resolved {
+                cues: vec![cue("a"), cue("b"), cue("c")],
+                looping: true,
+            },
+            current: None,
+            time: Duration::ZERO,
+            executed: Vec::new(),
+            fades: Vec::new(),
+        };
+
+        let values = HashMap::new();
+
+        player.go(&values).await;
+        assert_eq!(player.current, Some(0));
+
+        // Passes every cue once and stops at the cue it started from
+        player.advance(UPDATE_INTERVAL, &values).await;
+        assert_eq!(player.current, Some(0));
+    }
+
+    #[tokio::test]
+    async fn follow_carries_overshoot() {
+        let cue = |name: &str| ResolvedCue {
+            name: name.to_owned(),
+            actions: Vec::new(),
+            follow: Some(Duration::from_millis(100)),
+        };
+
+        let mut player = Player {
+            show: Resolved {
+                cues: vec![cue("a"), cue("b"), cue("c")],
+                looping: false,
+            },
+            current: None,
+            time: Duration::ZERO,
+            executed: Vec::new(),
+            fades: Vec::new(),
+        };
+
+        let values = HashMap::new();
+
+        player.go(&values).await;
+
+        player.advance(Duration::from_millis(150), &values).await;
+        assert_eq!(player.current, Some(1));
+        assert_eq!(player.time, Duration::from_millis(50));
+
+        player.advance(Duration::from_millis(60), &values).await;
+        assert_eq!(player.current, Some(2));
+        assert_eq!(player.time, Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+
+use photonic::input::{AnyInputValue, InputValueDecl, InputValueType};
+use photonic::interface::Introspection;
+use photonic::InputInfo;
+
+/// A show as declared in a show file.
+#[derive(Deserialize, Debug)]
+pub struct Show {
+    pub cues: Vec<Cue>,
+
+    /// Continue with the first cue after the last one
+    #[serde(default, rename = "loop")]
+    pub looping: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Cue {
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[serde(default)]
+    pub actions: Vec<Action>,
+
+    /// Automatically advance to the next cue after this duration
+    #[serde(default, with = "humantime_serde")]
+    pub follow: Option<Duration>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Action {
+    pub input: String,
+
+    /// The value to send - can be omitted for trigger inputs
+    #[serde(default)]
+    pub value: Option<InputValueDecl>,
+
+    /// Time after the start of the cue to execute the action
+    #[serde(default, with = "humantime_serde")]
+    pub delay: Duration,
+
+    /// Time to fade from the current value to the new one - only for decimal, color and gradient inputs
+    #[serde(default, with = "humantime_serde")]
+    pub fade: Duration,
+}
+
+/// A show with all actions resolved against the inputs of a scene.
+pub struct Resolved {
+    pub cues: Vec<ResolvedCue>,
+    pub looping: bool,
+}
+
+pub struct ResolvedCue {
+    pub name: String,
+    pub actions: Vec<ResolvedAction>,
+    pub follow: Option<Duration>,
+}
+
+pub struct ResolvedAction {
+    pub input: Arc<InputInfo>,
+    pub value: AnyInputValue,
+    pub delay: Duration,
+    pub fade: Duration,
+}
+
+impl Show {
+    pub fn resolve(self, introspection: &Introspection) -> Result<Resolved> {
+        if self.cues.is_empty() {
+            bail!("Show contains no cues");
+        }
+
+        if self.looping && self.cues.iter().all(|cue| cue.follow.is_some_and(|follow| follow.is_zero())) {
+            bail!("Looping show without any pause: all cues follow immediately");
+        }
+
+        let cues = self
+            .cues
+            .into_iter()
+            .enumerate()
+            .map(|(i, cue)| {
+                let name = cue.name.unwrap_or_else(|| format!("#{i}"));
+
+                let actions = cue
+                    .actions
+                    .into_iter()
+                    .map(|action| {
+                        let input = introspection
+                            .inputs
+                            .get(&action.input)
+                            .ok_or_else(|| anyhow!("No such input: {}", action.input))?;
+
+                        let value = match action.value {
+                            Some(value) => value.resolve(input.value_type())?,
+                            None if input.value_type() == InputValueType::Trigger => AnyInputValue::Trigger,
+                            None => bail!("Value missing for input: {}", action.input),
+                        };
+
+                        if !action.fade.is_zero()
+                            && !matches!(
+                                input.value_type(),
+                                InputValueType::Decimal | InputValueType::Color | InputValueType::Gradient
+                            )
+                        {
+                            bail!("Fading not supported for input: {} ({})", action.input, input.value_type());
+                        }
+
+                        return Ok(ResolvedAction {
+                            input: input.clone(),
+                            value,
+                            delay: action.delay,
+                            fade: action.fade,
+                        });
+                    })
+                    .collect::<Result<Vec<_>>>()
+                    .with_context(|| format!("Invalid cue: {name}"))?;
+
+                return Ok(ResolvedCue {
+                    name,
+                    actions,
+                    follow: cue.follow,
+                });
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        return Ok(Resolved {
+            cues,
+            looping: self.looping,
+        });
+    }
+}
@@ -10,6 +10,7 @@ use crate::Context;
 pub mod attr;
 pub mod input;
 pub mod node;
+pub mod preset;
 
 pub trait CliCommand: CommandFactory + FromArgMatches {
     fn execute(args: Self, context: &mut Context, f: &mut dyn fmt::Write) -> impl Future<Output = Result<()>>;
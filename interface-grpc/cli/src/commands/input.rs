@@ -4,7 +4,7 @@ use anyhow::Result;
 use clap::Parser;
 
 use photonic_interface_grpc_client::input::InputSink;
-use photonic_interface_grpc_client::values::{ColorValue, RangeValue};
+use photonic_interface_grpc_client::values::{ColorValue, GradientValue, RangeValue};
 use photonic_interface_grpc_client::InputId;
 
 use crate::commands::CliCommand;
@@ -78,9 +78,11 @@ impl CliCommand for InputSet {
             InputSink::Integer(sink) => sink.send(args.value.parse()?).await?,
             InputSink::Decimal(sink) => sink.send(args.value.parse()?).await?,
             InputSink::Color(sink) => sink.send(args.value.parse::<ColorValue>()?).await?,
+            InputSink::Duration(sink) => sink.send(humantime::parse_duration(&args.value)?).await?,
             InputSink::IntegerRange(sink) => sink.send(args.value.parse::<RangeValue<i64>>()?).await?,
             InputSink::DecimalRange(sink) => sink.send(args.value.parse::<RangeValue<f32>>()?).await?,
             InputSink::ColorRange(sink) => sink.send(args.value.parse::<RangeValue<ColorValue>>()?).await?,
+            InputSink::Gradient(sink) => sink.send(args.value.parse::<GradientValue>()?).await?,
         };
 
         return Ok(().render(f)?);
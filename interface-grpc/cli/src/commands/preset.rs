@@ -0,0 +1,91 @@
+use std::fmt;
+
+use anyhow::Result;
+use clap::Parser;
+
+use photonic_interface_grpc_client::PresetId;
+
+use crate::commands::CliCommand;
+use crate::output::{ListOutput, Output};
+use crate::Context;
+
+#[derive(Parser, Debug)]
+#[command(name = "preset")]
+pub enum Preset {
+    List(PresetList),
+    Save(PresetSave),
+    Recall(PresetRecall),
+    Delete(PresetDelete),
+}
+
+impl CliCommand for Preset {
+    async fn execute(args: Self, context: &mut Context, f: &mut dyn fmt::Write) -> Result<()> {
+        return match args {
+            Self::List(args) => PresetList::execute(args, context, f).await,
+            Self::Save(args) => PresetSave::execute(args, context, f).await,
+            Self::Recall(args) => PresetRecall::execute(args, context, f).await,
+            Self::Delete(args) => PresetDelete::execute(args, context, f).await,
+        };
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(name = "list", about = "List all stored presets")]
+pub struct PresetList {}
+
+impl CliCommand for PresetList {
+    async fn execute(_args: Self, context: &mut Context, f: &mut dyn fmt::Write) -> Result<()> {
+        let presets = context.client.presets().await?;
+
+        let output = ListOutput::from(presets.into_iter());
+        return Ok(output.render(f)?);
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(name = "save", about = "Store the current input values as preset")]
+pub struct PresetSave {
+    #[arg(required = true)]
+    preset: PresetId,
+}
+
+impl CliCommand for PresetSave {
+    async fn execute(args: Self, context: &mut Context, f: &mut dyn fmt::Write) -> Result<()> {
+        context.client.preset_save(&args.preset).await?;
+
+        return Ok(().render(f)?);
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(name = "recall", about = "Recall the input values stored in a preset")]
+pub struct PresetRecall {
+    #[arg(required = true)]
+    preset: PresetId,
+
+    #[arg(long, help = "Duration to cross-fade decimal and color inputs", value_parser = humantime::parse_duration, default_value = "0s")]
+    fade: std::time::Duration,
+}
+
+impl CliCommand for PresetRecall {
+    async fn execute(args: Self, context: &mut Context, f: &mut dyn fmt::Write) -> Result<()> {
+        context.client.preset_recall(&args.preset, args.fade).await?;
+
+        return Ok(().render(f)?);
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(name = "delete", about = "Delete a stored preset")]
+pub struct PresetDelete {
+    #[arg(required = true)]
+    preset: PresetId,
+}
+
+impl CliCommand for PresetDelete {
+    async fn execute(args: Self, context: &mut Context, f: &mut dyn fmt::Write) -> Result<()> {
+        context.client.preset_delete(&args.preset).await?;
+
+        return Ok(().render(f)?);
+    }
+}
@@ -36,7 +36,8 @@ async fn main() -> Result<()> {
         .with_description(crate_description!())
         .with_command_async(commands::node::Node::command(), commands::node::Node::callback)
         .with_command_async(commands::attr::Attr::command(), commands::attr::Attr::callback)
-        .with_command_async(commands::input::Input::command(), commands::input::Input::callback);
+        .with_command_async(commands::input::Input::command(), commands::input::Input::callback)
+        .with_command_async(commands::preset::Preset::command(), commands::preset::Preset::callback);
 
     return Ok(repl.run_async().await?);
 }
@@ -6,7 +6,7 @@ use yansi::{Paint, Style};
 use photonic_interface_grpc_client::attr::Attr;
 use photonic_interface_grpc_client::input::Input;
 use photonic_interface_grpc_client::node::{Node, NodeId};
-use photonic_interface_grpc_client::{values, AttrId, InputId};
+use photonic_interface_grpc_client::{values, AttrId, InputId, PresetId};
 
 pub trait Output {
     fn render(&self, f: &mut dyn fmt::Write) -> fmt::Result;
@@ -27,6 +27,7 @@ impl<O: Output + ?Sized> fmt::Display for Render<'_, O> {
 const NODE_STYLE: Style = Style::new().bright_yellow();
 const ATTR_STYLE: Style = Style::new().bright_cyan();
 const INPUT_STYLE: Style = Style::new().bright_magenta();
+const PRESET_STYLE: Style = Style::new().bright_green();
 
 const TYPE_STYLE: Style = Style::new().bright_blue();
 
@@ -50,6 +51,12 @@ impl Output for InputId {
     }
 }
 
+impl Output for PresetId {
+    fn render(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        return write!(f, "{}", self.as_ref().paint(PRESET_STYLE));
+    }
+}
+
 impl Output for values::ValueType {
     fn render(&self, f: &mut dyn fmt::Write) -> fmt::Result {
         let value = match self {
@@ -58,9 +65,11 @@ impl Output for values::ValueType {
             values::ValueType::Integer => "integer",
             values::ValueType::Decimal => "decimal",
             values::ValueType::Color => "color",
+            values::ValueType::Duration => "duration",
             values::ValueType::IntegerRange => "range<integer>",
             values::ValueType::DecimalRange => "range<decimal>",
             values::ValueType::ColorRange => "range<color>",
+            values::ValueType::Gradient => "gradient",
         };
 
         return write!(f, "{}", value.paint(TYPE_STYLE));
@@ -146,3 +155,11 @@ impl<I: Iterator<Item = InputId>> From<I> for ListOutput<InputId> {
         };
     }
 }
+
+impl<I: Iterator<Item = PresetId>> From<I> for ListOutput<PresetId> {
+    fn from(iter: I) -> Self {
+        return Self {
+            elements: iter.collect(),
+        };
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt;
 use std::marker::PhantomData;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use parking_lot::Mutex;
@@ -11,7 +12,7 @@ use tonic::transport::Channel;
 use photonic_interface_grpc_proto::interface_client::InterfaceClient;
 use photonic_interface_grpc_proto::{input_value, InputInfoResponse, InputSendRequest, InputValue, InputValueType};
 
-use crate::values::{ColorValue, RangeValue, ValueType};
+use crate::values::{ColorValue, GradientValue, RangeValue, ValueType};
 
 #[derive(Eq, PartialEq, Clone, Hash)]
 pub struct InputId(pub(crate) String);
@@ -58,9 +59,11 @@ impl Input {
             InputValueType::Integer => ValueType::Integer,
             InputValueType::Decimal => ValueType::Decimal,
             InputValueType::Color => ValueType::Color,
+            InputValueType::Duration => ValueType::Duration,
             InputValueType::IntegerRange => ValueType::IntegerRange,
             InputValueType::DecimalRange => ValueType::DecimalRange,
             InputValueType::ColorRange => ValueType::ColorRange,
+            InputValueType::Gradient => ValueType::Gradient,
         };
 
         Self {
@@ -100,6 +103,10 @@ impl Input {
                 input: self,
                 value_type: PhantomData,
             }),
+            ValueType::Duration => InputSink::Duration(Sink {
+                input: self,
+                value_type: PhantomData,
+            }),
             ValueType::IntegerRange => InputSink::IntegerRange(Sink {
                 input: self,
                 value_type: PhantomData,
@@ -112,6 +119,10 @@ impl Input {
                 input: self,
                 value_type: PhantomData,
             }),
+            ValueType::Gradient => InputSink::Gradient(Sink {
+                input: self,
+                value_type: PhantomData,
+            }),
         };
     }
 }
@@ -122,9 +133,11 @@ pub enum InputSink<'i> {
     Integer(Sink<'i, i64>),
     Decimal(Sink<'i, f32>),
     Color(Sink<'i, ColorValue>),
+    Duration(Sink<'i, Duration>),
     IntegerRange(Sink<'i, RangeValue<i64>>),
     DecimalRange(Sink<'i, RangeValue<f32>>),
     ColorRange(Sink<'i, RangeValue<ColorValue>>),
+    Gradient(Sink<'i, GradientValue>),
 }
 
 pub struct Sink<'i, V> {
@@ -212,6 +225,22 @@ impl Sink<'_, ColorValue> {
     }
 }
 
+impl Sink<'_, Duration> {
+    pub async fn send(&self, value: Duration) -> Result<()> {
+        let mut client = self.input.client.lock_arc();
+
+        client
+            .input_send(InputSendRequest {
+                name: self.input.name.0.clone(),
+                value: Some(InputValue {
+                    value: Some(input_value::Value::Duration(value.try_into()?)),
+                }),
+            })
+            .await?;
+        return Ok(());
+    }
+}
+
 impl Sink<'_, RangeValue<i64>> {
     pub async fn send(&self, value: RangeValue<i64>) -> Result<()> {
         let mut client = self.input.client.lock_arc();
@@ -259,3 +288,19 @@ impl Sink<'_, RangeValue<ColorValue>> {
         return Ok(());
     }
 }
+
+impl Sink<'_, GradientValue> {
+    pub async fn send(&self, value: GradientValue) -> Result<()> {
+        let mut client = self.input.client.lock_arc();
+
+        client
+            .input_send(InputSendRequest {
+                name: self.input.name.0.clone(),
+                value: Some(InputValue {
+                    value: Some(input_value::Value::Gradient(value.into())),
+                }),
+            })
+            .await?;
+        return Ok(());
+    }
+}
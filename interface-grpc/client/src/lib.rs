@@ -1,20 +1,25 @@
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use parking_lot::Mutex;
 use tonic::transport::{Channel, Endpoint, Uri};
 
 use photonic_interface_grpc_proto::interface_client::InterfaceClient;
-use photonic_interface_grpc_proto::{AttrInfoRequest, AttrName, InputInfoRequest, NodeInfoRequest};
+use photonic_interface_grpc_proto::{
+    AttrInfoRequest, AttrName, InputInfoRequest, NodeInfoRequest, PresetRecallRequest, PresetRequest,
+};
 
 pub use crate::attr::{Attr, AttrId};
 pub use crate::input::{Input, InputId};
 pub use crate::node::{Node, NodeId};
+pub use crate::preset::PresetId;
 
 pub mod attr;
 pub mod input;
 pub mod node;
+pub mod preset;
 pub mod values;
 
 #[cfg(feature = "python")]
@@ -101,4 +106,49 @@ impl Client {
 
         return Ok(Input::from_input_info(self.client.clone(), info));
     }
+
+    pub async fn presets(&self) -> Result<Vec<PresetId>> {
+        let mut client = self.client.lock_arc();
+
+        let presets = client.presets(()).await?.into_inner().presets.into_iter().map(PresetId).collect();
+
+        return Ok(presets);
+    }
+
+    pub async fn preset_save(&self, name: &PresetId) -> Result<()> {
+        let mut client = self.client.lock_arc();
+
+        client
+            .preset_save(PresetRequest {
+                name: name.0.clone(),
+            })
+            .await?;
+
+        return Ok(());
+    }
+
+    pub async fn preset_recall(&self, name: &PresetId, fade: Duration) -> Result<()> {
+        let mut client = self.client.lock_arc();
+
+        client
+            .preset_recall(PresetRecallRequest {
+                name: name.0.clone(),
+                fade: Some(fade.try_into()?),
+            })
+            .await?;
+
+        return Ok(());
+    }
+
+    pub async fn preset_delete(&self, name: &PresetId) -> Result<()> {
+        let mut client = self.client.lock_arc();
+
+        client
+            .preset_delete(PresetRequest {
+                name: name.0.clone(),
+            })
+            .await?;
+
+        return Ok(());
+    }
 }
@@ -0,0 +1,32 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Eq, PartialEq, Clone, Hash)]
+pub struct PresetId(pub(crate) String);
+
+impl fmt::Display for PresetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Debug for PresetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for PresetId {
+    fn as_ref(&self) -> &str {
+        return &self.0;
+    }
+}
+
+impl FromStr for PresetId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return Ok(Self(s.to_owned()));
+    }
+}
@@ -5,7 +5,7 @@ use anyhow::Result;
 use pyo3::prelude::*;
 
 use crate::input::InputSink;
-use crate::values::{ColorValue, RangeValue};
+use crate::values::{ColorValue, GradientValue, RangeValue};
 use crate::{Attr, AttrId, Client, Input, InputId, Node, NodeId};
 
 #[pymodule]
@@ -186,9 +186,11 @@ impl PyInput {
             InputSink::Integer(sink) => sink.send(extract(value)?).await?,
             InputSink::Decimal(sink) => sink.send(extract(value)?).await?,
             InputSink::Color(sink) => sink.send(extract(value)?).await?,
+            InputSink::Duration(sink) => sink.send(extract(value)?).await?,
             InputSink::IntegerRange(sink) => sink.send(extract(value)?).await?,
             InputSink::DecimalRange(sink) => sink.send(extract(value)?).await?,
             InputSink::ColorRange(sink) => sink.send(extract(value)?).await?,
+            InputSink::Gradient(sink) => sink.send(extract(value)?).await?,
         });
     }
 
@@ -331,6 +333,15 @@ impl<'a, 'py> FromPyObject<'a, 'py> for ColorValue {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for GradientValue {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> std::result::Result<Self, Self::Error> {
+        let ob: String = obj.extract()?;
+        return Ok(GradientValue::from_str(&ob)?);
+    }
+}
+
 impl<'a, 'py, T: FromPyObject<'a, 'py>> FromPyObject<'a, 'py> for RangeValue<T> {
     type Error = PyErr;
 
@@ -1,9 +1,10 @@
 use std::fmt;
 use std::str::FromStr;
 
+use anyhow::{bail, ensure};
 use palette::Srgb;
 
-use photonic_interface_grpc_proto::input_value::{ColorRange, DecimalRange, IntegerRange, Rgb};
+use photonic_interface_grpc_proto::input_value::{gradient, ColorRange, DecimalRange, Gradient, IntegerRange, Rgb};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum ValueType {
@@ -12,9 +13,11 @@ pub enum ValueType {
     Integer,
     Decimal,
     Color,
+    Duration,
     IntegerRange,
     DecimalRange,
     ColorRange,
+    Gradient,
 }
 
 impl fmt::Display for ValueType {
@@ -25,9 +28,11 @@ impl fmt::Display for ValueType {
             Self::Integer => "integer",
             Self::Decimal => "decimal",
             Self::Color => "color",
+            Self::Duration => "duration",
             Self::IntegerRange => "range<integer>",
             Self::DecimalRange => "range<decimal>",
             Self::ColorRange => "range<color>",
+            Self::Gradient => "gradient",
         });
     }
 }
@@ -118,3 +123,95 @@ impl From<RangeValue<ColorValue>> for ColorRange {
         };
     }
 }
+
+pub struct GradientValue {
+    pub stops: Vec<(f32, ColorValue)>,
+    pub space: gradient::Space,
+}
+
+/// Parses a gradient from a comma separated list of stops like `oklab:#ff0000,#00ff00@0.25,#0000ff`.
+///
+/// Stops without a position are distributed evenly between their neighbours.
+impl FromStr for GradientValue {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (space, s) = match s.split_once(':') {
+            Some((space, s)) => (
+                match space.trim() {
+                    "srgb" => gradient::Space::Srgb,
+                    "linear" => gradient::Space::Linear,
+                    "hsv" => gradient::Space::Hsv,
+                    "oklab" => gradient::Space::Oklab,
+                    "oklch" => gradient::Space::Oklch,
+                    space => bail!("Unknown gradient space: {space}"),
+                },
+                s,
+            ),
+            None => (gradient::Space::Srgb, s),
+        };
+
+        let mut stops = s
+            .split(',')
+            .map(|stop| {
+                return Ok(match stop.split_once('@') {
+                    Some((color, position)) => (Some(position.trim().parse::<f32>()?), color.trim().parse()?),
+                    None => (None, stop.trim().parse()?),
+                });
+            })
+            .collect::<anyhow::Result<Vec<(Option<f32>, ColorValue)>>>()?;
+
+        if let Some((first, _)) = stops.first_mut() {
+            first.get_or_insert(0.0);
+        }
+
+        if let Some((last, _)) = stops.last_mut() {
+            last.get_or_insert(1.0);
+        }
+
+        // Distribute missing positions between the known ones
+        let mut start = 0;
+        for end in 1..stops.len() {
+            let Some(to) = stops[end].0 else {
+                continue;
+            };
+
+            let from = stops[start].0.expect("Known position");
+            for (i, (position, _)) in stops[start + 1..end].iter_mut().enumerate() {
+                *position = Some(from + (to - from) * (i + 1) as f32 / (end - start) as f32);
+            }
+
+            start = end;
+        }
+
+        let stops = stops
+            .into_iter()
+            .map(|(position, color)| (position.expect("Distributed position"), color))
+            .collect::<Vec<_>>();
+
+        ensure!(!stops.is_empty(), "Gradient must have at least one stop");
+
+        return Ok(Self {
+            stops,
+            space,
+        });
+    }
+}
+
+impl From<GradientValue> for Gradient {
+    fn from(val: GradientValue) -> Self {
+        let mut gradient = Gradient {
+            stops: val
+                .stops
+                .into_iter()
+                .map(|(position, color)| gradient::Stop {
+                    position,
+                    color: Some(color.into()),
+                })
+                .collect(),
+            space: 0,
+        };
+        gradient.set_space(val.space);
+        return gradient;
+    }
+}
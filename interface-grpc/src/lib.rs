@@ -2,6 +2,7 @@
 
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use futures::StreamExt;
@@ -9,22 +10,32 @@ use tonic::codegen::tokio_stream::Stream;
 use tonic::transport::Server;
 use tonic::{async_trait, Request, Response, Status};
 
-use photonic::attr::Range;
+use photonic::attr::{Gradient, GradientSpace, Range, Stop};
 use photonic::input::{InputSink, InputValueType, Trigger};
 use photonic::interface::{Interface, Introspection};
-use photonic_interface_grpc_proto::input_value::{ColorRange, DecimalRange, IntegerRange, Rgb};
+use photonic_interface_grpc_proto::input_value::{gradient, ColorRange, DecimalRange, IntegerRange, Rgb};
 use photonic_interface_grpc_proto::interface_server::InterfaceServer;
 use photonic_interface_grpc_proto::{
     input_value, interface_server, AttrInfoRequest, AttrInfoResponse, InputInfoRequest, InputInfoResponse,
     InputSendRequest, InputSubscribeRequest, InputValue, InputsResponse, NodeInfoRequest, NodeInfoResponse,
-    NodesResponse,
+    NodesResponse, PresetRecallRequest, PresetRequest, PresetsResponse,
 };
+use photonic_interface_presets::Presets;
 
-pub struct GRPC {}
+pub struct GRPC {
+    presets: Option<Presets>,
+}
 
 impl GRPC {
     pub fn new() -> Result<Self> {
-        return Ok(Self {});
+        return Ok(Self {
+            presets: None,
+        });
+    }
+
+    pub fn with_presets(mut self, presets: Presets) -> Self {
+        self.presets = Some(presets);
+        return self;
     }
 }
 
@@ -35,6 +46,7 @@ impl Interface for GRPC {
         Server::builder()
             .add_service(InterfaceServer::new(InterfaceImpl {
                 introspection,
+                presets: self.presets,
             }))
             .serve(addr)
             .await?;
@@ -45,6 +57,13 @@ impl Interface for GRPC {
 
 struct InterfaceImpl {
     introspection: Arc<Introspection>,
+    presets: Option<Presets>,
+}
+
+impl InterfaceImpl {
+    fn presets(&self) -> Result<&Presets, Status> {
+        return self.presets.as_ref().ok_or_else(|| Status::unimplemented("Presets not available"));
+    }
 }
 
 #[async_trait]
@@ -135,9 +154,11 @@ impl interface_server::Interface for InterfaceImpl {
                 InputValueType::Integer => photonic_interface_grpc_proto::InputValueType::Integer,
                 InputValueType::Decimal => photonic_interface_grpc_proto::InputValueType::Decimal,
                 InputValueType::Color => photonic_interface_grpc_proto::InputValueType::Color,
+                InputValueType::Duration => photonic_interface_grpc_proto::InputValueType::Duration,
                 InputValueType::IntegerRange => photonic_interface_grpc_proto::InputValueType::IntegerRange,
                 InputValueType::DecimalRange => photonic_interface_grpc_proto::InputValueType::DecimalRange,
                 InputValueType::ColorRange => photonic_interface_grpc_proto::InputValueType::ColorRange,
+                InputValueType::Gradient => photonic_interface_grpc_proto::InputValueType::Gradient,
             }
             .into(),
         }));
@@ -195,6 +216,13 @@ impl interface_server::Interface for InterfaceImpl {
                 sink.send(value).await
             }
 
+            InputSink::Duration(sink) => {
+                let value = match_value!(Duration);
+                let value =
+                    (*value).try_into().map_err(|err| Status::invalid_argument(format!("Invalid value: {err}")))?;
+                sink.send(value).await
+            }
+
             InputSink::IntegerRange(sink) => {
                 let value = match_value!(IntegerRange);
                 let value = Range::new(value.a, value.b);
@@ -217,6 +245,32 @@ impl interface_server::Interface for InterfaceImpl {
                 );
                 sink.send(value).await
             }
+
+            InputSink::Gradient(sink) => {
+                let value = match_value!(Gradient);
+                let stops = value
+                    .stops
+                    .iter()
+                    .map(|stop| {
+                        let color = stop.color.as_ref().ok_or(Status::invalid_argument("Value missing"))?;
+                        Ok(Stop {
+                            position: stop.position,
+                            color: palette::Srgb::new(color.r, color.g, color.b),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Status>>()?;
+                let space = match value.space() {
+                    gradient::Space::Srgb => GradientSpace::Srgb,
+                    gradient::Space::Linear => GradientSpace::Linear,
+                    gradient::Space::Hsv => GradientSpace::Hsv,
+                    gradient::Space::Oklab => GradientSpace::Oklab,
+                    gradient::Space::Oklch => GradientSpace::Oklch,
+                };
+                let value = Gradient::new(stops)
+                    .map_err(|err| Status::invalid_argument(format!("Invalid value: {err}")))?
+                    .with_space(space);
+                sink.send(value).await
+            }
         }
         .map_err(|err| Status::invalid_argument(format!("Invalid value: {err}")))?;
 
@@ -270,6 +324,14 @@ impl interface_server::Interface for InterfaceImpl {
                 })
             })),
 
+            InputSink::Duration(sink) => Box::pin(sink.subscribe().map(|value| {
+                Ok(InputValue {
+                    value: Some(input_value::Value::Duration(
+                        value.try_into().map_err(|err| Status::out_of_range(format!("Invalid value: {err}")))?,
+                    )),
+                })
+            })),
+
             InputSink::IntegerRange(sink) => Box::pin(sink.subscribe().map(|value| {
                 Ok(InputValue {
                     value: Some(input_value::Value::IntegerRange(IntegerRange {
@@ -304,8 +366,74 @@ impl interface_server::Interface for InterfaceImpl {
                     })),
                 })
             })),
+
+            InputSink::Gradient(sink) => Box::pin(sink.subscribe().map(|value| {
+                let mut gradient = input_value::Gradient {
+                    stops: value
+                        .stops()
+                        .iter()
+                        .map(|stop| gradient::Stop {
+                            position: stop.position,
+                            color: Some(Rgb {
+                                r: stop.color.red,
+                                g: stop.color.green,
+                                b: stop.color.blue,
+                            }),
+                        })
+                        .collect(),
+                    space: 0,
+                };
+                gradient.set_space(match value.space() {
+                    GradientSpace::Srgb => gradient::Space::Srgb,
+                    GradientSpace::Linear => gradient::Space::Linear,
+                    GradientSpace::Hsv => gradient::Space::Hsv,
+                    GradientSpace::Oklab => gradient::Space::Oklab,
+                    GradientSpace::Oklch => gradient::Space::Oklch,
+                });
+
+                Ok(InputValue {
+                    value: Some(input_value::Value::Gradient(gradient)),
+                })
+            })),
         };
 
         return Ok(Response::new(stream));
     }
+
+    async fn presets(&self, _request: Request<()>) -> Result<Response<PresetsResponse>, Status> {
+        let presets = self.presets()?.list().await.map_err(|err| Status::internal(format!("{err:#}")))?;
+
+        return Ok(Response::new(PresetsResponse {
+            presets,
+        }));
+    }
+
+    async fn preset_save(&self, request: Request<PresetRequest>) -> Result<Response<()>, Status> {
+        let request = request.get_ref();
+
+        self.presets()?.save(&request.name).await.map_err(|err| Status::internal(format!("{err:#}")))?;
+
+        return Ok(Response::new(()));
+    }
+
+    async fn preset_recall(&self, request: Request<PresetRecallRequest>) -> Result<Response<()>, Status> {
+        let request = request.get_ref();
+
+        let fade = match request.fade {
+            Some(fade) => fade.try_into().map_err(|err| Status::invalid_argument(format!("Invalid fade: {err}")))?,
+            None => Duration::ZERO,
+        };
+
+        self.presets()?.recall(&request.name, fade).await.map_err(|err| Status::not_found(format!("{err:#}")))?;
+
+        return Ok(Response::new(()));
+    }
+
+    async fn preset_delete(&self, request: Request<PresetRequest>) -> Result<Response<()>, Status> {
+        let request = request.get_ref();
+
+        self.presets()?.delete(&request.name).await.map_err(|err| Status::not_found(format!("{err:#}")))?;
+
+        return Ok(Response::new(()));
+    }
 }
@@ -1,16 +1,15 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use bytes::Bytes;
 use futures::StreamExt;
-use palette::rgb::Rgb;
-use photonic::attr::Range;
-use photonic::input::{AnyInputValue, InputSink, Trigger};
+use photonic::input::AnyInputValue;
 use rumqttc::{AsyncClient, Event, Incoming, LastWill, MqttOptions, QoS};
 use tokio_stream::StreamMap;
 
 use photonic::interface::{Interface, Introspection};
+use photonic_interface_presets::Presets;
 
 struct Realm<'a>(&'a str);
 
@@ -28,6 +27,8 @@ pub struct MQTT {
     pub mqtt_options: MqttOptions,
 
     pub realm: String,
+
+    pub presets: Option<Presets>,
 }
 
 impl MQTT {
@@ -39,6 +40,7 @@ impl MQTT {
         return Ok(Self {
             mqtt_options,
             realm: "photonic".into(), // TODO: Extract realm from URL
+            presets: None,
         });
     }
 
@@ -46,6 +48,44 @@ impl MQTT {
         self.realm = realm.into();
         return self;
     }
+
+    pub fn with_presets(mut self, presets: Presets) -> Self {
+        self.presets = Some(presets);
+        return self;
+    }
+}
+
+/// Handle a command sent to a `preset/<name>/<command>` topic.
+async fn preset(presets: &Presets, name: &str, command: &str, payload: &str) -> Result<()> {
+    return match command {
+        "save" => presets.save(name).await,
+        "delete" => presets.delete(name).await,
+        "recall" => {
+            let fade = match payload.trim() {
+                "" => Duration::ZERO,
+                fade => humantime::parse_duration(fade)?,
+            };
+            presets.recall(name, fade).await
+        }
+        command => Err(anyhow!("Unknown preset command: {command}")),
+    };
+}
+
+/// Publish the list of available presets.
+///
+/// Failing to list the presets is reported but keeps the interface running.
+async fn publish_presets(client: &AsyncClient, realm: &Realm<'_>, presets: &Presets) -> Result<()> {
+    let list = match presets.list().await {
+        Ok(list) => list.join("\n"),
+        Err(err) => {
+            eprintln!("⇄ Failed to list presets: {err:#}");
+            return Ok(());
+        }
+    };
+
+    client.publish(realm.topic("presets"), QoS::AtLeastOnce, true, list).await?;
+
+    return Ok(());
 }
 
 impl Interface for MQTT {
@@ -78,9 +118,11 @@ impl Interface for MQTT {
                         AnyInputValue::Integer(value) => value.to_string(),
                         AnyInputValue::Decimal(value) => value.to_string(),
                         AnyInputValue::Color(value) => format!("#{:06x}", value.into_format::<u8>()),
+                        AnyInputValue::Duration(value) => humantime::format_duration(value).to_string(),
                         AnyInputValue::IntegerRange(value) => value.to_string(),
                         AnyInputValue::DecimalRange(value) => value.to_string(),
                         AnyInputValue::ColorRange(value) => value.map(|value| format!("#{:06x}", value.into_format::<u8>())).to_string(),
+                        AnyInputValue::Gradient(value) => value.to_string(),
                     };
                     client.publish(topic, QoS::AtLeastOnce, false, value).await?;
                 }
@@ -90,11 +132,40 @@ impl Interface for MQTT {
                         // Subscribe to all input topics
                         client.subscribe(realm.topic("input/+/set"), QoS::AtLeastOnce).await?;
 
+                        // Subscribe to preset commands and report available presets
+                        if let Some(presets) = &self.presets {
+                            client.subscribe(realm.topic("preset/+/+"), QoS::AtLeastOnce).await?;
+
+                            publish_presets(&client, &realm, presets).await?;
+                        }
+
                         // Report online status
                         client.publish_bytes(realm.topic("status"), QoS::AtLeastOnce, true, Bytes::from("online")).await?;
                     }
 
                     Ok(Event::Incoming(Incoming::Publish(publish))) => {
+                        if let (Some(presets), Some(command)) = (&self.presets, publish.topic.strip_prefix(&realm.topic("preset/"))) {
+                            let Some((name, command)) = command.split_once('/') else {
+                                eprintln!("Got notification for unknown topic: {}", publish.topic);
+                                continue;
+                            };
+
+                            let payload = String::from_utf8_lossy(&publish.payload);
+
+                            match preset(presets, name, command, &payload).await {
+                                Ok(()) if command == "recall" => {}
+                                Ok(()) => {
+                                    // Report changed list of presets
+                                    publish_presets(&client, &realm, presets).await?;
+                                }
+                                Err(err) => {
+                                    eprintln!("⇄ Preset command failed on '{}': {:#}", publish.topic, err);
+                                }
+                            }
+
+                            continue;
+                        }
+
                         let input = introspection.inputs.iter()
                             .find_map(|(name, input)| (realm.topic(format!("input/{name}/set")) == publish.topic).then_some(input));
 
@@ -114,16 +185,10 @@ impl Interface for MQTT {
                             }
                         };
 
-                        let res: Result<()> = (async { match input.sink() {
-                            InputSink::Trigger(sink) => sink.send(Trigger::next()).await,
-                            InputSink::Boolean(sink) => sink.send(payload.parse()?).await,
-                            InputSink::Integer(sink) => sink.send(payload.parse()?).await,
-                            InputSink::Decimal(sink) => sink.send(payload.parse()?).await,
-                            InputSink::Color(sink) => sink.send(payload.parse::<Rgb<_, u8>>()?.into_format()).await,
-                            InputSink::IntegerRange(sink) => sink.send(payload.parse()?).await,
-                            InputSink::DecimalRange(sink) => sink.send(payload.parse()?).await,
-                            InputSink::ColorRange(sink) => sink.send(payload.parse::<Range<Rgb<_, u8>>>()?.map(Rgb::into_format)).await,
-                        }}).await;
+                        let res = match AnyInputValue::parse(input.value_type(), &payload) {
+                            Ok(value) => input.sink().send_any(value).await,
+                            Err(err) => Err(err),
+                        };
 
                         match res {
                             Ok(()) => {}
@@ -0,0 +1,356 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use futures::future;
+use futures::stream::{self, StreamExt};
+use tokio::time::Instant;
+
+use photonic::input::{AnyInputValue, Input, Trigger};
+use photonic::interface::{Interface, Introspection};
+use photonic::math::Lerp;
+use photonic::scene::InputHandle;
+
+/// Interval between two updates sent to the inputs while cross-fading
+const FADE_INTERVAL: Duration = Duration::from_millis(20);
+
+fn fadeable(a: &AnyInputValue, b: &AnyInputValue) -> bool {
+    return matches!(
+        (a, b),
+        (AnyInputValue::Decimal(_), AnyInputValue::Decimal(_))
+            | (AnyInputValue::Color(_), AnyInputValue::Color(_))
+            | (AnyInputValue::Gradient(_), AnyInputValue::Gradient(_))
+    );
+}
+
+/// Interpolates between two values, if the value type supports cross-fading.
+fn lerp(a: &AnyInputValue, b: &AnyInputValue, i: f32) -> Option<AnyInputValue> {
+    return match (a, b) {
+        (AnyInputValue::Decimal(a), AnyInputValue::Decimal(b)) => Some(AnyInputValue::Decimal(Lerp::lerp(*a, *b, i))),
+        (AnyInputValue::Color(a), AnyInputValue::Color(b)) => Some(AnyInputValue::Color(Lerp::lerp(*a, *b, i))),
+        (AnyInputValue::Gradient(a), AnyInputValue::Gradient(b)) => Some(Lerp::lerp(**a, **b, i).into()),
+        (_, _) => None,
+    };
+}
+
+type Preset = HashMap<String, AnyInputValue>;
+
+struct Shared {
+    path: PathBuf,
+
+    /// Counter used to cancel a running cross-fade if another preset gets recalled
+    generation: AtomicUsize,
+
+    introspection: OnceLock<Arc<Introspection>>,
+
+    triggers: Mutex<Vec<(Input<Trigger>, String, Duration)>>,
+}
+
+/// Named snapshots of all input values.
+///
+/// A preset captures the values of all non-trigger inputs and stores them in a file in the presets directory. When
+/// recalled, the values are sent back to the inputs - either instantly or by cross-fading decimal and color inputs
+/// over the given duration.
+///
+/// The presets must be served as an interface to access the inputs. The handle can be cloned and passed to
+/// other interfaces to control the presets.
+#[derive(Clone)]
+pub struct Presets {
+    shared: Arc<Shared>,
+}
+
+impl Presets {
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        return Self {
+            shared: Arc::new(Shared {
+                path: path.into(),
+                generation: AtomicUsize::new(0),
+                introspection: OnceLock::new(),
+                triggers: Mutex::new(Vec::new()),
+            }),
+        };
+    }
+
+    /// Recall a preset every time the given trigger input fires.
+    pub fn with_trigger(self, trigger: InputHandle<Trigger>, preset: impl Into<String>, fade: Duration) -> Self {
+        self.shared.triggers.lock().expect("Lock poisoned").push((trigger.detach(), preset.into(), fade));
+        return self;
+    }
+
+    fn file(&self, name: &str) -> Result<PathBuf> {
+        if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+            bail!("Invalid preset name: '{name}'");
+        }
+
+        return Ok(self.shared.path.join(format!("{name}.json")));
+    }
+
+    /// List the names of all stored presets.
+    pub async fn list(&self) -> Result<Vec<String>> {
+        let mut presets = Vec::new();
+
+        let mut entries = match tokio::fs::read_dir(&self.shared.path).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(presets),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("Failed to read presets directory: {}", self.shared.path.display()))
+            }
+        };
+
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                    presets.push(name.to_owned());
+                }
+            }
+        }
+
+        presets.sort();
+
+        return Ok(presets);
+    }
+
+    /// Store the current values of all inputs as a preset.
+    ///
+    /// Inputs which have neither received a value nor have an initial value are skipped. An existing preset with the
+    /// same name gets replaced.
+    pub async fn save(&self, name: &str) -> Result<()> {
+        let file = self.file(name)?;
+
+        let introspection = self.shared.introspection.get().ok_or_else(|| anyhow!("Presets are not served"))?;
+
+        let preset: Preset = introspection
+            .inputs
+            .iter()
+            .filter_map(|(input, info)| Some((input.clone(), info.sink().current()?)))
+            // Triggers have no value to capture
+            .filter(|(_, value)| !matches!(value, AnyInputValue::Trigger))
+            .collect();
+
+        let data = serde_json::to_vec_pretty(&preset).context("Failed to serialize preset")?;
+
+        tokio::fs::create_dir_all(&self.shared.path)
+            .await
+            .with_context(|| format!("Failed to create presets directory: {}", self.shared.path.display()))?;
+
+        tokio::fs::write(&file, data).await.with_context(|| format!("Failed to write preset: {}", file.display()))?;
+
+        return Ok(());
+    }
+
+    /// Delete a stored preset.
+    pub async fn delete(&self, name: &str) -> Result<()> {
+        let file = self.file(name)?;
+
+        tokio::fs::remove_file(&file).await.with_context(|| format!("Failed to delete preset: {}", file.display()))?;
+
+        return Ok(());
+    }
+
+    /// Recall a stored preset.
+    ///
+    /// Decimal and color inputs are cross-faded from their current value over the given duration, all other inputs
+    /// are set instantly. A cross-fade runs in the background and is canceled when another preset gets recalled.
+    pub async fn recall(&self, name: &str, fade: Duration) -> Result<()> {
+        let file = self.file(name)?;
+
+        let introspection = self.shared.introspection.get().ok_or_else(|| anyhow!("Presets are not served"))?.clone();
+
+        let data = tokio::fs::read(&file).await.with_context(|| format!("No such preset: {name}"))?;
+        let preset: Preset =
+            serde_json::from_slice(&data).with_context(|| format!("Invalid preset data: {}", file.display()))?;
+
+        let generation = self.shared.generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+        let mut fading = Vec::new();
+        for (input, value) in preset {
+            let Some(info) = introspection.inputs.get(&input) else {
+                eprintln!("🎞 Preset '{name}' contains unknown input '{input}' - ignoring");
+                continue;
+            };
+
+            if let Some(current) = info.sink().current() {
+                if !fade.is_zero() && fadeable(&current, &value) {
+                    fading.push((input, current, value));
+                    continue;
+                }
+            }
+
+            if let Err(err) = info.sink().send_any(value).await {
+                eprintln!("🎞 Failed to recall input '{input}' from preset '{name}': {err}");
+            }
+        }
+
+        if fading.is_empty() {
+            return Ok(());
+        }
+
+        let shared = self.shared.clone();
+        tokio::spawn(async move {
+            let start = Instant::now();
+            let mut interval = tokio::time::interval(FADE_INTERVAL);
+
+            loop {
+                interval.tick().await;
+
+                // Stop fading if another preset was recalled in the meantime
+                if shared.generation.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+
+                let i = f32::min(start.elapsed().as_secs_f32() / fade.as_secs_f32(), 1.0);
+
+                for (input, a, b) in fading.iter() {
+                    let value = lerp(a, b, i).expect("Checked before");
+                    if let Err(err) = introspection.inputs[input].sink().send_any(value).await {
+                        eprintln!("🎞 Failed to fade input '{input}': {err}");
+                    }
+                }
+
+                if i >= 1.0 {
+                    return;
+                }
+            }
+        });
+
+        return Ok(());
+    }
+}
+
+impl Interface for Presets {
+    async fn listen(self, introspection: Arc<Introspection>) -> Result<()> {
+        if self.shared.introspection.set(introspection.clone()).is_err() {
+            bail!("Presets are already served");
+        }
+
+        // Merge all recall triggers into a stream of (preset, fade)
+        let triggers = std::mem::take(&mut *self.shared.triggers.lock().expect("Lock poisoned"));
+        let mut triggers = stream::select_all(triggers.into_iter().map(|(input, preset, fade)| {
+            Box::pin(stream::unfold(input, move |mut input| {
+                let preset = preset.clone();
+                async move {
+                    input.recv().await;
+                    return Some(((preset, fade), input));
+                }
+            }))
+        }));
+
+        while let Some((preset, fade)) = triggers.next().await {
+            if let Err(err) = self.recall(&preset, fade).await {
+                eprintln!("🎞 Failed to recall preset '{preset}': {err:#}");
+            }
+        }
+
+        // No triggers available - keep serving for saves and recalls from other interfaces
+        return future::pending().await;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use palette::rgb::Rgb;
+
+    use photonic::attr::Attr;
+    use photonic::{
+        Buffer, BufferReader, FreeAttrDecl, Node, NodeBuilder, NodeDecl, Output, OutputDecl, RenderContext, Scene,
+    };
+
+    use super::*;
+
+    struct Level<Value> {
+        value: Value,
+    }
+
+    struct LevelNode<Value> {
+        value: Value,
+    }
+
+    impl<Value> NodeDecl for Level<Value>
+    where Value: FreeAttrDecl<f32>
+    {
+        const KIND: &'static str = "level";
+
+        type Node = LevelNode<Value::Attr>;
+
+        async fn materialize(self, builder: &mut NodeBuilder<'_>) -> Result<Self::Node> {
+            return Ok(LevelNode {
+                value: builder.unbound_attr("value", self.value).await?,
+            });
+        }
+    }
+
+    impl<Value> Node for LevelNode<Value>
+    where Value: Attr<f32>
+    {
+        type Element = Rgb;
+
+        fn update(&mut self, ctx: &RenderContext, out: &mut Buffer<Self::Element>) -> Result<()> {
+            let value = self.value.update(ctx);
+            out.fill(Rgb::new(value, value, value));
+            return Ok(());
+        }
+    }
+
+    struct Discard;
+
+    impl OutputDecl for Discard {
+        const KIND: &'static str = "discard";
+
+        type Output = Self;
+
+        async fn materialize(self) -> Result<Self::Output> {
+            return Ok(self);
+        }
+    }
+
+    impl Output for Discard {
+        const KIND: &'static str = "discard";
+
+        type Element = Rgb;
+
+        async fn render(&mut self, _out: impl BufferReader<Element = Self::Element>) -> Result<()> {
+            return Ok(());
+        }
+
+        fn size(&self) -> usize {
+            return 1;
+        }
+    }
+
+    #[tokio::test]
+    async fn save_untouched_inputs() {
+        let path = std::env::temp_dir().join(format!("photonic-presets-{}", std::process::id()));
+
+        let mut scene = Scene::new();
+
+        let level = scene.input::<f32>("level").unwrap();
+        scene.input::<Trigger>("flash").unwrap();
+
+        let node = scene
+            .node("level", Level {
+                value: level.attr(0.25),
+            })
+            .unwrap();
+
+        let main = scene.run(node, Discard).await.unwrap();
+
+        let presets = Presets::with_path(&path);
+        tokio::spawn(presets.clone().listen(main.introspection.clone()));
+        tokio::task::yield_now().await;
+
+        presets.save("initial").await.unwrap();
+
+        let data = tokio::fs::read(path.join("initial.json")).await.unwrap();
+        let preset: Preset = serde_json::from_slice(&data).unwrap();
+
+        assert_eq!(preset.len(), 1);
+        assert!(matches!(preset["level"], AnyInputValue::Decimal(v) if v == 0.25));
+
+        tokio::fs::remove_dir_all(&path).await.unwrap();
+    }
+}
@@ -4,27 +4,12 @@ use std::pin::pin;
 use std::sync::Arc;
 use std::time::Duration;
 
-use anyhow::{anyhow, Context, Result};
-use photonic::attr::Range;
-use serde::{Deserialize, Serialize};
+use anyhow::{Context, Result};
 use tokio_stream::{StreamExt, StreamMap};
 
-use photonic::color::palette::rgb::Rgb;
-use photonic::input::{AnyInputValue, InputSink};
+use photonic::input::AnyInputValue;
 use photonic::interface::{Interface, Introspection};
 
-#[derive(Serialize, Deserialize)]
-#[serde(untagged)]
-enum InputValue {
-    Boolean(bool),
-    Integer(i64),
-    Decimal(f32),
-    Color(Rgb),
-    IntegerRange(i64, i64),
-    DecimalRange(f32, f32),
-    ColorRange(Rgb, Rgb),
-}
-
 pub struct Restore {
     pub path: PathBuf,
 
@@ -37,7 +22,7 @@ impl Restore {}
 impl Interface for Restore {
     async fn listen(self, introspection: Arc<Introspection>) -> Result<()> {
         // Read existing restore data, if possible
-        let mut data = if let Ok(data) = tokio::fs::read(&self.path).await {
+        let mut data: HashMap<String, AnyInputValue> = if let Ok(data) = tokio::fs::read(&self.path).await {
             serde_json::from_slice(&data).with_context(|| format!("Invalid restore data: {}", self.path.display()))?
         } else {
             HashMap::new()
@@ -50,19 +35,10 @@ impl Interface for Restore {
             } else {
                 continue;
             };
-            let result = match (&input.sink(), value) {
-                (InputSink::Boolean(sink), InputValue::Boolean(value)) => sink.send(*value).await,
-                (InputSink::Integer(sink), InputValue::Integer(value)) => sink.send(*value).await,
-                (InputSink::Decimal(sink), InputValue::Decimal(value)) => sink.send(*value).await,
-                (InputSink::Color(sink), InputValue::Color(value)) => sink.send(*value).await,
-                (InputSink::IntegerRange(sink), InputValue::IntegerRange(a, b)) => sink.send(Range::new(*a, *b)).await,
-                (InputSink::DecimalRange(sink), InputValue::DecimalRange(a, b)) => sink.send(Range::new(*a, *b)).await,
-                (InputSink::ColorRange(sink), InputValue::ColorRange(a, b)) => sink.send(Range::new(*a, *b)).await,
-                (_, _) => Err(anyhow!("Restore data type mismatch: {} - ignoring", name)),
-            };
+            let result = input.sink().send_any(value.clone()).await;
 
             if let Err(err) = result {
-                eprintln!("Failed to restore input value: {err}");
+                eprintln!("Failed to restore input value for '{name}': {err}");
             }
         }
 
@@ -81,16 +57,10 @@ impl Interface for Restore {
 
             // Persist the values in the aggregated view
             for (name, value) in values {
-                let value = match value {
-                    AnyInputValue::Trigger => continue, // Skip triggers
-                    AnyInputValue::Boolean(value) => InputValue::Boolean(value),
-                    AnyInputValue::Integer(value) => InputValue::Integer(value),
-                    AnyInputValue::Decimal(value) => InputValue::Decimal(value),
-                    AnyInputValue::Color(value) => InputValue::Color(value),
-                    AnyInputValue::IntegerRange(value) => InputValue::IntegerRange(value.0, value.1),
-                    AnyInputValue::DecimalRange(value) => InputValue::DecimalRange(value.0, value.1),
-                    AnyInputValue::ColorRange(value) => InputValue::ColorRange(value.0, value.1),
-                };
+                // Skip triggers
+                if matches!(value, AnyInputValue::Trigger) {
+                    continue;
+                }
 
                 data.insert(name, value);
             }
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Local};
+
+use photonic::input::{AnyInputValue, InputValueType};
+use photonic::interface::{Interface, Introspection};
+use photonic::InputInfo;
+
+use crate::schedule::{Config, Event, Schedule};
+
+pub mod schedule;
+
+/// Maximum time to sleep before checking the schedule again.
+///
+/// This keeps the scheduler on track if the system clock is adjusted.
+const MAX_SLEEP: Duration = Duration::from_secs(60);
+
+/// Number of upcoming events logged after each run.
+const LOG_UPCOMING: usize = 3;
+
+/// A time-of-day scheduler sending values to inputs.
+///
+/// The schedule is loaded from a YAML file containing a list of rules. Each rule fires either on a cron expression or
+/// relative to a sun event (dawn, sunrise, sunset, dusk) calculated locally from the configured location. When a rule
+/// fires, its value is sent to the named input.
+///
+/// The handle can be cloned to list the upcoming events while the scheduler is served.
+#[derive(Clone)]
+pub struct Scheduler {
+    schedule: Arc<Schedule>,
+}
+
+impl Scheduler {
+    pub fn with_schedule(schedule: Schedule) -> Self {
+        return Self {
+            schedule: Arc::new(schedule),
+        };
+    }
+
+    /// Load the schedule from a YAML file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        let config = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read schedule file: {}", path.display()))?;
+        let config: Config =
+            serde_yaml::from_str(&config).with_context(|| format!("Invalid schedule file: {}", path.display()))?;
+        let schedule = Schedule::try_from(config).with_context(|| format!("Invalid schedule: {}", path.display()))?;
+
+        return Ok(Self::with_schedule(schedule));
+    }
+
+    pub fn schedule(&self) -> &Schedule {
+        return &self.schedule;
+    }
+
+    /// List the upcoming events in chronological order.
+    pub fn upcoming(&self) -> impl Iterator<Item = Event<'_>> {
+        return self.schedule.after(Local::now());
+    }
+
+    async fn fire(&self, event: Event<'_>, resolved: &[(Arc<InputInfo>, AnyInputValue)]) {
+        let (input, value) = &resolved[event.index];
+
+        eprintln!("⏰ Running {}", event);
+
+        if let Err(err) = input.sink().send_any(value.clone()).await {
+            eprintln!("⏰ Failed to send input '{}': {}", input.name(), err);
+        }
+    }
+
+    fn log_upcoming(&self, after: DateTime<Local>) {
+        for event in self.schedule.after(after).take(LOG_UPCOMING) {
+            eprintln!("⏰ Upcoming {}", event);
+        }
+    }
+}
+
+impl Interface for Scheduler {
+    async fn listen(self, introspection: Arc<Introspection>) -> Result<()> {
+        let resolved = self
+            .schedule
+            .rules
+            .iter()
+            .map(|rule| {
+                let input =
+                    introspection.inputs.get(&rule.input).ok_or_else(|| anyhow!("No such input: {}", rule.input))?;
+
+                let value = match &rule.value {
+                    Some(value) => value.resolve(input.value_type())?,
+                    None if input.value_type() == InputValueType::Trigger => AnyInputValue::Trigger,
+                    None => bail!("Value missing for input: {}", rule.input),
+                };
+
+                return Ok((input.clone(), value));
+            })
+            .zip(self.schedule.rules.iter())
+            .map(|(resolved, rule)| resolved.with_context(|| format!("Invalid rule: {}", rule.name)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut last = Local::now();
+
+        if self.schedule.restore {
+            // Find the latest past event for each input
+            let mut latest = HashMap::new();
+            for (index, rule) in self.schedule.rules.iter().enumerate() {
+                if matches!(resolved[index].1, AnyInputValue::Trigger) {
+                    continue;
+                }
+
+                let Some(time) = rule.timing.before(last) else {
+                    continue;
+                };
+
+                let event = Event {
+                    time,
+                    index,
+                    rule,
+                };
+
+                latest
+                    .entry(&rule.input)
+                    .and_modify(|latest: &mut Event| {
+                        if event.time > latest.time {
+                            *latest = event;
+                        }
+                    })
+                    .or_insert(event);
+            }
+
+            for event in latest.into_values() {
+                self.fire(event, &resolved).await;
+            }
+        }
+
+        self.log_upcoming(last);
+
+        loop {
+            let now = Local::now();
+            let sleep = match self.schedule.after(last).next() {
+                Some(event) => (event.time - now).to_std().unwrap_or(Duration::ZERO).min(MAX_SLEEP),
+                None => MAX_SLEEP,
+            };
+
+            tokio::time::sleep(sleep).await;
+
+            let now = Local::now();
+
+            let mut fired = false;
+            for event in self.schedule.after(last).take_while(|event| event.time <= now) {
+                self.fire(event, &resolved).await;
+                fired = true;
+            }
+
+            last = now;
+
+            if fired {
+                self.log_upcoming(last);
+            }
+        }
+    }
+}
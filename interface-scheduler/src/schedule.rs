@@ -0,0 +1,443 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Days, Local, NaiveDate, TimeDelta, TimeZone, Utc};
+use serde::{Deserialize, Deserializer};
+use sunrise::{Coordinates, DawnType, SolarDay, SolarEvent};
+
+use photonic::input::InputValueDecl;
+
+/// Maximum number of days to search for the next sun event.
+///
+/// Close to the poles, the sun may not rise or set for months.
+const SUN_SEARCH_DAYS: u64 = 366;
+
+/// A schedule as declared in a schedule file.
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    /// Coordinates used to calculate sun events
+    #[serde(default)]
+    pub location: Option<Location>,
+
+    /// Apply the latest past event of each input on startup
+    #[serde(default)]
+    pub restore: bool,
+
+    pub rules: Vec<RuleConfig>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct Location {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RuleConfig {
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Cron expression with seconds: `sec min hour day-of-month month day-of-week [year]`
+    #[serde(default)]
+    pub cron: Option<String>,
+
+    /// Sun event relative to the configured location
+    #[serde(default)]
+    pub sun: Option<SunEvent>,
+
+    /// Offset applied to the sun event - prefix with `-` for times before the event
+    #[serde(default, deserialize_with = "deserialize_offset")]
+    pub offset: TimeDelta,
+
+    pub input: String,
+
+    /// The value to send - can be omitted for trigger inputs
+    #[serde(default)]
+    pub value: Option<InputValueDecl>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SunEvent {
+    /// Begin of civil twilight
+    Dawn,
+    Sunrise,
+    Sunset,
+    /// End of civil twilight
+    Dusk,
+}
+
+impl SunEvent {
+    fn solar(self) -> SolarEvent {
+        return match self {
+            SunEvent::Dawn => SolarEvent::Dawn(DawnType::Civil),
+            SunEvent::Sunrise => SolarEvent::Sunrise,
+            SunEvent::Sunset => SolarEvent::Sunset,
+            SunEvent::Dusk => SolarEvent::Dusk(DawnType::Civil),
+        };
+    }
+}
+
+impl fmt::Display for SunEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            SunEvent::Dawn => write!(f, "dawn"),
+            SunEvent::Sunrise => write!(f, "sunrise"),
+            SunEvent::Sunset => write!(f, "sunset"),
+            SunEvent::Dusk => write!(f, "dusk"),
+        };
+    }
+}
+
+fn deserialize_offset<'de, D>(deserializer: D) -> Result<TimeDelta, D::Error>
+where D: Deserializer<'de> {
+    let offset = String::deserialize(deserializer)?;
+
+    let (negative, offset) = match offset.trim().strip_prefix('-') {
+        Some(offset) => (true, offset),
+        None => (false, offset.trim().trim_start_matches('+')),
+    };
+
+    let offset = humantime::parse_duration(offset.trim()).map_err(serde::de::Error::custom)?;
+    let offset = TimeDelta::from_std(offset).map_err(serde::de::Error::custom)?;
+
+    return Ok(if negative { -offset } else { offset });
+}
+
+/// The point in time a rule fires at.
+#[derive(Debug, Clone)]
+pub enum Timing {
+    Cron(Box<cron::Schedule>),
+    Sun { event: SunEvent, offset: TimeDelta, coordinates: Coordinates },
+}
+
+impl Timing {
+    fn sun<Tz>(
+        event: SunEvent,
+        offset: TimeDelta,
+        coordinates: Coordinates,
+        date: NaiveDate,
+        tz: &Tz,
+    ) -> Option<DateTime<Tz>>
+    where
+        Tz: TimeZone,
+    {
+        let time = SolarDay::new(coordinates, date).event_time(event.solar())?;
+        let time: DateTime<Utc> = time.checked_add_signed(offset)?;
+        return Some(time.with_timezone(tz));
+    }
+
+    /// Number of days a sun event can be moved by the given offset.
+    fn margin(offset: TimeDelta) -> Days {
+        return Days::new(offset.num_days().unsigned_abs() + 1);
+    }
+
+    /// All times the rule fires at strictly after the given time.
+    pub fn after<Tz>(&self, after: DateTime<Tz>) -> Box<dyn Iterator<Item = DateTime<Tz>> + Send + '_>
+    where
+        Tz: TimeZone + Send + Sync + 'static,
+        Tz::Offset: Send + Sync,
+    {
+        return match self {
+            Timing::Cron(schedule) => Box::new(schedule.after(&after)),
+            Timing::Sun {
+                event,
+                offset,
+                coordinates,
+            } => {
+                // Start early as the offset can move the event to the days before
+                let start = after.date_naive() - Self::margin(*offset);
+                let tz = after.timezone();
+                Box::new(
+                    (0..SUN_SEARCH_DAYS)
+                        .filter_map(move |day| start.checked_add_days(Days::new(day)))
+                        .filter_map(move |date| Self::sun(*event, *offset, *coordinates, date, &tz))
+                        .filter(move |time| *time > after),
+                )
+            }
+        };
+    }
+
+    /// The latest time the rule fired at before or at the given time.
+    pub fn before<Tz>(&self, before: DateTime<Tz>) -> Option<DateTime<Tz>>
+    where Tz: TimeZone {
+        return match self {
+            Timing::Cron(schedule) => schedule.after(&before).next_back(),
+            Timing::Sun {
+                event,
+                offset,
+                coordinates,
+            } => {
+                // Start late as the offset can move the event to the days after
+                let start = before.date_naive() + Self::margin(*offset);
+                (0..SUN_SEARCH_DAYS)
+                    .filter_map(|day| start.checked_sub_days(Days::new(day)))
+                    .filter_map(|date| Self::sun(*event, *offset, *coordinates, date, &before.timezone()))
+                    .find(|time| *time <= before)
+            }
+        };
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Timing::Cron(schedule) => write!(f, "cron '{}'", schedule.source()),
+            Timing::Sun {
+                event,
+                offset,
+                ..
+            } if offset.is_zero() => write!(f, "{event}"),
+            Timing::Sun {
+                event,
+                offset,
+                ..
+            } => {
+                let sign = if *offset < TimeDelta::zero() { '-' } else { '+' };
+                let offset = offset.abs().to_std().map_err(|_| fmt::Error)?;
+                write!(f, "{event} {sign} {}", humantime::format_duration(offset))
+            }
+        };
+    }
+}
+
+/// A rule sending a value to an input at the scheduled times.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub name: String,
+    pub timing: Timing,
+    pub input: String,
+    pub value: Option<InputValueDecl>,
+}
+
+/// A scheduled event.
+#[derive(Debug, Clone, Copy)]
+pub struct Event<'a> {
+    pub time: DateTime<Local>,
+
+    /// Index of the rule in the schedule
+    pub index: usize,
+    pub rule: &'a Rule,
+}
+
+impl fmt::Display for Event<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} ({}) -> {}", self.time.format("%F %T"), self.rule.name, self.rule.timing, self.rule.input)?;
+        if let Some(value) = &self.rule.value {
+            write!(f, " = {value}")?;
+        }
+        return Ok(());
+    }
+}
+
+/// A schedule with all rules validated.
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    pub rules: Vec<Rule>,
+    pub restore: bool,
+}
+
+impl Schedule {
+    /// All events of all rules strictly after the given time in chronological order.
+    pub fn after(&self, after: DateTime<Local>) -> impl Iterator<Item = Event<'_>> {
+        let mut rules = self
+            .rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                rule.timing
+                    .after(after)
+                    .map(move |time| Event {
+                        time,
+                        index,
+                        rule,
+                    })
+                    .peekable()
+            })
+            .collect::<Vec<_>>();
+
+        return std::iter::from_fn(move || {
+            let next =
+                rules.iter_mut().filter_map(|rule| Some((rule.peek()?.time, rule))).min_by_key(|(time, _)| *time)?.1;
+            return next.next();
+        });
+    }
+}
+
+impl TryFrom<Config> for Schedule {
+    type Error = anyhow::Error;
+
+    fn try_from(config: Config) -> Result<Self> {
+        let coordinates = config
+            .location
+            .map(|location| {
+                Coordinates::new(location.latitude, location.longitude)
+                    .ok_or_else(|| anyhow!("Invalid location: {}, {}", location.latitude, location.longitude))
+            })
+            .transpose()?;
+
+        let rules = config
+            .rules
+            .into_iter()
+            .enumerate()
+            .map(|(i, rule)| {
+                let name = rule.name.unwrap_or_else(|| format!("#{i}"));
+
+                let timing = match (rule.cron, rule.sun) {
+                    (Some(cron), None) => Timing::Cron(Box::new(
+                        cron::Schedule::from_str(&cron)
+                            .with_context(|| format!("Invalid cron expression in rule {name}: {cron}"))?,
+                    )),
+                    (None, Some(event)) => Timing::Sun {
+                        event,
+                        offset: rule.offset,
+                        coordinates: coordinates
+                            .ok_or_else(|| anyhow!("Rule {name} requires a location for sun events"))?,
+                    },
+                    (None, None) => bail!("Rule {name} requires either 'cron' or 'sun'"),
+                    (Some(_), Some(_)) => bail!("Rule {name} must not have both 'cron' and 'sun'"),
+                };
+
+                return Ok(Rule {
+                    name,
+                    timing,
+                    input: rule.input,
+                    value: rule.value,
+                });
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        return Ok(Self {
+            rules,
+            restore: config.restore,
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono_tz::Europe::Berlin;
+
+    use super::*;
+
+    fn berlin() -> Coordinates {
+        return Coordinates::new(52.52, 13.405).unwrap();
+    }
+
+    fn cron(expr: &str) -> Timing {
+        return Timing::Cron(Box::new(cron::Schedule::from_str(expr).unwrap()));
+    }
+
+    fn sun(event: SunEvent, offset: TimeDelta) -> Timing {
+        return Timing::Sun {
+            event,
+            offset,
+            coordinates: berlin(),
+        };
+    }
+
+    #[test]
+    fn cron_next() {
+        let timing = cron("0 30 7 * * *");
+
+        let next = timing.after(Berlin.with_ymd_and_hms(2024, 5, 1, 7, 0, 0).unwrap()).next().unwrap();
+        assert_eq!(next, Berlin.with_ymd_and_hms(2024, 5, 1, 7, 30, 0).unwrap());
+
+        // Strictly after the given time
+        let next = timing.after(next).next().unwrap();
+        assert_eq!(next, Berlin.with_ymd_and_hms(2024, 5, 2, 7, 30, 0).unwrap());
+
+        let prev = timing.before(Berlin.with_ymd_and_hms(2024, 5, 1, 7, 0, 0).unwrap()).unwrap();
+        assert_eq!(prev, Berlin.with_ymd_and_hms(2024, 4, 30, 7, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn cron_dst() {
+        let timing = cron("0 30 7 * * *");
+
+        // Clocks go forward on 2024-03-31 at 02:00 - the rule keeps firing at the same local time
+        let times = timing.after(Berlin.with_ymd_and_hms(2024, 3, 30, 0, 0, 0).unwrap()).take(2).collect::<Vec<_>>();
+        assert_eq!(times[0], Berlin.with_ymd_and_hms(2024, 3, 30, 7, 30, 0).unwrap());
+        assert_eq!(times[1], Berlin.with_ymd_and_hms(2024, 3, 31, 7, 30, 0).unwrap());
+        assert_eq!(times[1] - times[0], TimeDelta::hours(23));
+    }
+
+    #[test]
+    fn sun_offset() {
+        let after = Berlin.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+
+        let sunrise = sun(SunEvent::Sunrise, TimeDelta::zero()).after(after).next().unwrap();
+        assert_eq!(sunrise.date_naive(), after.date_naive());
+
+        let early = sun(SunEvent::Sunrise, TimeDelta::minutes(-30)).after(after).next().unwrap();
+        assert_eq!(early, sunrise - TimeDelta::minutes(30));
+
+        let late = sun(SunEvent::Sunrise, TimeDelta::minutes(90)).after(after).next().unwrap();
+        assert_eq!(late, sunrise + TimeDelta::minutes(90));
+
+        // The event of the day is not repeated once passed
+        let next = sun(SunEvent::Sunrise, TimeDelta::minutes(-30)).after(early).next().unwrap();
+        assert_eq!(next.date_naive(), early.date_naive().succ_opt().unwrap());
+
+        let prev = sun(SunEvent::Sunrise, TimeDelta::minutes(-30)).before(sunrise).unwrap();
+        assert_eq!(prev, early);
+    }
+
+    #[test]
+    fn sun_offset_across_days() {
+        // Half a day after sunset is early the next morning
+        let timing = sun(SunEvent::Sunset, TimeDelta::hours(12));
+
+        let after = Berlin.with_ymd_and_hms(2024, 6, 2, 0, 0, 0).unwrap();
+        let next = timing.after(after).next().unwrap();
+
+        // The event belongs to the sunset of the day before
+        let sunset = sun(SunEvent::Sunset, TimeDelta::zero())
+            .after(Berlin.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap())
+            .next()
+            .unwrap();
+        assert_eq!(next, sunset + TimeDelta::hours(12));
+        assert_eq!(next.date_naive(), after.date_naive());
+    }
+
+    #[test]
+    fn sun_dst() {
+        let timing = sun(SunEvent::Sunrise, TimeDelta::minutes(-30));
+
+        let times = timing.after(Berlin.with_ymd_and_hms(2024, 3, 30, 0, 0, 0).unwrap()).take(2).collect::<Vec<_>>();
+        assert_eq!(times[0].date_naive(), NaiveDate::from_ymd_opt(2024, 3, 30).unwrap());
+        assert_eq!(times[1].date_naive(), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+
+        // Sunrise moves by a few minutes per day in absolute time but jumps by an hour in local time
+        let absolute = times[1] - times[0];
+        assert!(absolute > TimeDelta::hours(23) + TimeDelta::minutes(50) && absolute < TimeDelta::hours(24));
+
+        let local = times[1].naive_local().time() - times[0].naive_local().time();
+        assert!(local > TimeDelta::minutes(50) && local < TimeDelta::hours(1));
+    }
+
+    #[test]
+    fn schedule_order() {
+        let rule = |name: &str, timing: Timing| Rule {
+            name: name.to_owned(),
+            timing,
+            input: name.to_owned(),
+            value: None,
+        };
+
+        let schedule = Schedule {
+            rules: vec![rule("a", cron("0 0 12 * * *")), rule("b", cron("0 0 6,18 * * *"))],
+            restore: false,
+        };
+
+        let after = Local::now();
+        let names = schedule.after(after).take(6).map(|event| event.rule.name.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(names.iter().filter(|name| **name == "a").count(), 2);
+        assert_eq!(names.iter().filter(|name| **name == "b").count(), 4);
+
+        let times = schedule.after(after).take(6).map(|event| event.time).collect::<Vec<_>>();
+        assert!(times.windows(2).all(|times| times[0] < times[1]));
+    }
+}
@@ -1,10 +1,11 @@
 use crate::scene;
 pub use bounds::{Bounded, Bounds};
 pub use fixed::{AsFixedAttr, FixedAttr, FixedAttrDecl};
+pub use gradient::{Gradient, GradientSpace, Stop};
 pub use range::Range;
 pub use values::AttrValue;
 
-pub use self::ext::FreeAttrDeclExt;
+pub use self::ext::{BoundAttrDeclExt, FreeAttrDeclExt};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum AttrValueType {
@@ -13,7 +14,9 @@ pub enum AttrValueType {
     Integer,
     Decimal,
     Color,
+    Duration,
     Range(&'static AttrValueType),
+    Gradient,
 }
 
 impl std::fmt::Display for AttrValueType {
@@ -24,7 +27,9 @@ impl std::fmt::Display for AttrValueType {
             Self::Integer => f.write_str("integer"),
             Self::Decimal => f.write_str("decimal"),
             Self::Color => f.write_str("color"),
+            Self::Duration => f.write_str("duration"),
             Self::Range(element) => write!(f, "range<{element}>"),
+            Self::Gradient => f.write_str("gradient"),
         };
     }
 }
@@ -45,5 +50,6 @@ where V: AttrValue
 pub mod bounds;
 pub mod ext;
 pub mod fixed;
+pub mod gradient;
 pub mod range;
 pub mod values;
@@ -1,9 +1,10 @@
-use anyhow::Result;
-use num_traits::Num;
+use anyhow::{bail, Result};
+use num_traits::{Float, Num};
 use std::marker::PhantomData;
 use std::ops;
 
 use crate::attr::{Bounded, Bounds};
+use crate::math::Lerp;
 use crate::{Attr, AttrBuilder, AttrValue, BoundAttrDecl, FreeAttrDecl, RenderContext};
 
 pub trait FreeAttrDeclExt<V>: FreeAttrDecl<V> + Sized
@@ -17,6 +18,30 @@ where V: AttrValue
 
     fn scale(self, scale: V) -> Scale<Self, V>
     where V: Num;
+
+    fn add<Other>(self, other: Other) -> Binary<Self, Other, op::Add>
+    where Other: FreeAttrDecl<V>;
+
+    fn mul<Other>(self, other: Other) -> Binary<Self, Other, op::Mul>
+    where Other: FreeAttrDecl<V>;
+
+    fn min<Other>(self, other: Other) -> Binary<Self, Other, op::Min>
+    where Other: FreeAttrDecl<V>;
+
+    fn max<Other>(self, other: Other) -> Binary<Self, Other, op::Max>
+    where Other: FreeAttrDecl<V>;
+
+    fn mix<Other, Amount>(self, other: Other, amount: Amount) -> Mix<Self, Other, Amount>
+    where
+        Other: FreeAttrDecl<V>,
+        Amount: BoundAttrDecl<f32>;
+
+    fn clamp(self, bounds: impl Into<Bounds<V>>) -> Clamp<Self, V>;
+
+    fn invert(self, bounds: impl Into<Bounds<V>>) -> Invert<Self, V>;
+
+    fn quantize(self, step: V) -> Quantize<Self, V>
+    where V: Float;
 }
 
 pub trait BoundAttrDeclExt<V>: BoundAttrDecl<V> + Sized
@@ -24,6 +49,29 @@ where V: AttrValue + Bounded
 {
     fn scale(self, scale: V) -> Scale<Self, V>
     where V: Num;
+
+    fn add<Other>(self, other: Other) -> Binary<Self, Other, op::Add>
+    where Other: BoundAttrDecl<V>;
+
+    fn mul<Other>(self, other: Other) -> Binary<Self, Other, op::Mul>
+    where Other: BoundAttrDecl<V>;
+
+    fn min<Other>(self, other: Other) -> Binary<Self, Other, op::Min>
+    where Other: BoundAttrDecl<V>;
+
+    fn max<Other>(self, other: Other) -> Binary<Self, Other, op::Max>
+    where Other: BoundAttrDecl<V>;
+
+    fn mix<Other, Amount>(self, other: Other, amount: Amount) -> Mix<Self, Other, Amount>
+    where
+        Other: BoundAttrDecl<V>,
+        Amount: BoundAttrDecl<f32>;
+
+    /// Inverts the attribute within the bounds it is materialized with.
+    fn invert(self) -> Invert<Self, V>;
+
+    fn quantize(self, step: V) -> Quantize<Self, V>
+    where V: Float;
 }
 
 impl<V, Decl> FreeAttrDeclExt<V> for Decl
@@ -51,6 +99,60 @@ where
             phantom: PhantomData,
         };
     }
+
+    fn add<Other>(self, other: Other) -> Binary<Self, Other, op::Add>
+    where Other: FreeAttrDecl<V> {
+        return Binary::new(self, other);
+    }
+
+    fn mul<Other>(self, other: Other) -> Binary<Self, Other, op::Mul>
+    where Other: FreeAttrDecl<V> {
+        return Binary::new(self, other);
+    }
+
+    fn min<Other>(self, other: Other) -> Binary<Self, Other, op::Min>
+    where Other: FreeAttrDecl<V> {
+        return Binary::new(self, other);
+    }
+
+    fn max<Other>(self, other: Other) -> Binary<Self, Other, op::Max>
+    where Other: FreeAttrDecl<V> {
+        return Binary::new(self, other);
+    }
+
+    fn mix<Other, Amount>(self, other: Other, amount: Amount) -> Mix<Self, Other, Amount>
+    where
+        Other: FreeAttrDecl<V>,
+        Amount: BoundAttrDecl<f32>,
+    {
+        return Mix {
+            inner: self,
+            other,
+            amount,
+        };
+    }
+
+    fn clamp(self, bounds: impl Into<Bounds<V>>) -> Clamp<Self, V> {
+        return Clamp {
+            inner: self,
+            bounds: bounds.into(),
+        };
+    }
+
+    fn invert(self, bounds: impl Into<Bounds<V>>) -> Invert<Self, V> {
+        return Invert {
+            inner: self,
+            bounds: Some(bounds.into()),
+        };
+    }
+
+    fn quantize(self, step: V) -> Quantize<Self, V>
+    where V: Float {
+        return Quantize {
+            inner: self,
+            step,
+        };
+    }
 }
 
 impl<V, Decl> BoundAttrDeclExt<V> for Decl
@@ -66,6 +168,53 @@ where
             phantom: PhantomData,
         };
     }
+
+    fn add<Other>(self, other: Other) -> Binary<Self, Other, op::Add>
+    where Other: BoundAttrDecl<V> {
+        return Binary::new(self, other);
+    }
+
+    fn mul<Other>(self, other: Other) -> Binary<Self, Other, op::Mul>
+    where Other: BoundAttrDecl<V> {
+        return Binary::new(self, other);
+    }
+
+    fn min<Other>(self, other: Other) -> Binary<Self, Other, op::Min>
+    where Other: BoundAttrDecl<V> {
+        return Binary::new(self, other);
+    }
+
+    fn max<Other>(self, other: Other) -> Binary<Self, Other, op::Max>
+    where Other: BoundAttrDecl<V> {
+        return Binary::new(self, other);
+    }
+
+    fn mix<Other, Amount>(self, other: Other, amount: Amount) -> Mix<Self, Other, Amount>
+    where
+        Other: BoundAttrDecl<V>,
+        Amount: BoundAttrDecl<f32>,
+    {
+        return Mix {
+            inner: self,
+            other,
+            amount,
+        };
+    }
+
+    fn invert(self) -> Invert<Self, V> {
+        return Invert {
+            inner: self,
+            bounds: None,
+        };
+    }
+
+    fn quantize(self, step: V) -> Quantize<Self, V>
+    where V: Float {
+        return Quantize {
+            inner: self,
+            step,
+        };
+    }
 }
 
 #[derive(Debug)]
@@ -91,8 +240,8 @@ where
 
     type Attr = MapAttr<Inner::Attr, F, V, R>;
 
-    fn materialize(self, builder: &mut AttrBuilder) -> Result<Self::Attr> {
-        let inner = builder.unbound_attr("inner", self.inner)?;
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let inner = builder.unbound_attr("inner", self.inner).await?;
 
         return Ok(Self::Attr {
             inner,
@@ -145,9 +294,9 @@ where
     const KIND: &'static str = "scale";
     type Attr = ScaleAttr<Inner::Attr, V>;
 
-    fn materialize(self, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         return Ok(Self::Attr {
-            inner: builder.unbound_attr("inner", self.inner)?,
+            inner: builder.unbound_attr("inner", self.inner).await?,
             scale: self.scale,
             phantom: self.phantom,
         });
@@ -162,12 +311,14 @@ where
     const KIND: &'static str = "scale";
     type Attr = ScaleAttr<Inner::Attr, V>;
 
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         return Ok(Self::Attr {
-            inner: builder.bound_attr("inner", self.inner, Bounds {
-                min: bounds.min / self.scale,
-                max: bounds.max / self.scale,
-            })?,
+            inner: builder
+                .bound_attr("inner", self.inner, Bounds {
+                    min: bounds.min / self.scale,
+                    max: bounds.max / self.scale,
+                })
+                .await?,
             scale: self.scale,
             phantom: self.phantom,
         });
@@ -193,3 +344,428 @@ where
         return self.inner.update(ctx) * self.scale;
     }
 }
+
+fn clamp<V>(value: V, bounds: &Bounds<V>) -> V
+where V: PartialOrd + Copy {
+    if value < bounds.min {
+        return bounds.min;
+    }
+
+    if value > bounds.max {
+        return bounds.max;
+    }
+
+    return value;
+}
+
+/// A named operation on attribute values.
+pub trait Operation {
+    const KIND: &'static str;
+}
+
+/// Operations combining two attribute values.
+pub trait BinaryOp<V>: Operation {
+    fn apply(a: V, b: V) -> V;
+}
+
+pub mod op {
+    use std::ops;
+
+    use super::{BinaryOp, Operation};
+
+    #[derive(Debug)]
+    pub struct Add;
+
+    impl Operation for Add {
+        const KIND: &'static str = "add";
+    }
+
+    impl<V> BinaryOp<V> for Add
+    where V: ops::Add<Output = V>
+    {
+        fn apply(a: V, b: V) -> V {
+            return a + b;
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Mul;
+
+    impl Operation for Mul {
+        const KIND: &'static str = "mul";
+    }
+
+    impl<V> BinaryOp<V> for Mul
+    where V: ops::Mul<Output = V>
+    {
+        fn apply(a: V, b: V) -> V {
+            return a * b;
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Min;
+
+    impl Operation for Min {
+        const KIND: &'static str = "min";
+    }
+
+    impl<V> BinaryOp<V> for Min
+    where V: PartialOrd
+    {
+        fn apply(a: V, b: V) -> V {
+            return if b < a { b } else { a };
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Max;
+
+    impl Operation for Max {
+        const KIND: &'static str = "max";
+    }
+
+    impl<V> BinaryOp<V> for Max
+    where V: PartialOrd
+    {
+        fn apply(a: V, b: V) -> V {
+            return if b > a { b } else { a };
+        }
+    }
+}
+
+/// Combines two attributes using a binary operation.
+///
+/// If used as bound attribute, both attributes are bound by the same bounds and the result is clamped to them.
+#[derive(Debug)]
+pub struct Binary<Inner, Other, Op> {
+    inner: Inner,
+    other: Other,
+    phantom: PhantomData<Op>,
+}
+
+impl<Inner, Other, Op> Binary<Inner, Other, Op> {
+    pub fn new(inner: Inner, other: Other) -> Self {
+        return Self {
+            inner,
+            other,
+            phantom: PhantomData,
+        };
+    }
+}
+
+impl<Inner, Other, Op, V> FreeAttrDecl<V> for Binary<Inner, Other, Op>
+where
+    Inner: FreeAttrDecl<V>,
+    Other: FreeAttrDecl<V>,
+    Op: BinaryOp<V>,
+    V: AttrValue,
+{
+    const KIND: &'static str = Op::KIND;
+    type Attr = BinaryAttr<Inner::Attr, Other::Attr, Op>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(Self::Attr {
+            inner: builder.unbound_attr("inner", self.inner).await?,
+            other: builder.unbound_attr("other", self.other).await?,
+            phantom: self.phantom,
+        });
+    }
+}
+
+impl<Inner, Other, Op, V> BoundAttrDecl<V> for Binary<Inner, Other, Op>
+where
+    Inner: BoundAttrDecl<V>,
+    Other: BoundAttrDecl<V>,
+    Op: BinaryOp<V>,
+    V: AttrValue + Bounded + PartialOrd,
+{
+    const KIND: &'static str = Op::KIND;
+    type Attr = ClampAttr<BinaryAttr<Inner::Attr, Other::Attr, Op>, V>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(Self::Attr {
+            inner: BinaryAttr {
+                inner: builder.bound_attr("inner", self.inner, bounds).await?,
+                other: builder.bound_attr("other", self.other, bounds).await?,
+                phantom: self.phantom,
+            },
+            bounds,
+        });
+    }
+}
+
+pub struct BinaryAttr<Inner, Other, Op> {
+    inner: Inner,
+    other: Other,
+    phantom: PhantomData<Op>,
+}
+
+impl<Inner, Other, Op, V> Attr<V> for BinaryAttr<Inner, Other, Op>
+where
+    Inner: Attr<V>,
+    Other: Attr<V>,
+    Op: BinaryOp<V>,
+    V: AttrValue,
+{
+    fn update(&mut self, ctx: &RenderContext) -> V {
+        let a = self.inner.update(ctx);
+        let b = self.other.update(ctx);
+        return Op::apply(a, b);
+    }
+}
+
+/// Mixes two attributes - an amount of `0.0` results in the first, `1.0` in the other attribute.
+#[derive(Debug)]
+pub struct Mix<Inner, Other, Amount> {
+    inner: Inner,
+    other: Other,
+    amount: Amount,
+}
+
+impl<Inner, Other, Amount, V> FreeAttrDecl<V> for Mix<Inner, Other, Amount>
+where
+    Inner: FreeAttrDecl<V>,
+    Other: FreeAttrDecl<V>,
+    Amount: BoundAttrDecl<f32>,
+    V: AttrValue + Lerp,
+{
+    const KIND: &'static str = "mix";
+    type Attr = MixAttr<Inner::Attr, Other::Attr, Amount::Attr>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(Self::Attr {
+            inner: builder.unbound_attr("inner", self.inner).await?,
+            other: builder.unbound_attr("other", self.other).await?,
+            amount: builder.bound_attr("amount", self.amount, Bounds::normal()).await?,
+        });
+    }
+}
+
+impl<Inner, Other, Amount, V> BoundAttrDecl<V> for Mix<Inner, Other, Amount>
+where
+    Inner: BoundAttrDecl<V>,
+    Other: BoundAttrDecl<V>,
+    Amount: BoundAttrDecl<f32>,
+    V: AttrValue + Bounded + Lerp,
+{
+    const KIND: &'static str = "mix";
+    type Attr = MixAttr<Inner::Attr, Other::Attr, Amount::Attr>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(Self::Attr {
+            inner: builder.bound_attr("inner", self.inner, bounds).await?,
+            other: builder.bound_attr("other", self.other, bounds).await?,
+            amount: builder.bound_attr("amount", self.amount, Bounds::normal()).await?,
+        });
+    }
+}
+
+pub struct MixAttr<Inner, Other, Amount> {
+    inner: Inner,
+    other: Other,
+    amount: Amount,
+}
+
+impl<Inner, Other, Amount, V> Attr<V> for MixAttr<Inner, Other, Amount>
+where
+    Inner: Attr<V>,
+    Other: Attr<V>,
+    Amount: Attr<f32>,
+    V: AttrValue + Lerp,
+{
+    fn update(&mut self, ctx: &RenderContext) -> V {
+        let a = self.inner.update(ctx);
+        let b = self.other.update(ctx);
+        let amount = self.amount.update(ctx);
+        return V::lerp(a, b, amount);
+    }
+}
+
+/// Clamps an unbound attribute to the given bounds.
+///
+/// If used as bound attribute, the value is clamped to the intersection of both bounds.
+#[derive(Debug)]
+pub struct Clamp<Inner, V> {
+    inner: Inner,
+    bounds: Bounds<V>,
+}
+
+impl<Inner, V> FreeAttrDecl<V> for Clamp<Inner, V>
+where
+    Inner: FreeAttrDecl<V>,
+    V: AttrValue + PartialOrd,
+{
+    const KIND: &'static str = "clamp";
+    type Attr = ClampAttr<Inner::Attr, V>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(Self::Attr {
+            inner: builder.unbound_attr("inner", self.inner).await?,
+            bounds: self.bounds,
+        });
+    }
+}
+
+impl<Inner, V> BoundAttrDecl<V> for Clamp<Inner, V>
+where
+    Inner: FreeAttrDecl<V>,
+    V: AttrValue + Bounded + PartialOrd,
+{
+    const KIND: &'static str = "clamp";
+    type Attr = ClampAttr<Inner::Attr, V>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(Self::Attr {
+            inner: builder.unbound_attr("inner", self.inner).await?,
+            bounds: Bounds {
+                min: clamp(self.bounds.min, &bounds),
+                max: clamp(self.bounds.max, &bounds),
+            },
+        });
+    }
+}
+
+pub struct ClampAttr<Inner, V> {
+    inner: Inner,
+    bounds: Bounds<V>,
+}
+
+impl<Inner, V> Attr<V> for ClampAttr<Inner, V>
+where
+    Inner: Attr<V>,
+    V: AttrValue + PartialOrd,
+{
+    fn update(&mut self, ctx: &RenderContext) -> V {
+        return clamp(self.inner.update(ctx), &self.bounds);
+    }
+}
+
+/// Inverts an attribute within bounds - the lower bound becomes the upper bound and vice versa.
+///
+/// Unbound attributes are inverted within the given bounds, bound attributes within the bounds they are materialized
+/// with.
+#[derive(Debug)]
+pub struct Invert<Inner, V> {
+    inner: Inner,
+    bounds: Option<Bounds<V>>,
+}
+
+impl<Inner, V> FreeAttrDecl<V> for Invert<Inner, V>
+where
+    Inner: FreeAttrDecl<V>,
+    V: AttrValue + ops::Add<Output = V> + ops::Sub<Output = V>,
+{
+    const KIND: &'static str = "invert";
+    type Attr = InvertAttr<Inner::Attr, V>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        let Some(bounds) = self.bounds else {
+            bail!("Inverting an unbound attribute requires bounds");
+        };
+
+        return Ok(Self::Attr {
+            inner: builder.unbound_attr("inner", self.inner).await?,
+            bounds,
+        });
+    }
+}
+
+impl<Inner, V> BoundAttrDecl<V> for Invert<Inner, V>
+where
+    Inner: BoundAttrDecl<V>,
+    V: AttrValue + Bounded + ops::Add<Output = V> + ops::Sub<Output = V>,
+{
+    const KIND: &'static str = "invert";
+    type Attr = InvertAttr<Inner::Attr, V>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(Self::Attr {
+            inner: builder.bound_attr("inner", self.inner, bounds).await?,
+            bounds,
+        });
+    }
+}
+
+pub struct InvertAttr<Inner, V> {
+    inner: Inner,
+    bounds: Bounds<V>,
+}
+
+impl<Inner, V> Attr<V> for InvertAttr<Inner, V>
+where
+    Inner: Attr<V>,
+    V: AttrValue + ops::Add<Output = V> + ops::Sub<Output = V>,
+{
+    fn update(&mut self, ctx: &RenderContext) -> V {
+        return self.bounds.min + self.bounds.max - self.inner.update(ctx);
+    }
+}
+
+/// Rounds an attribute to multiples of a step.
+///
+/// If used as bound attribute, the steps start at the lower bound and the result is clamped to the bounds.
+#[derive(Debug)]
+pub struct Quantize<Inner, V> {
+    inner: Inner,
+    step: V,
+}
+
+impl<Inner, V> FreeAttrDecl<V> for Quantize<Inner, V>
+where
+    Inner: FreeAttrDecl<V>,
+    V: AttrValue + Float,
+{
+    const KIND: &'static str = "quantize";
+    type Attr = QuantizeAttr<Inner::Attr, V>;
+
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(Self::Attr {
+            inner: builder.unbound_attr("inner", self.inner).await?,
+            step: self.step,
+            origin: V::zero(),
+        });
+    }
+}
+
+impl<Inner, V> BoundAttrDecl<V> for Quantize<Inner, V>
+where
+    Inner: BoundAttrDecl<V>,
+    V: AttrValue + Bounded + Float,
+{
+    const KIND: &'static str = "quantize";
+    type Attr = ClampAttr<QuantizeAttr<Inner::Attr, V>, V>;
+
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return Ok(Self::Attr {
+            inner: QuantizeAttr {
+                inner: builder.bound_attr("inner", self.inner, bounds).await?,
+                step: self.step,
+                origin: bounds.min,
+            },
+            bounds,
+        });
+    }
+}
+
+pub struct QuantizeAttr<Inner, V> {
+    inner: Inner,
+    step: V,
+    origin: V,
+}
+
+impl<Inner, V> Attr<V> for QuantizeAttr<Inner, V>
+where
+    Inner: Attr<V>,
+    V: AttrValue + Float,
+{
+    fn update(&mut self, ctx: &RenderContext) -> V {
+        let value = self.inner.update(ctx);
+
+        if self.step <= V::zero() {
+            return value;
+        }
+
+        return self.origin + ((value - self.origin) / self.step).round() * self.step;
+    }
+}
@@ -39,7 +39,7 @@ where V: AttrValue
 
     type Attr = FixedAttr<V>;
 
-    fn materialize(self, _builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, _builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         return Ok(FixedAttr(self.0));
     }
 }
@@ -51,7 +51,7 @@ where V: AttrValue + Bounded
 
     type Attr = FixedAttr<V>;
 
-    fn materialize(self, bounds: Bounds<V>, _builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<V>, _builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         let value = bounds.ensure(self.0)?;
         return Ok(FixedAttr(value));
     }
@@ -0,0 +1,332 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, ensure, Result};
+use palette::rgb::Rgb;
+use palette::{FromColor, Hsv, Mix, Oklab, Oklch, Srgb};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::attr::Range;
+use crate::math::Lerp;
+
+/// Maximum number of stops in a gradient.
+///
+/// Gradients are values and must be `Copy` - so the stops are kept inline.
+pub const MAX_STOPS: usize = 16;
+
+/// The color space used to interpolate between the stops of a gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradientSpace {
+    #[default]
+    Srgb,
+    Linear,
+    Hsv,
+    Oklab,
+    Oklch,
+}
+
+impl GradientSpace {
+    pub fn mix(self, a: Rgb, b: Rgb, i: f32) -> Rgb {
+        return match self {
+            Self::Srgb => a.mix(b, i),
+            Self::Linear => Rgb::from_linear(a.into_linear().mix(b.into_linear(), i)),
+            Self::Hsv => Rgb::from_color(Hsv::from_color(a).mix(Hsv::from_color(b), i)),
+            Self::Oklab => Rgb::from_color(Oklab::from_color(a).mix(Oklab::from_color(b), i)),
+            Self::Oklch => Rgb::from_color(Oklch::from_color(a).mix(Oklch::from_color(b), i)),
+        };
+    }
+}
+
+impl fmt::Display for GradientSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f.write_str(match self {
+            Self::Srgb => "srgb",
+            Self::Linear => "linear",
+            Self::Hsv => "hsv",
+            Self::Oklab => "oklab",
+            Self::Oklch => "oklch",
+        });
+    }
+}
+
+impl FromStr for GradientSpace {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return Ok(match s.trim() {
+            "srgb" => Self::Srgb,
+            "linear" => Self::Linear,
+            "hsv" => Self::Hsv,
+            "oklab" => Self::Oklab,
+            "oklch" => Self::Oklch,
+            s => bail!("Unknown gradient space: {s}"),
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stop {
+    /// Position of the stop in `[0, 1]`
+    pub position: f32,
+
+    pub color: Rgb,
+}
+
+/// A color gradient with multiple stops.
+///
+/// Between the stops, colors are interpolated in the color space of the gradient. Before the first and after the last
+/// stop, the color of that stop is used.
+#[derive(Debug, Clone, Copy)]
+pub struct Gradient {
+    stops: [Stop; MAX_STOPS],
+    len: usize,
+
+    space: GradientSpace,
+}
+
+impl Gradient {
+    /// Creates a gradient from the given stops.
+    ///
+    /// The stops are ordered by their position.
+    pub fn new(stops: impl IntoIterator<Item = Stop>) -> Result<Self> {
+        let mut gradient = Self {
+            stops: [Stop {
+                position: 0.0,
+                color: Rgb::new(0.0, 0.0, 0.0),
+            }; MAX_STOPS],
+            len: 0,
+            space: GradientSpace::default(),
+        };
+
+        for stop in stops {
+            ensure!(gradient.len < MAX_STOPS, "Gradient exceeds {MAX_STOPS} stops");
+            ensure!((0.0..=1.0).contains(&stop.position), "Gradient stop outside of [0, 1]: {}", stop.position);
+
+            gradient.stops[gradient.len] = stop;
+            gradient.len += 1;
+        }
+
+        ensure!(gradient.len > 0, "Gradient must have at least one stop");
+
+        gradient.stops[..gradient.len].sort_by(|a, b| a.position.total_cmp(&b.position));
+
+        return Ok(gradient);
+    }
+
+    /// Creates a gradient with the colors evenly distributed.
+    pub fn evenly(colors: impl IntoIterator<Item = Rgb>) -> Result<Self> {
+        let colors = colors.into_iter().collect::<Vec<_>>();
+        let steps = colors.len().saturating_sub(1).max(1) as f32;
+
+        return Self::new(colors.into_iter().enumerate().map(|(i, color)| Stop {
+            position: i as f32 / steps,
+            color,
+        }));
+    }
+
+    pub fn with_space(mut self, space: GradientSpace) -> Self {
+        self.space = space;
+        return self;
+    }
+
+    pub fn stops(&self) -> &[Stop] {
+        return &self.stops[..self.len];
+    }
+
+    pub fn space(&self) -> GradientSpace {
+        return self.space;
+    }
+
+    /// Returns the color at the given position in `[0, 1]`.
+    pub fn sample(&self, position: f32) -> Rgb {
+        let stops = self.stops();
+
+        let next = stops.partition_point(|stop| stop.position <= position);
+
+        if next == 0 {
+            return stops[0].color;
+        }
+
+        if next == stops.len() {
+            return stops[next - 1].color;
+        }
+
+        let (curr, next) = (stops[next - 1], stops[next]);
+
+        let i = (position - curr.position) / (next.position - curr.position);
+        return self.space.mix(curr.color, next.color, i);
+    }
+
+    /// Returns `count` colors sampled evenly from start to end.
+    pub fn samples(&self, count: usize) -> impl Iterator<Item = Rgb> + '_ {
+        let steps = count.saturating_sub(1).max(1) as f32;
+        return (0..count).map(move |i| self.sample(i as f32 / steps));
+    }
+}
+
+impl From<Range<Rgb>> for Gradient {
+    fn from(range: Range<Rgb>) -> Self {
+        return Self::evenly([range.0, range.1]).expect("Two stops");
+    }
+}
+
+impl PartialEq for Gradient {
+    fn eq(&self, other: &Self) -> bool {
+        return self.space == other.space && self.stops() == other.stops();
+    }
+}
+
+impl Lerp for Gradient {
+    /// Blends two gradients by sampling both at the union of their stop positions.
+    ///
+    /// If the union exceeds the maximum number of stops, both gradients are sampled at evenly distributed positions.
+    fn lerp(a: Self, b: Self, i: f32) -> Self {
+        let space = if i < 0.5 { a.space } else { b.space };
+
+        let mut positions = a.stops().iter().chain(b.stops()).map(|stop| stop.position).collect::<Vec<_>>();
+        positions.sort_by(f32::total_cmp);
+        positions.dedup();
+
+        if positions.len() > MAX_STOPS {
+            positions = (0..MAX_STOPS).map(|i| i as f32 / (MAX_STOPS - 1) as f32).collect();
+        }
+
+        let stops = positions.into_iter().map(|position| Stop {
+            position,
+            color: space.mix(a.sample(position), b.sample(position), i),
+        });
+
+        return Self::new(stops).expect("Valid stops").with_space(space);
+    }
+}
+
+impl fmt::Display for Gradient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.space != GradientSpace::default() {
+            write!(f, "{}:", self.space)?;
+        }
+
+        for (i, stop) in self.stops().iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+
+            write!(f, "#{:x}@{}", stop.color.into_format::<u8>(), stop.position)?;
+        }
+
+        return Ok(());
+    }
+}
+
+/// Parses a gradient from a comma separated list of stops like `oklab:#ff0000,#00ff00@0.25,#0000ff`.
+///
+/// The color space prefix is optional. Stops without a position are distributed evenly between their neighbours, with
+/// the first and last stop defaulting to the start and end of the gradient.
+impl FromStr for Gradient {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (space, s) = match s.split_once(':') {
+            Some((space, s)) => (space.parse()?, s),
+            None => (GradientSpace::default(), s),
+        };
+
+        let stops = s
+            .split(',')
+            .map(|stop| {
+                let (color, position) = match stop.split_once('@') {
+                    Some((color, position)) => (color, Some(position.trim().parse::<f32>()?)),
+                    None => (stop, None),
+                };
+
+                let color = color.trim().parse::<Srgb<u8>>()?.into_format::<f32>();
+
+                return Ok((color, position));
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut positions = stops.iter().map(|(_, position)| *position).collect::<Vec<_>>();
+
+        if let Some(first) = positions.first_mut() {
+            first.get_or_insert(0.0);
+        }
+
+        if let Some(last) = positions.last_mut() {
+            last.get_or_insert(1.0);
+        }
+
+        // Distribute missing positions between the known ones
+        let mut start = 0;
+        for end in 1..positions.len() {
+            let Some(to) = positions[end] else {
+                continue;
+            };
+
+            let from = positions[start].expect("Known position");
+            for (i, position) in positions[start + 1..end].iter_mut().enumerate() {
+                *position = Some(from + (to - from) * (i + 1) as f32 / (end - start) as f32);
+            }
+
+            start = end;
+        }
+
+        let stops = stops.into_iter().zip(positions).map(|((color, _), position)| Stop {
+            position: position.expect("Distributed position"),
+            color,
+        });
+
+        return Ok(Self::new(stops)?.with_space(space));
+    }
+}
+
+impl<'de> Deserialize<'de> for Gradient {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        let value = String::deserialize(deserializer)?;
+        return value.parse().map_err(serde::de::Error::custom);
+    }
+}
+
+impl Serialize for Gradient {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        return serializer.collect_str(self);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_distributes_positions() {
+        let gradient: Gradient = "oklab:#ff0000,#00ff00,#0000ff@0.5,#ffffff".parse().unwrap();
+
+        assert_eq!(gradient.space(), GradientSpace::Oklab);
+        assert_eq!(gradient.stops().iter().map(|stop| stop.position).collect::<Vec<_>>(), vec![0.0, 0.25, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        let gradient: Gradient = "hsv:#ff0000@0,#00ff00@0.25,#0000ff@1".parse().unwrap();
+        assert_eq!(gradient.to_string().parse::<Gradient>().unwrap(), gradient);
+    }
+
+    #[test]
+    fn sample_holds_ends() {
+        let gradient: Gradient = "#ff0000@0.25,#0000ff@0.75".parse().unwrap();
+
+        assert_eq!(gradient.sample(0.0), Rgb::new(1.0, 0.0, 0.0));
+        assert_eq!(gradient.sample(0.5), Rgb::new(0.5, 0.0, 0.5));
+        assert_eq!(gradient.sample(1.0), Rgb::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn lerp_ends() {
+        let a: Gradient = "#ff0000,#00ff00".parse().unwrap();
+        let b: Gradient = "#0000ff,#ffffff@0.5,#000000".parse().unwrap();
+
+        assert_eq!(Gradient::lerp(a, b, 0.0).sample(0.5), a.sample(0.5));
+        assert_eq!(Gradient::lerp(a, b, 1.0).sample(0.5), b.sample(0.5));
+    }
+}
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use palette::num::{One, Zero};
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::str::FromStr;
 
@@ -116,3 +116,12 @@ where V: Deserialize<'de>
         return Ok(Range::new(a, b));
     }
 }
+
+impl<V> Serialize for Range<V>
+where V: Serialize
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        return (&self.0, &self.1).serialize(serializer);
+    }
+}
@@ -1,9 +1,11 @@
+use std::time::Duration;
+
 use palette::{
     Hsl, Hsla, Hsluv, Hsluva, Hsv, Hsva, Hwb, Hwba, Lab, Laba, Lch, Lcha, Lchuv, Lchuva, Luv, Luva, Okhsl, Okhsla,
     Okhsv, Okhsva, Okhwb, Okhwba, Oklab, Oklaba, Oklch, Oklcha, Srgb, Srgba, Xyz, Xyza, Yxy, Yxya,
 };
 
-use super::{AttrValueType, Range};
+use super::{AttrValueType, Gradient, Range};
 
 pub trait AttrValue: Send + Copy + PartialEq + 'static {
     const TYPE: AttrValueType;
@@ -15,6 +17,10 @@ where V: AttrValue
     const TYPE: AttrValueType = AttrValueType::Range(&V::TYPE);
 }
 
+impl AttrValue for Gradient {
+    const TYPE: AttrValueType = AttrValueType::Gradient;
+}
+
 impl AttrValue for ! {
     const TYPE: AttrValueType = AttrValueType::Never;
 }
@@ -36,6 +42,7 @@ macro_rules! attr_value {
 attr_value!(Boolean => bool);
 attr_value!(Integer => i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
 attr_value!(Decimal => f32, f64);
+attr_value!(Duration => Duration);
 attr_value!(Color => Srgb, Srgba);
 attr_value!(Color => Hsv, Hsva);
 attr_value!(Color => Hsl, Hsla, Hsluv, Hsluva);
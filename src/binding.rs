@@ -0,0 +1,157 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+
+use crate::input::{Input, InputSink, InputValueType};
+use crate::interface::Introspection;
+use crate::InputInfo;
+
+/// A target of a binding.
+///
+/// Values received by the binding are transformed for each target: The value is clamped to `[0.0, 1.0]` and inverted
+/// first, then shaped by the curve and finally scaled and offset.
+#[derive(Debug, Clone)]
+pub struct Target {
+    /// The name of the input to drive
+    pub input: String,
+
+    pub scale: f32,
+    pub offset: f32,
+
+    pub invert: bool,
+
+    /// Exponent applied to the value - `1.0` is linear, greater values make the curve steeper towards the end
+    pub curve: f32,
+}
+
+impl Target {
+    pub fn new(input: impl Into<String>) -> Self {
+        return Self {
+            input: input.into(),
+            scale: 1.0,
+            offset: 0.0,
+            invert: false,
+            curve: 1.0,
+        };
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        return self;
+    }
+
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        return self;
+    }
+
+    pub fn with_invert(mut self, invert: bool) -> Self {
+        self.invert = invert;
+        return self;
+    }
+
+    pub fn with_curve(mut self, curve: f32) -> Self {
+        self.curve = curve;
+        return self;
+    }
+
+    pub fn apply(&self, value: f32) -> f32 {
+        let value = value.clamp(0.0, 1.0);
+        let value = if self.invert { 1.0 - value } else { value };
+        let value = value.powf(self.curve);
+        return value * self.scale + self.offset;
+    }
+}
+
+/// A virtual decimal input fanning out to other inputs.
+///
+/// The binding is declared as an ordinary input in the scene and is therefore visible to all interfaces. Every value
+/// sent to the binding is transformed and forwarded to all of its targets.
+#[derive(Debug)]
+pub(crate) struct Binding {
+    pub name: String,
+
+    pub input: Input<f32>,
+    pub targets: Vec<Target>,
+}
+
+impl Binding {
+    /// Resolves the targets of the binding and returns the future running the fan-out.
+    pub fn bind(self, introspection: &Introspection) -> Result<impl Future<Output = Result<()>> + 'static> {
+        let Binding {
+            name,
+            mut input,
+            targets,
+        } = self;
+
+        let targets = targets
+            .into_iter()
+            .map(|target| {
+                let Some(info) = introspection.inputs.get(&target.input) else {
+                    bail!("Unknown target input of binding {}: {}", name, target.input);
+                };
+
+                if info.value_type() != InputValueType::Decimal {
+                    bail!("Target input of binding {} must be decimal: {} ({})", name, target.input, info.value_type());
+                }
+
+                return Ok((target, info.clone()));
+            })
+            .collect::<Result<Vec<(Target, Arc<InputInfo>)>>>()?;
+
+        eprintln!(
+            "🔗 Bound {} to {}",
+            name,
+            targets.iter().map(|(target, _)| target.input.as_str()).collect::<Vec<_>>().join(", ")
+        );
+
+        return Ok(async move {
+            loop {
+                let value = input.recv().await;
+
+                for (target, info) in targets.iter() {
+                    let InputSink::Decimal(sink) = info.sink() else {
+                        unreachable!("Checked before");
+                    };
+
+                    if let Err(err) = sink.send(target.apply(value)).await {
+                        eprintln!("🔗 Failed to send binding {} to input '{}': {}", name, target.input, err);
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Scene;
+
+    use super::*;
+
+    #[test]
+    fn apply_clamps_independent_of_curve() {
+        let linear = Target::new("x").with_scale(10.0);
+        let curved = Target::new("x").with_scale(10.0).with_curve(2.0);
+
+        assert_eq!(linear.apply(1.5), 10.0);
+        assert_eq!(curved.apply(1.5), 10.0);
+        assert_eq!(linear.apply(-0.5), 0.0);
+        assert_eq!(curved.apply(-0.5), 0.0);
+
+        assert_eq!(Target::new("x").with_invert(true).apply(0.25), 0.75);
+        assert_eq!(Target::new("x").with_offset(1.0).apply(0.5), 1.5);
+    }
+
+    #[test]
+    fn binding_name_is_unique() {
+        let mut scene = Scene::new();
+        scene.input::<f32>("a").unwrap();
+        assert!(scene.binding("a", [Target::new("b")]).is_err());
+
+        let mut scene = Scene::new();
+        scene.binding("a", [Target::new("b")]).unwrap();
+        assert!(scene.input::<f32>("a").is_err());
+    }
+}
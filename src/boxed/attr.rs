@@ -1,24 +1,27 @@
 use anyhow::Result;
+use async_trait::async_trait;
 
 use crate::attr::{Bounded, Bounds};
 use crate::{scene, Attr, AttrBuilder, AttrValue, BoundAttrDecl, FreeAttrDecl};
 
 use super::Boxed;
 
+#[async_trait(? Send)]
 pub trait DynFreeAttrDecl<V>
 where V: AttrValue
 {
-    fn materialize(self: Box<Self>, builder: &mut AttrBuilder) -> Result<BoxedAttr<V>>;
+    async fn materialize(self: Box<Self>, builder: &mut AttrBuilder<'_>) -> Result<BoxedAttr<V>>;
 }
 
+#[async_trait(? Send)]
 impl<T, V> DynFreeAttrDecl<V> for T
 where
     T: FreeAttrDecl<V>,
     <T as FreeAttrDecl<V>>::Attr: DynAttr<V> + Sized + 'static,
     V: AttrValue,
 {
-    fn materialize(self: Box<Self>, builder: &mut AttrBuilder) -> Result<BoxedAttr<V>> {
-        let attr = <T as FreeAttrDecl<V>>::materialize(*self, builder)?;
+    async fn materialize(self: Box<Self>, builder: &mut AttrBuilder<'_>) -> Result<BoxedAttr<V>> {
+        let attr = <T as FreeAttrDecl<V>>::materialize(*self, builder).await?;
         return Ok(Box::new(attr));
     }
 }
@@ -43,25 +46,27 @@ where V: AttrValue
 
     type Attr = BoxedAttr<V>;
 
-    fn materialize(self, builder: &mut AttrBuilder) -> Result<Self::Attr> {
-        return DynFreeAttrDecl::materialize(self, builder);
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return DynFreeAttrDecl::materialize(self, builder).await;
     }
 }
 
+#[async_trait(? Send)]
 pub trait DynBoundAttrDecl<V>
 where V: AttrValue
 {
-    fn materialize(self: Box<Self>, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<BoxedAttr<V>>;
+    async fn materialize(self: Box<Self>, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<BoxedAttr<V>>;
 }
 
+#[async_trait(? Send)]
 impl<T, V> DynBoundAttrDecl<V> for T
 where
     T: BoundAttrDecl<V>,
     <T as BoundAttrDecl<V>>::Attr: DynAttr<V> + Sized + 'static,
     V: AttrValue + Bounded,
 {
-    fn materialize(self: Box<Self>, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<BoxedAttr<V>> {
-        let attr = <T as BoundAttrDecl<V>>::materialize(*self, bounds, builder)?;
+    async fn materialize(self: Box<Self>, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<BoxedAttr<V>> {
+        let attr = <T as BoundAttrDecl<V>>::materialize(*self, bounds, builder).await?;
         return Ok(Box::new(attr));
     }
 }
@@ -86,8 +91,8 @@ where V: AttrValue + Bounded
 
     type Attr = BoxedAttr<V>;
 
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<Self::Attr> {
-        return DynBoundAttrDecl::materialize(self, bounds, builder);
+    async fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
+        return DynBoundAttrDecl::materialize(self, bounds, builder).await;
     }
 }
 
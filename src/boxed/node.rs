@@ -4,7 +4,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use palette::convert::{FromColorUnclamped, IntoColorUnclamped};
 
-use crate::{Buffer, BufferReader, Node, NodeBuilder, NodeDecl, RenderContext};
+use crate::{Buffer, BufferReader, Node, NodeBuilder, NodeDecl, Opacity, RenderContext};
 
 use super::Boxed;
 
@@ -17,7 +17,7 @@ pub trait DynNodeDecl<E> {
 impl<T, E> DynNodeDecl<E> for T
 where
     T: NodeDecl + 'static,
-    E: Default + Copy + FromColorUnclamped<<<T as NodeDecl>::Node as Node>::Element> + 'static,
+    E: Default + Copy + Opacity + FromColorUnclamped<<<T as NodeDecl>::Node as Node>::Element> + 'static,
 {
     async fn materialize(self: Box<Self>, builder: &mut NodeBuilder<'_>) -> Result<BoxedNode<E>> {
         let node = <T as NodeDecl>::materialize(*self, builder).await?;
@@ -34,7 +34,7 @@ where
 impl<T, E> Boxed<dyn DynNodeDecl<E>> for T
 where
     T: NodeDecl + 'static,
-    E: Default + Copy + FromColorUnclamped<<<T as NodeDecl>::Node as Node>::Element> + 'static,
+    E: Default + Copy + Opacity + FromColorUnclamped<<<T as NodeDecl>::Node as Node>::Element> + 'static,
 {
     fn boxed(self) -> Box<dyn DynNodeDecl<E>> {
         return Box::new(self);
@@ -44,7 +44,7 @@ where
 pub type BoxedNodeDecl<E> = Box<dyn DynNodeDecl<E>>;
 
 impl<E> NodeDecl for BoxedNodeDecl<E>
-where E: Default + Copy
+where E: Default + Copy + Opacity
 {
     const KIND: &'static str = "boxed";
 
@@ -58,7 +58,7 @@ where E: Default + Copy
 struct WrappedNode<N, E>
 where
     N: Node,
-    E: Default + Copy + FromColorUnclamped<<N as Node>::Element>,
+    E: Default + Copy + Opacity + FromColorUnclamped<<N as Node>::Element>,
 {
     node: N,
     buffer: Buffer<<N as Node>::Element>,
@@ -68,14 +68,23 @@ where
 impl<N, E> Node for WrappedNode<N, E>
 where
     N: Node,
-    E: Default + Copy + FromColorUnclamped<<N as Node>::Element>,
+    E: Default + Copy + Opacity + FromColorUnclamped<<N as Node>::Element>,
 {
     type Element = E;
 
     fn update(&mut self, ctx: &RenderContext, out: &mut Buffer<Self::Element>) -> Result<()> {
         self.node.update(ctx, &mut self.buffer)?;
 
-        out.blit_from(self.buffer.map(|e| e.into_color_unclamped()));
+        out.blit_from(self.buffer.map(|e| {
+            let target: E = e.into_color_unclamped();
+
+            // Flatten the alpha channel if the target can not hold it
+            if target.opacity() != e.opacity() {
+                return e.opaque().into_color_unclamped();
+            }
+
+            return target;
+        }));
         return Ok(());
     }
 }
@@ -87,7 +96,7 @@ pub trait DynNode<E> {
 impl<N, E> DynNode<E> for WrappedNode<N, E>
 where
     N: Node,
-    E: Default + Copy + FromColorUnclamped<<N as Node>::Element>,
+    E: Default + Copy + Opacity + FromColorUnclamped<<N as Node>::Element>,
 {
     fn update(&mut self, ctx: &RenderContext, out: &mut Buffer<E>) -> Result<()> {
         return Node::update(self, ctx, out);
@@ -97,7 +106,7 @@ where
 pub type BoxedNode<E> = Box<dyn DynNode<E>>;
 
 impl<E> Node for BoxedNode<E>
-where E: Default + Copy
+where E: Default + Copy + Opacity
 {
     type Element = E;
 
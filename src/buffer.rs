@@ -1,7 +1,7 @@
 use std::ops::{Deref, DerefMut, Range};
 
 use crate::math::Lerp;
-use crate::{Node, NodeBuilder, NodeDecl, RenderContext};
+use crate::{Node, NodeBuilder, NodeDecl, Opacity, RenderContext};
 use anyhow::Result;
 
 mod imap;
@@ -224,7 +224,7 @@ impl<E> BufferReader for Slice<'_, E> {
 }
 
 impl<E> NodeDecl for Buffer<E>
-where E: Default + Copy
+where E: Default + Copy + Opacity
 {
     const KIND: &'static str = "buffer";
     type Node = Self;
@@ -235,7 +235,7 @@ where E: Default + Copy
 }
 
 impl<E> Node for Buffer<E>
-where E: Default + Copy
+where E: Default + Copy + Opacity
 {
     type Element = E;
 
@@ -1,3 +1,4 @@
+pub mod alpha;
 pub mod rgbw;
 
 pub use palette;
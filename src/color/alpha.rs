@@ -0,0 +1,150 @@
+use palette::luma::Luma;
+use palette::rgb::Rgb;
+use palette::{Alpha, FromColor, Hsl, Hsv, Hwb, IntoColor, Lab, Lch, Oklab, Oklch, Xyz};
+
+use crate::Rgbw;
+
+/// Access to the alpha channel of node elements.
+///
+/// Elements without an alpha channel are fully opaque. Elements with an alpha channel can be flattened to opaque
+/// elements by compositing them onto black.
+///
+/// All node elements must implement this trait. The provided methods treat the element as fully opaque, so custom
+/// element types without an alpha channel only need an empty implementation:
+///
+/// ```
+/// # use photonic::Opacity;
+/// #[derive(Clone, Copy, Default)]
+/// struct Level(f32);
+///
+/// impl Opacity for Level {}
+/// ```
+pub trait Opacity: Sized {
+    /// The opacity of the element in `[0, 1]`.
+    fn opacity(&self) -> f32 {
+        return 1.0;
+    }
+
+    /// Replaces the opacity of the element.
+    ///
+    /// This has no effect on elements without an alpha channel.
+    fn with_opacity(self, _opacity: f32) -> Self {
+        return self;
+    }
+
+    /// Composites the element onto black, resulting in a fully opaque element.
+    fn opaque(self) -> Self {
+        return self;
+    }
+}
+
+macro_rules! impl_opaque {
+    ($($ty:ident<$($param:ident),*>),* $(,)?) => {
+        $(
+            impl<$($param),*> Opacity for $ty<$($param),*> {}
+        )*
+    };
+}
+
+impl_opaque!(
+    Rgb<S, T>,
+    Luma<S, T>,
+    Hsl<S, T>,
+    Hsv<S, T>,
+    Hwb<S, T>,
+    Lab<Wp, T>,
+    Lch<Wp, T>,
+    Xyz<Wp, T>,
+    Oklab<T>,
+    Oklch<T>,
+    Rgbw<S, T>,
+);
+
+impl<C> Opacity for Alpha<C, f32>
+where C: IntoColor<Rgb> + FromColor<Rgb> + Copy
+{
+    fn opacity(&self) -> f32 {
+        return self.alpha;
+    }
+
+    fn with_opacity(self, opacity: f32) -> Self {
+        return Alpha {
+            color: self.color,
+            alpha: opacity,
+        };
+    }
+
+    fn opaque(self) -> Self {
+        let rgb: Rgb = self.color.into_color();
+        return Alpha {
+            color: C::from_color(rgb * self.alpha),
+            alpha: 1.0,
+        };
+    }
+}
+
+impl<S> Opacity for Alpha<Rgb<S, u8>, u8> {
+    fn opacity(&self) -> f32 {
+        return self.alpha as f32 / 255.0;
+    }
+
+    fn with_opacity(self, opacity: f32) -> Self {
+        return Alpha {
+            color: self.color,
+            alpha: (opacity.clamp(0.0, 1.0) * 255.0).round() as u8,
+        };
+    }
+
+    fn opaque(self) -> Self {
+        let scale = |c: u8| (c as u16 * self.alpha as u16 / 255) as u8;
+        return Alpha {
+            color: Rgb::new(scale(self.color.red), scale(self.color.green), scale(self.color.blue)),
+            alpha: u8::MAX,
+        };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use palette::{Hsv, Srgba};
+
+    use super::*;
+
+    #[test]
+    fn opaque_elements() {
+        let color: Rgb = Rgb::new(0.5, 0.25, 1.0);
+
+        assert_eq!(color.opacity(), 1.0);
+        assert_eq!(color.with_opacity(0.5), color);
+        assert_eq!(color.opaque(), color);
+    }
+
+    #[test]
+    fn flatten_onto_black() {
+        let color = Srgba::new(0.5, 0.25, 1.0, 0.5);
+
+        assert_eq!(color.opacity(), 0.5);
+        assert_eq!(color.opaque(), Srgba::new(0.25, 0.125, 0.5, 1.0));
+        assert_eq!(color.with_opacity(1.0), Srgba::new(0.5, 0.25, 1.0, 1.0));
+    }
+
+    #[test]
+    fn flatten_integer_components() {
+        let color = Srgba::<u8>::new(255, 128, 64, 128);
+
+        assert!((color.opacity() - 0.5).abs() < 1e-2);
+        assert_eq!(color.opaque(), Srgba::new(128, 64, 32, 255));
+        assert_eq!(color.with_opacity(1.0), Srgba::new(255, 128, 64, 255));
+    }
+
+    #[test]
+    fn flatten_other_spaces() {
+        let color = Alpha {
+            color: Hsv::new(120.0, 1.0, 1.0),
+            alpha: 0.5,
+        };
+
+        let flat: Rgb = color.opaque().color.into_color();
+        assert!((flat.green - 0.5).abs() < 1e-6);
+    }
+}
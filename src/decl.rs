@@ -1,5 +1,5 @@
 use anyhow::Result;
-use std::future::Future;
+use std::future::{self, Future};
 
 use crate::attr::{Bounded, Bounds};
 use crate::{Attr, AttrBuilder, AttrValue, Node, NodeBuilder, Output};
@@ -25,7 +25,7 @@ pub trait FreeAttrDecl<V: AttrValue> {
 
     type Attr: Attr<V>;
 
-    fn materialize(self, builder: &mut AttrBuilder) -> Result<Self::Attr>;
+    fn materialize(self, builder: &mut AttrBuilder) -> impl Future<Output = Result<Self::Attr>>;
 }
 
 pub trait BoundAttrDecl<V: AttrValue + Bounded> {
@@ -33,7 +33,7 @@ pub trait BoundAttrDecl<V: AttrValue + Bounded> {
 
     type Attr: Attr<V>;
 
-    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> Result<Self::Attr>;
+    fn materialize(self, bounds: Bounds<V>, builder: &mut AttrBuilder) -> impl Future<Output = Result<Self::Attr>>;
 }
 
 #[allow(unreachable_code)]
@@ -44,8 +44,8 @@ where V: AttrValue
 
     type Attr = !;
 
-    fn materialize(self, _builder: &mut AttrBuilder) -> Result<Self::Attr> {
-        return self;
+    fn materialize(self, _builder: &mut AttrBuilder) -> impl Future<Output = Result<Self::Attr>> {
+        return future::ready(Ok(self));
     }
 }
 
@@ -57,7 +57,7 @@ where V: AttrValue + Bounded
 
     type Attr = !;
 
-    fn materialize(self, _bounds: Bounds<V>, _builder: &mut AttrBuilder) -> Result<Self::Attr> {
-        return self;
+    fn materialize(self, _bounds: Bounds<V>, _builder: &mut AttrBuilder) -> impl Future<Output = Result<Self::Attr>> {
+        return future::ready(Ok(self));
     }
 }
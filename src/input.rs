@@ -3,11 +3,11 @@ use std::task::Context;
 
 use anyhow::Result;
 use futures::Future;
-use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
 
-pub use self::sink::{AnyInputValue, InputSink, Sink};
+pub use self::sink::{AnyInputValue, InputSink, InputValueDecl, Sink};
 pub use self::trigger::Trigger;
-pub use self::values::Coerced;
+pub use self::values::{Coerced, InvalidVariant, Variants};
 
 mod attr;
 mod sink;
@@ -21,9 +21,11 @@ pub enum InputValueType {
     Integer,
     Decimal,
     Color,
+    Duration,
     IntegerRange,
     DecimalRange,
     ColorRange,
+    Gradient,
 }
 
 impl std::fmt::Display for InputValueType {
@@ -34,9 +36,11 @@ impl std::fmt::Display for InputValueType {
             Self::Integer => f.write_str("integer"),
             Self::Decimal => f.write_str("decimal"),
             Self::Color => f.write_str("color"),
+            Self::Duration => f.write_str("duration"),
             Self::IntegerRange => f.write_str("range<integer>"),
             Self::DecimalRange => f.write_str("range<decimal>"),
             Self::ColorRange => f.write_str("range<color>"),
+            Self::Gradient => f.write_str("gradient"),
         };
     }
 }
@@ -75,6 +79,8 @@ where V: InputValue
 
     value_tx: broadcast::Sender<V>,
     value_rx: broadcast::Receiver<V>,
+
+    current_tx: watch::Sender<Option<V>>,
 }
 
 impl<V> Default for Input<V>
@@ -93,11 +99,14 @@ where V: InputValue
 
         let (value_tx, value_rx) = broadcast::channel(1);
 
+        let (current_tx, _) = watch::channel(None);
+
         return Self {
             update_tx,
             update_rx,
             value_tx,
             value_rx,
+            current_tx,
         };
     }
 
@@ -119,7 +128,7 @@ where V: InputValue
         match validate(value) {
             Ok(update) => {
                 let _ = responder.send(Ok(()));
-                let _ = self.value_tx.send(value);
+                self.publish(value);
                 return Poll::Update(update);
             }
             Err(err) => {
@@ -129,10 +138,50 @@ where V: InputValue
         }
     }
 
+    /// Waits for the next value sent to the input.
+    ///
+    /// This allows to consume an input outside of the render loop, i.e. by an interface. The value is accepted without
+    /// further validation.
+    pub async fn recv(&mut self) -> V {
+        let UpdateRequest {
+            value,
+            responder,
+        } = self.update_rx.recv().await.expect("Sender is owned by input");
+
+        let _ = responder.send(Ok(()));
+        self.publish(value);
+
+        return value;
+    }
+
+    /// Notifies all subscribers about a value.
+    ///
+    /// This allows the owner of a detached input to report its state.
+    pub fn publish(&self, value: V) {
+        self.current_tx.send_replace(Some(value));
+        let _ = self.value_tx.send(value);
+    }
+
+    /// Sets the current value of the input without notifying subscribers.
+    ///
+    /// This is used to report the initial value of an input before any value has been sent to it. Once the input has
+    /// a current value, the seed is ignored.
+    pub fn seed(&self, value: V) {
+        self.current_tx.send_if_modified(|current| {
+            if current.is_some() {
+                return false;
+            }
+
+            *current = Some(value);
+            return true;
+        });
+    }
+
     pub fn sink(&self) -> Sink<V> {
         return Sink {
             update_tx: self.update_tx.clone(),
             value_rx: self.value_rx.resubscribe(),
+            current_rx: self.current_tx.subscribe(),
         };
     }
 }
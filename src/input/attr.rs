@@ -21,10 +21,13 @@ where A: AttrValue + input::Coerced + Bounded
 
     type Attr = BoundInputAttr<A>;
 
-    fn materialize(self, bounds: Bounds<A>, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, bounds: Bounds<A>, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         let initial = bounds.ensure(self.initial)?;
 
         let input = builder.input("value", self.input)?;
+        if let Some(initial) = initial.to_input() {
+            input.seed(initial);
+        }
 
         return Ok(Self::Attr {
             bounds,
@@ -41,8 +44,11 @@ where A: AttrValue + input::Coerced
 
     type Attr = FreeInputAttr<A>;
 
-    fn materialize(self, builder: &mut AttrBuilder) -> Result<Self::Attr> {
+    async fn materialize(self, builder: &mut AttrBuilder<'_>) -> Result<Self::Attr> {
         let input = builder.input("value", self.input)?;
+        if let Some(initial) = self.initial.to_input() {
+            input.seed(initial);
+        }
 
         return Ok(Self::Attr {
             input,
@@ -1,15 +1,17 @@
 use std::pin::Pin;
+use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use futures::{Stream, StreamExt};
 use palette::rgb::Rgb;
-use tokio::sync::{broadcast, mpsc, oneshot};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
 use tokio_stream::wrappers::BroadcastStream;
 
-use crate::attr::Range;
+use crate::attr::{Gradient, Range};
 use crate::input::trigger::Trigger;
 
-use super::{InputValue, UpdateRequest};
+use super::{InputValue, InputValueType, UpdateRequest};
 
 #[derive(Debug)]
 pub struct Sink<V>
@@ -17,6 +19,7 @@ where V: InputValue
 {
     pub(super) update_tx: mpsc::Sender<UpdateRequest<V>>,
     pub(super) value_rx: broadcast::Receiver<V>,
+    pub(super) current_rx: watch::Receiver<Option<V>>,
 }
 
 impl<V> Sink<V>
@@ -39,6 +42,11 @@ where V: InputValue + Sync
         return BroadcastStream::new(self.value_rx.resubscribe()).filter_map(|r| async { r.ok() });
         // Ignore lagging errors
     }
+
+    /// The last value accepted by the input, or its initial value if nothing has been sent to it yet.
+    pub fn current(&self) -> Option<V> {
+        return *self.current_rx.borrow();
+    }
 }
 
 pub enum InputSink {
@@ -47,9 +55,11 @@ pub enum InputSink {
     Integer(Sink<i64>),
     Decimal(Sink<f32>),
     Color(Sink<Rgb>),
+    Duration(Sink<Duration>),
     IntegerRange(Sink<Range<i64>>),
     DecimalRange(Sink<Range<f32>>),
     ColorRange(Sink<Range<Rgb>>),
+    Gradient(Sink<Gradient>),
 }
 
 impl std::fmt::Debug for InputSink {
@@ -66,9 +76,11 @@ impl std::fmt::Display for InputSink {
             Self::Integer(_) => "integer",
             Self::Decimal(_) => "decimal",
             Self::Color(_) => "color",
+            Self::Duration(_) => "duration",
             Self::IntegerRange(_) => "range<integer>",
             Self::DecimalRange(_) => "range<decimal>",
             Self::ColorRange(_) => "range<color>",
+            Self::Gradient(_) => "gradient",
         });
     }
 }
@@ -82,6 +94,21 @@ where V: InputValue
 }
 
 impl InputSink {
+    pub fn value_type(&self) -> InputValueType {
+        return match self {
+            InputSink::Trigger(_) => InputValueType::Trigger,
+            InputSink::Boolean(_) => InputValueType::Boolean,
+            InputSink::Integer(_) => InputValueType::Integer,
+            InputSink::Decimal(_) => InputValueType::Decimal,
+            InputSink::Color(_) => InputValueType::Color,
+            InputSink::Duration(_) => InputValueType::Duration,
+            InputSink::IntegerRange(_) => InputValueType::IntegerRange,
+            InputSink::DecimalRange(_) => InputValueType::DecimalRange,
+            InputSink::ColorRange(_) => InputValueType::ColorRange,
+            InputSink::Gradient(_) => InputValueType::Gradient,
+        };
+    }
+
     pub fn subscribe(&self) -> impl Stream<Item = AnyInputValue> + Send + Unpin {
         let result: Pin<Box<dyn Stream<Item = _> + Send>> = match self {
             InputSink::Trigger(sink) => Box::pin(sink.subscribe().map(AnyInputValue::from)),
@@ -89,25 +116,148 @@ impl InputSink {
             InputSink::Integer(sink) => Box::pin(sink.subscribe().map(AnyInputValue::from)),
             InputSink::Decimal(sink) => Box::pin(sink.subscribe().map(AnyInputValue::from)),
             InputSink::Color(sink) => Box::pin(sink.subscribe().map(AnyInputValue::from)),
+            InputSink::Duration(sink) => Box::pin(sink.subscribe().map(AnyInputValue::from)),
             InputSink::IntegerRange(sink) => Box::pin(sink.subscribe().map(AnyInputValue::from)),
             InputSink::DecimalRange(sink) => Box::pin(sink.subscribe().map(AnyInputValue::from)),
             InputSink::ColorRange(sink) => Box::pin(sink.subscribe().map(AnyInputValue::from)),
+            InputSink::Gradient(sink) => Box::pin(sink.subscribe().map(AnyInputValue::from)),
         };
 
         return result;
     }
+
+    /// The last value accepted by the input, or its initial value if nothing has been sent to it yet.
+    pub fn current(&self) -> Option<AnyInputValue> {
+        return match self {
+            InputSink::Trigger(sink) => sink.current().map(AnyInputValue::from),
+            InputSink::Boolean(sink) => sink.current().map(AnyInputValue::from),
+            InputSink::Integer(sink) => sink.current().map(AnyInputValue::from),
+            InputSink::Decimal(sink) => sink.current().map(AnyInputValue::from),
+            InputSink::Color(sink) => sink.current().map(AnyInputValue::from),
+            InputSink::Duration(sink) => sink.current().map(AnyInputValue::from),
+            InputSink::IntegerRange(sink) => sink.current().map(AnyInputValue::from),
+            InputSink::DecimalRange(sink) => sink.current().map(AnyInputValue::from),
+            InputSink::ColorRange(sink) => sink.current().map(AnyInputValue::from),
+            InputSink::Gradient(sink) => sink.current().map(AnyInputValue::from),
+        };
+    }
+
+    /// Sends a value of any type to the input.
+    ///
+    /// Fails if the type of the value does not match the type of the input.
+    pub async fn send_any(&self, value: AnyInputValue) -> Result<()> {
+        return match (self, value) {
+            (InputSink::Trigger(sink), AnyInputValue::Trigger) => sink.send(Trigger::next()).await,
+            (InputSink::Boolean(sink), AnyInputValue::Boolean(value)) => sink.send(value).await,
+            (InputSink::Integer(sink), AnyInputValue::Integer(value)) => sink.send(value).await,
+            (InputSink::Decimal(sink), AnyInputValue::Decimal(value)) => sink.send(value).await,
+            (InputSink::Color(sink), AnyInputValue::Color(value)) => sink.send(value).await,
+            (InputSink::Duration(sink), AnyInputValue::Duration(value)) => sink.send(value).await,
+            (InputSink::IntegerRange(sink), AnyInputValue::IntegerRange(value)) => sink.send(value).await,
+            (InputSink::DecimalRange(sink), AnyInputValue::DecimalRange(value)) => sink.send(value).await,
+            (InputSink::ColorRange(sink), AnyInputValue::ColorRange(value)) => sink.send(value).await,
+            (InputSink::Gradient(sink), AnyInputValue::Gradient(value)) => sink.send(*value).await,
+            (sink, value) => bail!("Value type mismatch: expected {sink}, got {}", value.value_type()),
+        };
+    }
 }
 
-#[derive(Debug, Copy, Clone)]
+/// A value of any input type.
+///
+/// Values serialize untagged in their natural representation, so the type of deserialized data must be checked against
+/// the input it is sent to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
 pub enum AnyInputValue {
     Trigger,
     Boolean(bool),
     Integer(i64),
     Decimal(f32),
     Color(Rgb),
+    Duration(#[serde(with = "humantime_serde")] Duration),
     IntegerRange(Range<i64>),
     DecimalRange(Range<f32>),
     ColorRange(Range<Rgb>),
+    Gradient(Box<Gradient>),
+}
+
+impl AnyInputValue {
+    pub fn value_type(&self) -> InputValueType {
+        return match self {
+            AnyInputValue::Trigger => InputValueType::Trigger,
+            AnyInputValue::Boolean(_) => InputValueType::Boolean,
+            AnyInputValue::Integer(_) => InputValueType::Integer,
+            AnyInputValue::Decimal(_) => InputValueType::Decimal,
+            AnyInputValue::Color(_) => InputValueType::Color,
+            AnyInputValue::Duration(_) => InputValueType::Duration,
+            AnyInputValue::IntegerRange(_) => InputValueType::IntegerRange,
+            AnyInputValue::DecimalRange(_) => InputValueType::DecimalRange,
+            AnyInputValue::ColorRange(_) => InputValueType::ColorRange,
+            AnyInputValue::Gradient(_) => InputValueType::Gradient,
+        };
+    }
+
+    /// Parses a value of the given type from its textual representation.
+    ///
+    /// Colors are given as hex codes, durations in human readable form (like `1m 30s`). Trigger values ignore the
+    /// text.
+    pub fn parse(value_type: InputValueType, s: &str) -> Result<Self> {
+        let s = s.trim();
+
+        return Ok(match value_type {
+            InputValueType::Trigger => AnyInputValue::Trigger,
+            InputValueType::Boolean => AnyInputValue::Boolean(s.parse()?),
+            InputValueType::Integer => AnyInputValue::Integer(s.parse()?),
+            InputValueType::Decimal => AnyInputValue::Decimal(s.parse()?),
+            InputValueType::Color => AnyInputValue::Color(s.parse::<Rgb<_, u8>>()?.into_format()),
+            InputValueType::Duration => AnyInputValue::Duration(humantime::parse_duration(s)?),
+            InputValueType::IntegerRange => AnyInputValue::IntegerRange(s.parse()?),
+            InputValueType::DecimalRange => AnyInputValue::DecimalRange(s.parse()?),
+            InputValueType::ColorRange => {
+                AnyInputValue::ColorRange(s.parse::<Range<Rgb<_, u8>>>()?.map(Rgb::into_format))
+            }
+            InputValueType::Gradient => AnyInputValue::Gradient(Box::new(s.parse()?)),
+        });
+    }
+}
+
+/// An input value as written in a configuration file.
+///
+/// The type of the value is not known while deserializing, so the value is resolved against the type of the input it
+/// is sent to afterwards. Values not representable as plain scalars are given as text and parsed using
+/// [`AnyInputValue::parse`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum InputValueDecl {
+    Boolean(bool),
+    Integer(i64),
+    Decimal(f32),
+    Text(String),
+}
+
+impl InputValueDecl {
+    pub fn resolve(&self, value_type: InputValueType) -> Result<AnyInputValue> {
+        return Ok(match (value_type, self) {
+            (InputValueType::Trigger, _) => AnyInputValue::Trigger,
+            (InputValueType::Boolean, InputValueDecl::Boolean(value)) => AnyInputValue::Boolean(*value),
+            (InputValueType::Integer, InputValueDecl::Integer(value)) => AnyInputValue::Integer(*value),
+            (InputValueType::Decimal, InputValueDecl::Decimal(value)) => AnyInputValue::Decimal(*value),
+            (InputValueType::Decimal, InputValueDecl::Integer(value)) => AnyInputValue::Decimal(*value as f32),
+            (value_type, InputValueDecl::Text(value)) => AnyInputValue::parse(value_type, value)?,
+            (value_type, value) => bail!("Invalid value for {value_type}: {value}"),
+        });
+    }
+}
+
+impl std::fmt::Display for InputValueDecl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            InputValueDecl::Boolean(value) => write!(f, "{value}"),
+            InputValueDecl::Integer(value) => write!(f, "{value}"),
+            InputValueDecl::Decimal(value) => write!(f, "{value}"),
+            InputValueDecl::Text(value) => write!(f, "{value}"),
+        };
+    }
 }
 
 impl From<Trigger> for AnyInputValue {
@@ -140,6 +290,12 @@ impl From<Rgb> for AnyInputValue {
     }
 }
 
+impl From<Duration> for AnyInputValue {
+    fn from(value: Duration) -> Self {
+        return Self::Duration(value);
+    }
+}
+
 impl From<Range<i64>> for AnyInputValue {
     fn from(value: Range<i64>) -> Self {
         return Self::IntegerRange(value);
@@ -157,3 +313,79 @@ impl From<Range<Rgb>> for AnyInputValue {
         return Self::ColorRange(value);
     }
 }
+
+impl From<Gradient> for AnyInputValue {
+    fn from(value: Gradient) -> Self {
+        return Self::Gradient(Box::new(value));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert!(matches!(AnyInputValue::parse(InputValueType::Trigger, "ignored").unwrap(), AnyInputValue::Trigger));
+        assert!(matches!(
+            AnyInputValue::parse(InputValueType::Boolean, " true ").unwrap(),
+            AnyInputValue::Boolean(true)
+        ));
+        assert!(matches!(AnyInputValue::parse(InputValueType::Integer, "42").unwrap(), AnyInputValue::Integer(42)));
+        assert!(matches!(
+            AnyInputValue::parse(InputValueType::Duration, "1m 30s").unwrap(),
+            AnyInputValue::Duration(d) if d == Duration::from_secs(90)
+        ));
+        assert!(matches!(
+            AnyInputValue::parse(InputValueType::Color, "#ff0000").unwrap(),
+            AnyInputValue::Color(c) if c == Rgb::new(1.0, 0.0, 0.0)
+        ));
+        assert!(matches!(
+            AnyInputValue::parse(InputValueType::IntegerRange, "1..5").unwrap(),
+            AnyInputValue::IntegerRange(Range(1, 5))
+        ));
+
+        assert!(AnyInputValue::parse(InputValueType::Integer, "nope").is_err());
+        assert!(AnyInputValue::parse(InputValueType::Duration, "later").is_err());
+    }
+    #[test]
+    fn serde() {
+        let data = r#"{"a": true, "b": 3, "c": 0.5, "d": "1m 30s", "e": [1, 2], "f": [0.5, 1.5], "g": null}"#;
+
+        let values: std::collections::HashMap<String, AnyInputValue> = serde_json::from_str(data).unwrap();
+
+        assert!(matches!(values["a"], AnyInputValue::Boolean(true)));
+        assert!(matches!(values["b"], AnyInputValue::Integer(3)));
+        assert!(matches!(values["c"], AnyInputValue::Decimal(0.5)));
+        assert!(matches!(values["d"], AnyInputValue::Duration(d) if d == Duration::from_secs(90)));
+        assert!(matches!(values["e"], AnyInputValue::IntegerRange(Range(1, 2))));
+        assert!(matches!(values["f"], AnyInputValue::DecimalRange(Range(0.5, 1.5))));
+        assert!(matches!(values["g"], AnyInputValue::Trigger));
+
+        let color = AnyInputValue::Color(Rgb::new(1.0, 0.5, 0.0));
+        let color: AnyInputValue = serde_json::from_str(&serde_json::to_string(&color).unwrap()).unwrap();
+        assert!(matches!(color, AnyInputValue::Color(c) if c == Rgb::new(1.0, 0.5, 0.0)));
+
+        let gradient = AnyInputValue::parse(InputValueType::Gradient, "#ff0000, #0000ff").unwrap();
+        let gradient: AnyInputValue = serde_json::from_str(&serde_json::to_string(&gradient).unwrap()).unwrap();
+        assert!(matches!(gradient, AnyInputValue::Gradient(_)));
+    }
+
+    #[test]
+    fn resolve() {
+        let value = |data: &str| serde_json::from_str::<InputValueDecl>(data).unwrap();
+
+        assert!(matches!(value("true").resolve(InputValueType::Boolean).unwrap(), AnyInputValue::Boolean(true)));
+        assert!(matches!(value("3").resolve(InputValueType::Integer).unwrap(), AnyInputValue::Integer(3)));
+        assert!(matches!(value("3").resolve(InputValueType::Decimal).unwrap(), AnyInputValue::Decimal(3.0)));
+        assert!(matches!(value("0.5").resolve(InputValueType::Decimal).unwrap(), AnyInputValue::Decimal(0.5)));
+        assert!(matches!(value("true").resolve(InputValueType::Trigger).unwrap(), AnyInputValue::Trigger));
+        assert!(matches!(
+            value(r##""#ff0000""##).resolve(InputValueType::Color).unwrap(),
+            AnyInputValue::Color(c) if c == Rgb::new(1.0, 0.0, 0.0)
+        ));
+
+        assert!(value("true").resolve(InputValueType::Integer).is_err());
+        assert!(value("0.5").resolve(InputValueType::Integer).is_err());
+    }
+}
@@ -1,4 +1,6 @@
 use std::convert::Infallible;
+use std::fmt;
+use std::time::Duration;
 
 use palette::rgb::Rgb;
 use palette::{
@@ -6,7 +8,7 @@ use palette::{
     Okhsla, Okhsv, Okhsva, Okhwb, Okhwba, Oklab, Oklaba, Oklch, Oklcha, Srgb, Srgba, Xyz, Xyza, Yxy, Yxya,
 };
 
-use crate::attr::Range;
+use crate::attr::{Gradient, Range};
 use crate::input::trigger::Trigger;
 
 use super::sink::{InputSink, Sink};
@@ -57,6 +59,15 @@ impl InputValue for Rgb {
     }
 }
 
+impl super::private::Sealed for Duration {}
+
+impl InputValue for Duration {
+    const TYPE: InputValueType = InputValueType::Duration;
+    fn sink(sink: Sink<Self>) -> InputSink {
+        return InputSink::Duration(sink);
+    }
+}
+
 impl super::private::Sealed for Range<i64> {}
 
 impl InputValue for Range<i64> {
@@ -84,11 +95,66 @@ impl InputValue for Range<Rgb> {
     }
 }
 
+impl super::private::Sealed for Gradient {}
+
+impl InputValue for Gradient {
+    const TYPE: InputValueType = InputValueType::Gradient;
+    fn sink(sink: Sink<Self>) -> InputSink {
+        return InputSink::Gradient(sink);
+    }
+}
+
 pub trait Coerced: Sized {
     type Input: InputValue;
     type Error: std::error::Error + Send + Sync;
 
     fn try_from_input(input: Self::Input) -> Result<Self, Self::Error>;
+
+    /// Converts the value back to an input value, if it is representable as such.
+    fn to_input(self) -> Option<Self::Input>;
+}
+
+/// Enum-like values selected by the index of their variant when used as input.
+///
+/// Inputs selecting an index without a variant are rejected.
+pub trait Variants: Copy + PartialEq + 'static {
+    /// All variants in the order of their index
+    const ALL: &'static [Self];
+}
+
+/// Error for an input selecting an index without a variant.
+#[derive(Debug)]
+pub struct InvalidVariant {
+    pub index: i64,
+    pub count: usize,
+}
+
+impl fmt::Display for InvalidVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "No variant with index {}: expected index in [0, {})", self.index, self.count);
+    }
+}
+
+impl std::error::Error for InvalidVariant {}
+
+impl<V> Coerced for V
+where V: Variants
+{
+    type Input = i64;
+    type Error = InvalidVariant;
+
+    fn try_from_input(input: Self::Input) -> Result<Self, Self::Error> {
+        let variant = usize::try_from(input).ok().and_then(|index| V::ALL.get(index));
+        return variant.copied().ok_or(InvalidVariant {
+            index: input,
+            count: V::ALL.len(),
+        });
+    }
+
+    fn to_input(self) -> Option<Self::Input> {
+        let index = V::ALL.iter().position(|variant| *variant == self)?;
+        return i64::try_from(index).ok();
+    }
 }
 
 impl<V> Coerced for Range<V>
@@ -102,6 +168,10 @@ where
     fn try_from_input(input: Self::Input) -> Result<Self, Self::Error> {
         return Ok(Range::new(V::try_from_input(input.0)?, V::try_from_input(input.1)?));
     }
+
+    fn to_input(self) -> Option<Self::Input> {
+        return Some(Range::new(self.0.to_input()?, self.1.to_input()?));
+    }
 }
 
 macro_rules! impl_coerced_from {
@@ -117,6 +187,10 @@ macro_rules! impl_coerced_from {
                 fn try_from_input(input: Self::Input) -> Result<Self, Self::Error> {
                     return TryFrom::try_from(input);
                 }
+
+                fn to_input(self) -> Option<Self::Input> {
+                    return TryFrom::try_from(self).ok();
+                }
             }
         )*
     };
@@ -135,6 +209,10 @@ macro_rules! impl_coerced_color {
                 fn try_from_input(input: Self::Input) -> Result<Self, Self::Error> {
                     return Ok(FromColor::from_color(input));
                 }
+
+                fn to_input(self) -> Option<Self::Input> {
+                    return Some(FromColor::from_color(self));
+                }
             }
         )*
     };
@@ -142,7 +220,9 @@ macro_rules! impl_coerced_color {
 
 impl_coerced_from!(bool => bool);
 impl_coerced_from!(i64 => i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
-impl_coerced_from!(f32 => f32, f64);
+impl_coerced_from!(f32 => f32);
+impl_coerced_from!(Duration => Duration);
+impl_coerced_from!(Gradient => Gradient);
 impl_coerced_color!(Rgb => Srgb, Srgba);
 impl_coerced_color!(Rgb => Hsv, Hsva);
 impl_coerced_color!(Rgb => Hsl, Hsla, Hsluv, Hsluva);
@@ -156,3 +236,41 @@ impl_coerced_color!(Rgb => Okhwb, Okhwba);
 impl_coerced_color!(Rgb => Oklab, Oklaba);
 impl_coerced_color!(Rgb => Oklch, Oklcha);
 impl_coerced_color!(Rgb => Xyz, Xyza, Yxy, Yxya);
+
+impl Coerced for f64 {
+    type Input = f32;
+    type Error = Infallible;
+
+    fn try_from_input(input: Self::Input) -> Result<Self, Self::Error> {
+        return Ok(input.into());
+    }
+
+    fn to_input(self) -> Option<Self::Input> {
+        return Some(self as f32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Direction {
+        Forward,
+        Backward,
+    }
+
+    impl Variants for Direction {
+        const ALL: &'static [Self] = &[Self::Forward, Self::Backward];
+    }
+
+    #[test]
+    fn variants() {
+        assert_eq!(Direction::try_from_input(0).unwrap(), Direction::Forward);
+        assert_eq!(Direction::try_from_input(1).unwrap(), Direction::Backward);
+        assert!(Direction::try_from_input(2).is_err());
+        assert!(Direction::try_from_input(-1).is_err());
+
+        assert_eq!(Direction::Backward.to_input(), Some(1));
+    }
+}
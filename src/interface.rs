@@ -29,6 +29,7 @@ pub struct AttrInfoBuilder {
     pub kind: &'static str,
     pub value_type: AttrValueType,
 
+    pub nodes: HashMap<String, NodeInfoBuilder>,
     pub attrs: HashMap<String, AttrInfoBuilder>,
     pub inputs: HashMap<String, InputInfoBuilder>,
 }
@@ -81,6 +82,17 @@ impl NodeInfo {
         return self.attrs.get().expect("set");
     }
 
+    /// All nodes below this node - including the nodes used as source by any of its attributes.
+    fn children(&self) -> impl Iterator<Item = &Arc<NodeInfo>> {
+        let attrs = self
+            .attrs()
+            .values()
+            .flat_map(|attr| TreeIterator::new(attr, |attr| attr.attrs().values()))
+            .flat_map(|attr| attr.nodes().values());
+
+        return self.nodes().values().chain(attrs);
+    }
+
     pub fn find_attr<'i, I, Q>(&self, mut path: I) -> Option<&AttrInfo>
     where
         I: Iterator<Item = &'i Q>,
@@ -104,6 +116,7 @@ pub struct AttrInfo {
     node: Arc<NodeInfo>,
     parent: Option<Arc<AttrInfo>>,
 
+    nodes: OnceLock<HashMap<String, Arc<NodeInfo>>>,
     attrs: OnceLock<HashMap<String, Arc<AttrInfo>>>,
     inputs: OnceLock<HashMap<String, Arc<InputInfo>>>,
 }
@@ -141,6 +154,11 @@ impl AttrInfo {
         };
     }
 
+    /// Nodes used as source of this attribute.
+    pub fn nodes(&self) -> &HashMap<String, Arc<NodeInfo>> {
+        return self.nodes.get().expect("set");
+    }
+
     pub fn attrs(&self) -> &HashMap<String, Arc<AttrInfo>> {
         return self.attrs.get().expect("set");
     }
@@ -157,8 +175,8 @@ pub struct InputInfo {
     name: String,
     value_type: InputValueType,
 
-    node: Arc<NodeInfo>,
-    attr: Arc<AttrInfo>,
+    node: Option<Arc<NodeInfo>>,
+    attr: Option<Arc<AttrInfo>>,
 
     sink: InputSink,
 }
@@ -176,12 +194,18 @@ impl InputInfo {
         return self.value_type;
     }
 
-    pub fn node(&self) -> &Arc<NodeInfo> {
-        return &self.node;
+    /// The node owning the attribute this input is attached to.
+    ///
+    /// Returns `None` for inputs not referenced by any attribute.
+    pub fn node(&self) -> Option<&Arc<NodeInfo>> {
+        return self.node.as_ref();
     }
 
-    pub fn attr(&self) -> &Arc<AttrInfo> {
-        return &self.attr;
+    /// The attribute this input is attached to.
+    ///
+    /// Returns `None` for inputs not referenced by any attribute.
+    pub fn attr(&self) -> Option<&Arc<AttrInfo>> {
+        return self.attr.as_ref();
     }
 
     pub fn sink(&self) -> &InputSink {
@@ -201,14 +225,14 @@ pub struct Introspection {
 }
 
 impl Introspection {
-    pub fn with(root: NodeInfoBuilder) -> Arc<Self> {
+    pub fn with(root: NodeInfoBuilder, inputs_declared: HashMap<String, InputSink>) -> Arc<Self> {
         fn build_input(builder: InputInfoBuilder, node: Arc<NodeInfo>, attr: Arc<AttrInfo>) -> Arc<InputInfo> {
             return Arc::new(InputInfo {
                 key: builder.key,
                 name: builder.name,
                 value_type: builder.value_type,
-                node,
-                attr,
+                node: Some(node),
+                attr: Some(attr),
                 sink: builder.sink,
             });
         }
@@ -220,10 +244,22 @@ impl Introspection {
                 value_type: builder.value_type,
                 node: node.clone(),
                 parent,
+                nodes: OnceLock::new(),
                 attrs: OnceLock::new(),
                 inputs: OnceLock::new(),
             });
 
+            result
+                .nodes
+                .set(
+                    builder
+                        .nodes
+                        .into_iter()
+                        .map(|(key, child)| (key, build_node(child, Some(node.clone()))))
+                        .collect(),
+                )
+                .expect("unset");
+
             result
                 .attrs
                 .set(
@@ -286,16 +322,30 @@ impl Introspection {
 
         let root = build_node(root, None);
 
-        let nodes = TreeIterator::new(&root, |node| node.nodes().values())
+        let nodes = TreeIterator::new(&root, |node| node.children())
             .map(|node| (node.name.clone(), node.clone()))
             .collect::<HashMap<_, _>>();
 
-        let inputs = TreeIterator::new(&root, |node| node.nodes().values())
+        let mut inputs = TreeIterator::new(&root, |node| node.children())
             .flat_map(|node| node.attrs().values())
             .flat_map(|attr| TreeIterator::new(attr, |attr| attr.attrs().values()))
             .flat_map(|attr| attr.inputs().values())
             .map(|input| (input.name.clone(), input.clone()))
-            .collect();
+            .collect::<HashMap<_, _>>();
+
+        // Add inputs declared in the scene but not referenced by any attribute
+        for (name, sink) in inputs_declared {
+            inputs.entry(name.clone()).or_insert_with(|| {
+                Arc::new(InputInfo {
+                    key: name.clone(),
+                    name,
+                    value_type: sink.value_type(),
+                    node: None,
+                    attr: None,
+                    sink,
+                })
+            });
+        }
 
         return Arc::new(Self {
             root,
@@ -324,6 +374,10 @@ impl Introspection {
                 log_input(depth + 1, name, input);
             }
 
+            for (name, node) in attr.nodes() {
+                log_node(depth + 1, name, node);
+            }
+
             eprintln!("🔎  {indent}}}");
         }
 
@@ -343,6 +397,10 @@ impl Introspection {
         }
 
         log_node(0, "root", &self.root);
+
+        for input in self.inputs.values().filter(|input| input.attr.is_none()) {
+            log_input(0, &input.key, input);
+        }
     }
 }
 
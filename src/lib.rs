@@ -7,6 +7,7 @@
 
 pub use attr::{Attr, AttrValue};
 pub use buffer::{Buffer, BufferReader};
+pub use color::alpha::Opacity;
 pub use color::rgbw::{Rgbw, WhiteMode, WithWhite};
 pub use decl::{BoundAttrDecl, FreeAttrDecl, NodeDecl, OutputDecl};
 pub use interface::{AttrInfo, InputInfo, NodeInfo};
@@ -17,6 +18,7 @@ pub use scene::{AttrBuilder, Loop, NodeBuilder, NodeHandle, NodeRef, RenderConte
 
 mod arena;
 pub mod attr;
+pub mod binding;
 pub mod buffer;
 pub mod color;
 pub mod decl;
@@ -1,6 +1,6 @@
 use num_traits::Float;
 use palette::rgb::Rgb;
-use palette::{Hsl, Hsv, Lch, Mix};
+use palette::{Alpha, Hsl, Hsv, Lch, Mix};
 
 pub trait Lerp {
     fn lerp(a: Self, b: Self, i: f32) -> Self;
@@ -38,6 +38,30 @@ impl Lerp for f32 {
     }
 }
 
+macro_rules! lerp_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Lerp for $t {
+                fn lerp(a: Self, b: Self, i: f32) -> Self {
+                    assert!((0.0..=1.0).contains(&i));
+
+                    if i <= 0.0 {
+                        return a;
+                    }
+
+                    if i >= 1.0 {
+                        return b;
+                    }
+
+                    return (a as f64 + (b as f64 - a as f64) * i as f64).round() as $t;
+                }
+            }
+        )*
+    };
+}
+
+lerp_integer!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+
 impl Lerp for Hsl {
     fn lerp(a: Self, b: Self, i: f32) -> Self {
         return Hsl::mix(a, b, i);
@@ -62,6 +86,17 @@ impl Lerp for Rgb {
     }
 }
 
+impl<C> Lerp for Alpha<C, f32>
+where C: Lerp
+{
+    fn lerp(a: Self, b: Self, i: f32) -> Self {
+        return Alpha {
+            color: C::lerp(a.color, b.color, i),
+            alpha: f32::lerp(a.alpha, b.alpha, i),
+        };
+    }
+}
+
 pub fn minmax<F>(a: F, b: F) -> (F, F)
 where F: PartialOrd {
     return if a < b { (a, b) } else { (b, a) };
@@ -1,4 +1,4 @@
-use crate::{Buffer, RenderContext};
+use crate::{Buffer, Opacity, RenderContext};
 
 use anyhow::Result;
 
@@ -6,7 +6,7 @@ pub mod convert;
 pub mod map;
 
 pub trait Node {
-    type Element: Copy + Default;
+    type Element: Copy + Default + Opacity;
 
     fn update(&mut self, ctx: &RenderContext, out: &mut Buffer<Self::Element>) -> Result<()>;
 }
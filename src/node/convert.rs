@@ -2,14 +2,14 @@ use std::marker::PhantomData;
 
 use anyhow::Result;
 
-use crate::{Buffer, BufferReader, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, RenderContext};
+use crate::{Buffer, BufferReader, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, Opacity, RenderContext};
 
 pub struct Convert<N, T, R>
 where
     N: NodeDecl,
     N::Node: Node<Element = T> + 'static,
     T: Default,
-    R: Default + Copy + From<T>,
+    R: Default + Copy + Opacity + From<T>,
 {
     source: NodeHandle<N>,
     phantom: PhantomData<R>,
@@ -20,7 +20,7 @@ where
     N: NodeDecl,
     N::Node: Node<Element = T> + 'static,
     T: Default,
-    R: Default + Copy + From<T>,
+    R: Default + Copy + Opacity + From<T>,
 {
     const KIND: &'static str = "convert";
 
@@ -37,7 +37,7 @@ where
 pub struct ConvertNode<N, T, R>
 where
     N: Node<Element = T> + 'static,
-    R: Default + Copy + From<T>,
+    R: Default + Copy + Opacity + From<T>,
 {
     source: NodeRef<N>,
     phantom: PhantomData<R>,
@@ -46,7 +46,7 @@ where
 impl<N, T, R> Node for ConvertNode<N, T, R>
 where
     N: Node<Element = T> + 'static,
-    R: Default + Copy + From<T>,
+    R: Default + Copy + Opacity + From<T>,
 {
     type Element = R;
 
@@ -1,6 +1,6 @@
 use anyhow::Result;
 
-use crate::{Buffer, BufferReader, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, RenderContext};
+use crate::{Buffer, BufferReader, Node, NodeBuilder, NodeDecl, NodeHandle, NodeRef, Opacity, RenderContext};
 
 pub struct Map<N, F, T, R>
 where
@@ -8,7 +8,7 @@ where
     N::Node: Node<Element = T> + 'static,
     F: Fn(T) -> R,
     T: Default,
-    R: Default + Copy,
+    R: Default + Copy + Opacity,
 {
     pub source: NodeHandle<N>,
     pub mapper: F,
@@ -20,7 +20,7 @@ where
     N::Node: Node<Element = T> + 'static,
     F: Fn(T) -> R,
     T: Default,
-    R: Default + Copy,
+    R: Default + Copy + Opacity,
 {
     const KIND: &'static str = "map";
 
@@ -38,7 +38,7 @@ pub struct MapNode<N, F, T, R>
 where
     N: Node<Element = T> + 'static,
     F: Fn(T) -> R,
-    R: Default + Copy,
+    R: Default + Copy + Opacity,
 {
     source: NodeRef<N>,
     mapper: F,
@@ -48,7 +48,7 @@ impl<N, F, T, R> Node for MapNode<N, F, T, R>
 where
     N: Node<Element = T> + 'static,
     F: Fn(T) -> R,
-    R: Default + Copy,
+    R: Default + Copy + Opacity,
 {
     type Element = R;
 
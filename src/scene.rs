@@ -3,7 +3,7 @@ use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
-use std::{future, ops};
+use std::{future, mem, ops};
 
 use anyhow::{bail, Context, Result};
 use futures::future::SelectAll;
@@ -12,11 +12,12 @@ use palette::FromColor;
 
 use crate::arena::{Arena, Ref, Slice};
 use crate::attr::{AttrValue, Bounded, Bounds};
+use crate::binding::{Binding, Target};
 use crate::decl::{BoundAttrDecl, FreeAttrDecl, NodeDecl, OutputDecl};
 use crate::input::{Input, InputSink, InputValue};
 use crate::interface::{AttrInfoBuilder, InputInfoBuilder, Interface, Introspection, NodeInfoBuilder};
 use crate::utils::{FrameStats, FrameTimer};
-use crate::{Buffer, BufferReader, Node, Output};
+use crate::{Buffer, BufferReader, Node, Opacity, Output};
 
 pub struct RenderContext<'ctx> {
     /// Duration since last update
@@ -51,8 +52,11 @@ impl<Decl> NodeHandle<Decl>
 where Decl: NodeDecl + 'static
 {
     pub fn boxed<E>(self) -> NodeHandle<crate::boxed::BoxedNodeDecl<E>>
-    where E: Default + Copy + palette::convert::FromColorUnclamped<<<Decl as NodeDecl>::Node as Node>::Element> + 'static
-    {
+    where E: Default
+            + Copy
+            + Opacity
+            + palette::convert::FromColorUnclamped<<<Decl as NodeDecl>::Node as Node>::Element>
+            + 'static {
         return NodeHandle {
             name: self.name,
             decl: crate::boxed::Boxed::boxed(self.decl),
@@ -159,12 +163,23 @@ where V: InputValue
     pub fn sink(&self) -> InputSink {
         return self.input.sink().into();
     }
+
+    /// Consumes the handle to use the input outside of the node tree.
+    ///
+    /// This is used by interfaces consuming inputs on their own. Such an input is still listed by the
+    /// [`Introspection`] of the scene, even if it is not referenced by any attribute.
+    pub fn detach(self) -> Input<V> {
+        return self.input;
+    }
 }
 
 /// Declaration of a scene.
 ///
 /// This is used to declare nodes, attributes and inputs.
-pub struct Scene {}
+pub struct Scene {
+    inputs: HashMap<String, InputSink>,
+    bindings: Vec<Binding>,
+}
 
 impl Default for Scene {
     fn default() -> Self {
@@ -175,7 +190,10 @@ impl Default for Scene {
 impl Scene {
     /// Create a new scene with a given size.
     pub fn new() -> Self {
-        return Self {};
+        return Self {
+            inputs: HashMap::new(),
+            bindings: Vec::new(),
+        };
     }
 
     /// Declares a new node in the scene.
@@ -200,10 +218,39 @@ impl Scene {
     /// in other nodes and attributes.
     pub fn input<V>(&mut self, name: &str) -> Result<InputHandle<V>>
     where V: InputValue {
+        if self.bindings.iter().any(|binding| binding.name == name) {
+            bail!("Duplicated input: {name}");
+        }
+
+        let input = Input::new();
+
+        self.inputs.entry(name.to_owned()).or_insert_with(|| input.sink().into());
+
         return Ok(InputHandle {
             name: name.to_owned(),
-            input: Input::new(),
+            input,
+        });
+    }
+
+    /// Declares a binding in the scene.
+    ///
+    /// A binding is a virtual decimal input with the given name which forwards all values sent to it to the given
+    /// target inputs, each transformed individually. The name must be unique over all inputs in the scene and the
+    /// targets must be decimal inputs of the scene.
+    pub fn binding(&mut self, name: &str, targets: impl IntoIterator<Item = Target>) -> Result<()> {
+        let input = Input::new();
+
+        if let Err(err) = self.inputs.try_insert(name.to_owned(), input.sink().into()) {
+            bail!("Duplicated input: {}", err.entry.key());
+        }
+
+        self.bindings.push(Binding {
+            name: name.to_owned(),
+            input,
+            targets: targets.into_iter().collect(),
         });
+
+        return Ok(());
     }
 
     /// Create a driver for the scene.
@@ -232,16 +279,24 @@ impl Scene {
         // Materialize the node tree using a builder tracking the info object creation
         let (scene, root) = SceneBuilder::build(output.size(), root).await.context("Failed to build scene")?;
 
-        let introspection = Introspection::with(scene.root);
+        let introspection = Introspection::with(scene.root, self.inputs);
         introspection.log();
 
+        // Bindings are running alongside the interfaces
+        let mut servers: Vec<Pin<Box<dyn Future<Output = Result<()>>>>> = Vec::new();
+        for binding in self.bindings {
+            let name = binding.name.clone();
+            let binding = binding.bind(&introspection).with_context(|| format!("Failed to bind: {name}"))?;
+            servers.push(Box::pin(binding));
+        }
+
         return Ok(Loop {
             nodes: scene.nodes,
             root,
             output,
             stats: FrameStats::default(),
             introspection,
-            servers: Vec::new(),
+            servers,
         });
     }
 }
@@ -280,7 +335,7 @@ where
     pub async fn run(mut self, fps: usize) -> Result<()> {
         // Wait for any server to finish
         let mut servers: Pin<Box<dyn Future<Output = Result<()>>>> = if !self.servers.is_empty() {
-            Box::pin(self.servers.into_iter().collect::<SelectAll<_>>().map(|(result, _, _)| result))
+            Box::pin(mem::take(&mut self.servers).into_iter().collect::<SelectAll<_>>().map(|(result, _, _)| result))
         } else {
             Box::pin(future::pending())
         };
@@ -295,24 +350,7 @@ where
                 }
             };
 
-            self.nodes
-                .try_walk(|curr, tail| {
-                    let ctx = RenderContext {
-                        duration,
-                        nodes: tail,
-                    };
-
-                    return curr.update(&ctx);
-                })
-                .context("Failed to update nodes")?;
-
-            let root = &self.nodes.as_slice()[self.root.node];
-
-            // Render node tree to output
-            self.output
-                .render(root.buffer.map(Output::Element::from_color))
-                .await
-                .context("Output failed to render")?;
+            self.render(duration).await?;
 
             self.stats.update(duration);
 
@@ -327,6 +365,33 @@ where
         }
     }
 
+    /// Render a single frame.
+    ///
+    /// All nodes are updated with the given duration since the last frame and the root node is rendered to the
+    /// output.
+    pub async fn render(&mut self, duration: Duration) -> Result<()> {
+        self.nodes
+            .try_walk(|curr, tail| {
+                let ctx = RenderContext {
+                    duration,
+                    nodes: tail,
+                };
+
+                return curr.update(&ctx);
+            })
+            .context("Failed to update nodes")?;
+
+        let root = &self.nodes.as_slice()[self.root.node];
+
+        // Render node tree to output
+        self.output
+            .render(root.buffer.map(|e| Output::Element::from_color(e.opaque())))
+            .await
+            .context("Output failed to render")?;
+
+        return Ok(());
+    }
+
     pub fn serve(&mut self, name: &'static str, interface: impl Interface) {
         let interface = interface.listen(self.introspection.clone());
         let interface = interface.inspect(move |result| {
@@ -404,43 +469,64 @@ impl SceneBuilder {
     {
         let mut nodes = Arena::new();
 
-        let mut builder = NodeBuilder {
+        let (node, info) = materialize_node(&mut nodes, size, String::new(), root).await?;
+
+        let scene = SceneBuilder {
             size,
+            nodes,
+            root: info,
+        };
 
-            nodes: &mut nodes,
+        return Ok((scene, node));
+    }
+}
 
-            info: NodeInfoBuilder {
-                key: "".to_string(),
-                kind: Node::KIND,
-                name: root.name,
-                nodes: HashMap::new(),
-                attrs: HashMap::new(),
-            },
-        };
+/// Materializes a node and appends it to the arena.
+///
+/// The node is appended after all nodes materialized while building it and is therefore rendered after them.
+async fn materialize_node<Node>(
+    nodes: &mut Arena<dyn NodeHolder>,
+    size: usize,
+    key: String,
+    decl: NodeHandle<Node>,
+) -> Result<(NodeRef<Node::Node>, NodeInfoBuilder)>
+where
+    Node: NodeDecl,
+    <Node as NodeDecl>::Node: 'static,
+    <<Node as NodeDecl>::Node as self::Node>::Element: Default, // TODO: Remove this constraint
+{
+    let mut builder = NodeBuilder {
+        size,
 
-        // TODO: Dedup with code from node-builder
-        let node = Node::materialize(root.decl, &mut builder).await?;
-        let info = builder.info;
+        nodes,
 
-        let buffer = Buffer::with_default(size);
+        info: NodeInfoBuilder {
+            key,
+            kind: Node::KIND,
+            name: decl.name,
+            nodes: HashMap::new(),
+            attrs: HashMap::new(),
+        },
+    };
 
-        let node = nodes.append(NodeContainer {
-            node,
-            buffer,
-        });
+    let node = Node::materialize(decl.decl, &mut builder).await?;
+    let info = builder.info;
 
-        eprintln!("✨ Materialized node {} ({:?})", info.name, node);
+    let buffer = Buffer::with_default(size);
 
-        let scene = SceneBuilder {
-            size,
-            nodes,
-            root: info,
-        };
+    let node = nodes.append(NodeContainer {
+        node,
+        buffer,
+    });
+
+    eprintln!("✨ Materialized node {} ({:?})", info.name, node);
 
-        return Ok((scene, NodeRef {
+    return Ok((
+        NodeRef {
             node,
-        }));
-    }
+        },
+        info,
+    ));
 }
 
 impl NodeBuilder<'_> {
@@ -466,45 +552,19 @@ impl NodeBuilder<'_> {
     {
         let key = key.into();
 
-        let mut builder = NodeBuilder {
-            size,
-
-            nodes: self.nodes,
-
-            info: NodeInfoBuilder {
-                key: key.clone(),
-                kind: Node::KIND,
-                name: decl.name,
-                nodes: HashMap::new(),
-                attrs: HashMap::new(),
-            },
-        };
-
-        let node = Node::materialize(decl.decl, &mut builder).await?;
-        let info = builder.info;
-
-        let buffer = Buffer::with_default(size);
-
-        let node = self.nodes.append(NodeContainer {
-            node,
-            buffer,
-        });
-
-        eprintln!("✨ Materialized node {} ({:?})", info.name, node);
+        let (node, info) = materialize_node(self.nodes, size, key.clone(), decl).await?;
 
         if let Err(err) = self.info.nodes.try_insert(key, info) {
             bail!("Duplicated node: {}", err.entry.key())
         }
 
-        return Ok(NodeRef {
-            node,
-        });
+        return Ok(node);
     }
 
     /// Create a bound attribute.
     ///
     /// The created attribute is registered as an attribute to the currently built node.
-    pub fn bound_attr<V, Attr>(
+    pub async fn bound_attr<V, Attr>(
         &mut self,
         key: impl Into<String>,
         decl: Attr,
@@ -525,12 +585,13 @@ impl NodeBuilder<'_> {
                 key: key.clone(),
                 kind: Attr::KIND,
                 value_type: V::TYPE,
+                nodes: HashMap::new(),
                 attrs: HashMap::new(),
                 inputs: HashMap::new(),
             },
         };
 
-        let attr = decl.materialize(bounds, &mut builder)?;
+        let attr = decl.materialize(bounds, &mut builder).await?;
 
         if let Err(err) = self.info.attrs.try_insert(key, builder.info) {
             bail!("Duplicated attribute: {}", err.entry.key())
@@ -543,7 +604,7 @@ impl NodeBuilder<'_> {
     ///
     /// The created attribute is registered as an attribute to the currently built node.
     // TODO: Rename to `free_attr`
-    pub fn unbound_attr<V, Attr>(&mut self, key: impl Into<String>, decl: Attr) -> Result<Attr::Attr>
+    pub async fn unbound_attr<V, Attr>(&mut self, key: impl Into<String>, decl: Attr) -> Result<Attr::Attr>
     where
         V: AttrValue,
         Attr: FreeAttrDecl<V>,
@@ -557,12 +618,13 @@ impl NodeBuilder<'_> {
                 key: key.clone(),
                 kind: Attr::KIND,
                 value_type: V::TYPE,
+                nodes: HashMap::new(),
                 attrs: HashMap::new(),
                 inputs: HashMap::new(),
             },
         };
 
-        let attr = decl.materialize(&mut builder)?;
+        let attr = decl.materialize(&mut builder).await?;
 
         if let Err(err) = self.info.attrs.try_insert(key, builder.info) {
             bail!("Duplicated attribute: {}", err.entry.key())
@@ -576,7 +638,7 @@ impl AttrBuilder<'_> {
     /// Create a bound child-attribute from its handle.
     ///
     /// The created attribute is registered as an attribute to the currently built node.
-    pub fn bound_attr<V, Attr>(
+    pub async fn bound_attr<V, Attr>(
         &mut self,
         key: impl Into<String>,
         decl: Attr,
@@ -597,12 +659,13 @@ impl AttrBuilder<'_> {
                 key: key.clone(),
                 kind: Attr::KIND,
                 value_type: V::TYPE,
+                nodes: HashMap::new(),
                 attrs: HashMap::new(),
                 inputs: HashMap::new(),
             },
         };
 
-        let attr = decl.materialize(bounds, &mut builder)?;
+        let attr = decl.materialize(bounds, &mut builder).await?;
 
         if let Err(err) = self.info.attrs.try_insert(key, builder.info) {
             bail!("Duplicated attribute: {}", err.entry.key())
@@ -614,7 +677,7 @@ impl AttrBuilder<'_> {
     /// Create a unbound child-attribute from its handle.
     ///
     /// The created attribute is registered as an attribute to the currently built node.
-    pub fn unbound_attr<V, Attr>(&mut self, key: impl Into<String>, decl: Attr) -> Result<Attr::Attr>
+    pub async fn unbound_attr<V, Attr>(&mut self, key: impl Into<String>, decl: Attr) -> Result<Attr::Attr>
     where
         V: AttrValue,
         Attr: FreeAttrDecl<V>,
@@ -628,12 +691,13 @@ impl AttrBuilder<'_> {
                 key: key.clone(),
                 kind: Attr::KIND,
                 value_type: V::TYPE,
+                nodes: HashMap::new(),
                 attrs: HashMap::new(),
                 inputs: HashMap::new(),
             },
         };
 
-        let attr = decl.materialize(&mut builder)?;
+        let attr = decl.materialize(&mut builder).await?;
 
         if let Err(err) = self.info.attrs.try_insert(key, builder.info) {
             bail!("Duplicated attribute: {}", err.entry.key())
@@ -642,6 +706,27 @@ impl AttrBuilder<'_> {
         return Ok(attr);
     }
 
+    /// Create a node used as source of the currently built attribute.
+    ///
+    /// The node is rendered before the node owning the attribute and can therefore be read during updates of the
+    /// attribute.
+    pub async fn node<Node>(&mut self, key: impl Into<String>, decl: NodeHandle<Node>) -> Result<NodeRef<Node::Node>>
+    where
+        Node: NodeDecl,
+        <Node as NodeDecl>::Node: 'static,
+        <<Node as NodeDecl>::Node as self::Node>::Element: Default, // TODO: Remove this constraint
+    {
+        let key = key.into();
+
+        let (node, info) = materialize_node(self.nodes, self.size, key.clone(), decl).await?;
+
+        if let Err(err) = self.info.nodes.try_insert(key, info) {
+            bail!("Duplicated node: {}", err.entry.key())
+        }
+
+        return Ok(node);
+    }
+
     /// Create an input from its handle.
     ///
     /// The created input is registered as an input to the currently built node.